@@ -0,0 +1,67 @@
+#!/usr/bin/env python3
+"""Minimal Tuisteroids bot: turn toward the nearest asteroid and fire.
+
+Connect to a game serving a Unix socket:
+    cargo run --release -- --bot-socket /tmp/tuisteroids.sock --seed 42 &
+    python3 examples/bot_client.py /tmp/tuisteroids.sock
+
+Without arguments the client speaks on its own stdin/stdout, for harnesses that
+launch `tuisteroids --bot` as a subprocess and cross-wire the pipes.
+"""
+
+import json
+import math
+import socket
+import sys
+
+
+def wrap(delta, size):
+    if delta > size / 2:
+        return delta - size
+    if delta < -size / 2:
+        return delta + size
+    return delta
+
+
+def decide(obs):
+    ship = obs["ship"]
+    if not obs["asteroids"]:
+        return {}
+    w, h = obs["world_width"], obs["world_height"]
+
+    def offset(a):
+        return wrap(a["x"] - ship["x"], w), wrap(a["y"] - ship["y"], h)
+
+    target = min(obs["asteroids"], key=lambda a: math.hypot(*offset(a)))
+    dx, dy = offset(target)
+    error = math.atan2(dy, dx) - ship["rotation"]
+    error = math.atan2(math.sin(error), math.cos(error))
+    return {
+        "rotate_left": error < -0.1,
+        "rotate_right": error > 0.1,
+        "fire": abs(error) < 0.2 and obs["tick"] % 10 == 0,
+    }
+
+
+def play(reader, writer):
+    for line in reader:
+        obs = json.loads(line)
+        if obs["done"]:
+            print(f"score {obs['score']} wave {obs['wave']}", file=sys.stderr)
+            return
+        writer.write(json.dumps(decide(obs)) + "\n")
+        writer.flush()
+
+
+def main():
+    if len(sys.argv) > 1:
+        sock = socket.socket(socket.AF_UNIX, socket.SOCK_STREAM)
+        sock.connect(sys.argv[1])
+        stream = sock.makefile("rw")
+        play(stream, stream)
+    else:
+        play(sys.stdin, sys.stdout)
+
+
+if __name__ == "__main__":
+    main()
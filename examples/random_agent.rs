@@ -0,0 +1,37 @@
+// Runs a few episodes of the RL environment with a uniformly random policy.
+
+use rand::{Rng, SeedableRng};
+use tuisteroids::env::{Env, EnvConfig, RaySensor, ACTION_COUNT};
+
+fn main() {
+    let mut env = Env::new(EnvConfig {
+        rays: Some(RaySensor::default()),
+        max_steps: Some(5_000),
+        ..Default::default()
+    });
+    let mut policy = rand::rngs::StdRng::seed_from_u64(0);
+
+    for seed in 0..3 {
+        env.reset(seed);
+        let mut total = 0.0;
+        loop {
+            let (_, reward, done, info) = env.step(policy.gen_range(0..ACTION_COUNT));
+            total += reward;
+            if done {
+                println!(
+                    "seed {}: return {} over {} steps (wave {}, {})",
+                    seed,
+                    total,
+                    env.steps(),
+                    info.wave,
+                    if info.truncated {
+                        "truncated"
+                    } else {
+                        "game over"
+                    }
+                );
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,366 @@
+// Achievements capability: goals unlocked by gameplay events and kept in a file
+
+use crate::events::{Destroyer, GameEvent};
+use crate::save;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const ACHIEVEMENTS_FILE: &str = "achievements.json";
+pub const TOAST_TIME: f64 = 3.0; // seconds an unlock stays on screen
+
+/// What has to happen for an achievement to unlock.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Goal {
+    /// Clear a wave without thrusting.
+    WaveWithoutThrust,
+    /// Clear a wave with at least this percentage of its shots hitting.
+    WaveAccuracy(u32),
+    /// Start this wave or a later one.
+    ReachWave(u32),
+    /// Stay alive this many seconds without firing.
+    SurviveWithoutFiring(f64),
+}
+
+pub struct Achievement {
+    /// Key in the achievements file.
+    pub id: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+    pub goal: Goal,
+}
+
+/// Every achievement, in the order they are listed.
+pub const ACHIEVEMENTS: &[Achievement] = &[
+    Achievement {
+        id: "drifter",
+        name: "Drifter",
+        description: "Clear a wave without thrusting",
+        goal: Goal::WaveWithoutThrust,
+    },
+    Achievement {
+        id: "marksman",
+        name: "Marksman",
+        description: "Clear a wave without missing a shot",
+        goal: Goal::WaveAccuracy(100),
+    },
+    Achievement {
+        id: "veteran",
+        name: "Veteran",
+        description: "Reach wave 10",
+        goal: Goal::ReachWave(10),
+    },
+    Achievement {
+        id: "pacifist",
+        name: "Pacifist",
+        description: "Survive 60 seconds without firing",
+        goal: Goal::SurviveWithoutFiring(60.0),
+    },
+];
+
+/// One player's progress toward the goals in the game being played.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Progress {
+    /// Thrusted during this wave.
+    thrusted: bool,
+    /// Bullets fired and rocks hit during this wave.
+    shots: u32,
+    hits: u32,
+    /// Seconds alive since the last shot or death.
+    calm: f64,
+}
+
+impl Goal {
+    /// Whether `event`, with `progress` already updated for it, meets the goal.
+    fn met(self, event: &GameEvent, progress: &[Progress; 2]) -> bool {
+        match (self, *event) {
+            (Goal::WaveWithoutThrust, GameEvent::WaveCleared { player }) => {
+                !progress[player].thrusted
+            }
+            (Goal::WaveAccuracy(percent), GameEvent::WaveCleared { player }) => {
+                let Progress { shots, hits, .. } = progress[player];
+                shots > 0 && hits as u64 * 100 >= percent as u64 * shots as u64
+            }
+            (Goal::ReachWave(target), GameEvent::WaveStarted { wave }) => wave >= target,
+            (Goal::SurviveWithoutFiring(seconds), GameEvent::ShipFlew { player, .. }) => {
+                progress[player].calm >= seconds
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Unlocked achievements, kept in the achievements file, and progress toward the rest.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Achievements {
+    /// Ids of the achievements unlocked so far.
+    pub unlocked: BTreeSet<String>,
+    #[serde(skip)]
+    progress: [Progress; 2],
+}
+
+impl Achievements {
+    /// A new game starts: progress toward every goal begins again.
+    pub fn new_game(&mut self) {
+        self.progress = Default::default();
+    }
+
+    /// Watch one event, returning the achievements it unlocks.
+    pub fn observe(&mut self, event: &GameEvent) -> Vec<&'static Achievement> {
+        match *event {
+            GameEvent::ShipFlew {
+                player,
+                dt,
+                thrusting,
+                ..
+            } => {
+                let progress = &mut self.progress[player];
+                progress.thrusted |= thrusting;
+                progress.calm += dt;
+            }
+            GameEvent::BulletFired { player, bullets } => {
+                let progress = &mut self.progress[player];
+                progress.shots += bullets;
+                progress.calm = 0.0;
+            }
+            GameEvent::AsteroidDestroyed {
+                by: Destroyer::Bullet { player },
+                ..
+            } => self.progress[player].hits += 1,
+            GameEvent::ShipDestroyed { player, .. } => self.progress[player].calm = 0.0,
+            GameEvent::WaveStarted { .. } => {
+                for progress in &mut self.progress {
+                    *progress = Progress {
+                        calm: progress.calm,
+                        ..Progress::default()
+                    };
+                }
+            }
+            _ => {}
+        }
+        let unlocked: Vec<&'static Achievement> = ACHIEVEMENTS
+            .iter()
+            .filter(|achievement| !self.unlocked.contains(achievement.id))
+            .filter(|achievement| achievement.goal.met(event, &self.progress))
+            .collect();
+        self.unlocked.extend(
+            unlocked
+                .iter()
+                .map(|achievement| achievement.id.to_string()),
+        );
+        unlocked
+    }
+
+    /// Lines for the lifetime stats screen: how many are unlocked, then each one.
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines = vec![format!(
+            "{:<14}{:>8}",
+            "Achievements",
+            format!("{}/{}", self.unlocked.len(), ACHIEVEMENTS.len())
+        )];
+        lines.extend(ACHIEVEMENTS.iter().map(|achievement| {
+            let mark = if self.unlocked.contains(achievement.id) {
+                'x'
+            } else {
+                ' '
+            };
+            format!(
+                "[{}] {:<10}{}",
+                mark, achievement.name, achievement.description
+            )
+        }));
+        lines
+    }
+
+    /// Read the achievements file. A missing file means none unlocked yet.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        save::read_json(path)
+    }
+
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        save::write_json_atomically(path, self)
+    }
+}
+
+/// The toast shown when `achievement` unlocks.
+pub fn toast(achievement: &Achievement) -> String {
+    format!(
+        "ACHIEVEMENT: {} - {}",
+        achievement.name, achievement.description
+    )
+}
+
+/// Where unlocked achievements live, in the data directory.
+pub fn default_path() -> PathBuf {
+    save::data_dir().join(ACHIEVEMENTS_FILE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unlocked_ids(achievements: &mut Achievements, events: &[GameEvent]) -> Vec<&'static str> {
+        events
+            .iter()
+            .flat_map(|event| achievements.observe(event))
+            .map(|achievement| achievement.id)
+            .collect()
+    }
+
+    fn flew(player: usize, dt: f64, thrusting: bool) -> GameEvent {
+        GameEvent::ShipFlew {
+            player,
+            dt,
+            speed: 0.0,
+            thrusting,
+        }
+    }
+
+    fn hit(player: usize) -> GameEvent {
+        GameEvent::AsteroidDestroyed {
+            size: crate::asteroids::AsteroidSize::Small,
+            position: crate::physics::Vec2::new(0.0, 0.0),
+            by: Destroyer::Bullet { player },
+        }
+    }
+
+    // === Requirement: Achievement Definitions ===
+
+    // Scenario: Every achievement has a unique id and a goal
+    #[test]
+    fn test_definitions() {
+        let ids: BTreeSet<&str> = ACHIEVEMENTS.iter().map(|a| a.id).collect();
+        assert_eq!(ids.len(), ACHIEVEMENTS.len());
+        assert!(ACHIEVEMENTS
+            .iter()
+            .any(|a| a.goal == Goal::WaveWithoutThrust));
+        assert!(ACHIEVEMENTS.iter().any(|a| a.goal == Goal::ReachWave(10)));
+    }
+
+    // === Requirement: Achievement Unlocking ===
+
+    // Scenario: Clearing a wave without thrusting unlocks Drifter
+    #[test]
+    fn test_wave_without_thrust() {
+        let mut achievements = Achievements::default();
+        let events = [
+            flew(0, 1.0, true),
+            GameEvent::WaveCleared { player: 0 },
+            GameEvent::WaveStarted { wave: 2 },
+            flew(0, 1.0, false),
+        ];
+        assert!(unlocked_ids(&mut achievements, &events).is_empty());
+        let cleared = [GameEvent::WaveCleared { player: 0 }];
+        assert_eq!(unlocked_ids(&mut achievements, &cleared), vec!["drifter"]);
+    }
+
+    // Scenario: Clearing a wave with every shot hitting unlocks Marksman
+    #[test]
+    fn test_wave_accuracy() {
+        let mut achievements = Achievements::default();
+        let events = [
+            flew(0, 1.0, true),
+            GameEvent::BulletFired {
+                player: 0,
+                bullets: 2,
+            },
+            hit(0),
+            GameEvent::WaveCleared { player: 0 },
+            GameEvent::WaveStarted { wave: 2 },
+            flew(0, 1.0, true),
+            GameEvent::BulletFired {
+                player: 0,
+                bullets: 1,
+            },
+            hit(0),
+        ];
+        assert!(unlocked_ids(&mut achievements, &events).is_empty());
+        let cleared = [GameEvent::WaveCleared { player: 0 }];
+        assert_eq!(unlocked_ids(&mut achievements, &cleared), vec!["marksman"]);
+    }
+
+    // Scenario: Starting wave 10 unlocks Veteran
+    #[test]
+    fn test_reach_wave() {
+        let mut achievements = Achievements::default();
+        let waves: Vec<GameEvent> = (2..=10)
+            .map(|wave| GameEvent::WaveStarted { wave })
+            .collect();
+        assert_eq!(unlocked_ids(&mut achievements, &waves), vec!["veteran"]);
+    }
+
+    // Scenario: Surviving 60 seconds without firing unlocks Pacifist
+    #[test]
+    fn test_survive_without_firing() {
+        let mut achievements = Achievements::default();
+        let events = [
+            flew(0, 50.0, false),
+            GameEvent::BulletFired {
+                player: 0,
+                bullets: 1,
+            },
+            flew(0, 50.0, false),
+            GameEvent::ShipDestroyed {
+                player: 0,
+                by: None,
+            },
+            flew(0, 50.0, false),
+            flew(1, 59.0, false),
+        ];
+        assert!(unlocked_ids(&mut achievements, &events).is_empty());
+        assert_eq!(
+            unlocked_ids(&mut achievements, &[flew(1, 1.0, false)]),
+            vec!["pacifist"]
+        );
+    }
+
+    // Scenario: An achievement unlocks once, and a new game starts progress again
+    #[test]
+    fn test_unlocks_once() {
+        let mut achievements = Achievements::default();
+        let cleared = [GameEvent::WaveCleared { player: 0 }];
+        assert_eq!(unlocked_ids(&mut achievements, &cleared), vec!["drifter"]);
+        assert!(unlocked_ids(&mut achievements, &cleared).is_empty());
+
+        achievements.observe(&flew(0, 59.0, false));
+        achievements.new_game();
+        assert!(achievements.observe(&flew(0, 1.0, false)).is_empty());
+    }
+
+    // Scenario: Unlocks are listed with a count
+    #[test]
+    fn test_lines() {
+        let mut achievements = Achievements::default();
+        achievements.observe(&GameEvent::WaveStarted { wave: 10 });
+        let lines = achievements.lines();
+        assert_eq!(lines[0], "Achievements       1/4");
+        assert_eq!(lines[1], "[ ] Drifter   Clear a wave without thrusting");
+        assert_eq!(lines[3], "[x] Veteran   Reach wave 10");
+        assert_eq!(
+            toast(&ACHIEVEMENTS[2]),
+            "ACHIEVEMENT: Veteran - Reach wave 10"
+        );
+    }
+
+    // === Requirement: Achievement Persistence ===
+
+    // Scenario: Unlocked achievements are kept in a file
+    #[test]
+    fn test_achievements_file() {
+        let dir =
+            std::env::temp_dir().join(format!("tuisteroids-achievements-{}", std::process::id()));
+        let path = dir.join(ACHIEVEMENTS_FILE);
+        assert_eq!(Achievements::load(&path).unwrap(), Achievements::default());
+        let mut achievements = Achievements::default();
+        achievements.observe(&GameEvent::WaveStarted { wave: 12 });
+        achievements.write(&path).unwrap();
+        let loaded = Achievements::load(&path).unwrap();
+        assert_eq!(loaded.unlocked, achievements.unlocked);
+        std::fs::write(&path, "not json").unwrap();
+        let error = Achievements::load(&path).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,11 +1,19 @@
 // Asteroids capability: asteroid sizes, splitting, wave system, scoring
 
+use crate::collision::{Collider, Shape};
+use crate::difficulty::{Difficulty, WaveSettings};
+use crate::entity::{Body, Render, Update};
 use crate::physics::{self, Vec2};
+use crate::renderer::Playfield;
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
 
+pub const SPLIT_SPEED: f64 = 1.2; // children fly slightly faster than their parent
+
 /// Asteroid size determines radius, point value, and split behavior.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum AsteroidSize {
     Large,
     Medium,
@@ -29,6 +37,15 @@ impl AsteroidSize {
         }
     }
 
+    /// Mass for bouncing off a shielded ship, which weighs as much as a small rock.
+    pub fn mass(self) -> f64 {
+        match self {
+            AsteroidSize::Large => 16.0,
+            AsteroidSize::Medium => 4.0,
+            AsteroidSize::Small => 1.0,
+        }
+    }
+
     pub fn split_into(self) -> Option<AsteroidSize> {
         match self {
             AsteroidSize::Large => Some(AsteroidSize::Medium),
@@ -38,6 +55,7 @@ impl AsteroidSize {
     }
 }
 
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Asteroid {
     pub position: Vec2,
     pub velocity: Vec2,
@@ -83,13 +101,6 @@ impl Asteroid {
         }
     }
 
-    /// Update position and rotation.
-    pub fn update(&mut self, dt: f64, world_width: f64, world_height: f64) {
-        self.position = physics::integrate_motion(self.position, self.velocity, dt);
-        self.position = physics::wrap_position(self.position, world_width, world_height);
-        self.rotation = physics::rotate_angle(self.rotation, self.angular_velocity, dt);
-    }
-
     /// Get world-space vertices (rotated and translated).
     pub fn world_vertices(&self) -> Vec<Vec2> {
         let cos_r = self.rotation.cos();
@@ -107,9 +118,26 @@ impl Asteroid {
 
     /// Split this asteroid into two children. Returns None for small asteroids.
     pub fn split<R: Rng>(&self, rng: &mut R) -> Option<[Asteroid; 2]> {
-        let child_size = self.size.split_into()?;
-
-        let speed = self.velocity.magnitude() * 1.2; // slightly faster
+        self.split_at_speed(SPLIT_SPEED, rng)
+    }
+
+    /// Split with the children flying `speed_multiplier` times as fast as this rock.
+    pub fn split_at_speed<R: Rng>(
+        &self,
+        speed_multiplier: f64,
+        rng: &mut R,
+    ) -> Option<[Asteroid; 2]> {
+        Some(self.break_into(self.size.split_into()?, speed_multiplier, rng))
+    }
+
+    /// Break into two `child_size` rocks flying `speed_multiplier` times as fast.
+    pub fn break_into<R: Rng>(
+        &self,
+        child_size: AsteroidSize,
+        speed_multiplier: f64,
+        rng: &mut R,
+    ) -> [Asteroid; 2] {
+        let speed = self.velocity.magnitude() * speed_multiplier;
         let base_angle = self.velocity.y.atan2(self.velocity.x);
 
         let angle1 = base_angle + rng.gen_range(0.3..0.8);
@@ -121,7 +149,48 @@ impl Asteroid {
         let child1 = Asteroid::new(self.position, vel1, child_size, rng);
         let child2 = Asteroid::new(self.position, vel2, child_size, rng);
 
-        Some([child1, child2])
+        [child1, child2]
+    }
+}
+
+impl Update for Asteroid {
+    /// Update position and rotation.
+    fn update(&mut self, dt: f64, world_width: f64, world_height: f64) {
+        self.position = physics::integrate_motion(self.position, self.velocity, dt);
+        self.position = physics::wrap_position(self.position, world_width, world_height);
+        self.rotation = physics::rotate_angle(self.rotation, self.angular_velocity, dt);
+    }
+}
+
+impl Render for Asteroid {
+    fn render(&self, playfield: &mut Playfield, _blink: bool) {
+        playfield.add_polygon(&self.world_vertices());
+    }
+}
+
+impl Collider for Asteroid {
+    fn position(&self) -> Vec2 {
+        self.position
+    }
+
+    fn shape(&self) -> Shape {
+        Shape::Circle {
+            radius: self.size.radius(),
+        }
+    }
+}
+
+impl Body for Asteroid {
+    fn velocity(&self) -> Vec2 {
+        self.velocity
+    }
+
+    fn set_velocity(&mut self, velocity: Vec2) {
+        self.velocity = velocity;
+    }
+
+    fn mass(&self) -> f64 {
+        self.size.mass()
     }
 }
 
@@ -151,27 +220,39 @@ pub fn spawn_wave<R: Rng>(
     world_height: f64,
     rng: &mut R,
 ) -> Vec<Asteroid> {
-    let count = wave_asteroid_count(wave);
+    spawn_wave_with(
+        &Difficulty::Normal.settings(),
+        wave,
+        ship_position,
+        min_distance,
+        world_width,
+        world_height,
+        rng,
+    )
+}
+
+/// Spawn a wave sized and paced by a difficulty's settings, away from the ship.
+pub fn spawn_wave_with<R: Rng>(
+    settings: &WaveSettings,
+    wave: u32,
+    ship_position: Vec2,
+    min_distance: f64,
+    world_width: f64,
+    world_height: f64,
+    rng: &mut R,
+) -> Vec<Asteroid> {
+    let count = settings.asteroid_count(wave);
+    let (min_speed, max_speed) = settings.speed_range(wave);
     let mut asteroids = Vec::with_capacity(count as usize);
 
     for _ in 0..count {
-        let pos = loop {
-            let candidate = Vec2::new(
-                rng.gen_range(0.0..world_width),
-                rng.gen_range(0.0..world_height),
-            );
-            let dx = (candidate.x - ship_position.x)
-                .abs()
-                .min(world_width - (candidate.x - ship_position.x).abs());
-            let dy = (candidate.y - ship_position.y)
-                .abs()
-                .min(world_height - (candidate.y - ship_position.y).abs());
-            if (dx * dx + dy * dy).sqrt() >= min_distance {
-                break candidate;
-            }
-        };
+        let pos = spawn_position(ship_position, min_distance, world_width, world_height, rng);
 
-        let speed = rng.gen_range(20.0..80.0);
+        let speed = if min_speed < max_speed {
+            rng.gen_range(min_speed..max_speed)
+        } else {
+            max_speed
+        };
         let angle = rng.gen_range(0.0..(2.0 * PI));
         let velocity = Vec2::from_angle(angle).scale(speed);
 
@@ -181,6 +262,31 @@ pub fn spawn_wave<R: Rng>(
     asteroids
 }
 
+/// A random point at least `min_distance` from the ship, across the wrap.
+pub fn spawn_position<R: Rng>(
+    ship_position: Vec2,
+    min_distance: f64,
+    world_width: f64,
+    world_height: f64,
+    rng: &mut R,
+) -> Vec2 {
+    loop {
+        let candidate = Vec2::new(
+            rng.gen_range(0.0..world_width),
+            rng.gen_range(0.0..world_height),
+        );
+        let dx = (candidate.x - ship_position.x)
+            .abs()
+            .min(world_width - (candidate.x - ship_position.x).abs());
+        let dy = (candidate.y - ship_position.y)
+            .abs()
+            .min(world_height - (candidate.y - ship_position.y).abs());
+        if (dx * dx + dy * dy).sqrt() >= min_distance {
+            return candidate;
+        }
+    }
+}
+
 /// Score for destroying an asteroid.
 pub fn score_for_size(size: AsteroidSize) -> u32 {
     size.points()
@@ -211,6 +317,7 @@ mod tests {
     fn test_large_asteroid_properties() {
         assert_eq!(AsteroidSize::Large.radius(), 40.0);
         assert_eq!(AsteroidSize::Large.points(), 20);
+        assert_eq!(AsteroidSize::Large.mass(), 16.0);
     }
 
     // Scenario: Medium asteroid properties
@@ -218,6 +325,7 @@ mod tests {
     fn test_medium_asteroid_properties() {
         assert_eq!(AsteroidSize::Medium.radius(), 20.0);
         assert_eq!(AsteroidSize::Medium.points(), 50);
+        assert_eq!(AsteroidSize::Medium.mass(), 4.0);
     }
 
     // Scenario: Small asteroid properties
@@ -225,6 +333,7 @@ mod tests {
     fn test_small_asteroid_properties() {
         assert_eq!(AsteroidSize::Small.radius(), 10.0);
         assert_eq!(AsteroidSize::Small.points(), 100);
+        assert_eq!(AsteroidSize::Small.mass(), 1.0);
     }
 
     // === Requirement: Asteroid Shape Generation ===
@@ -447,6 +556,71 @@ mod tests {
         assert_eq!(asteroids.len(), 6); // wave 3: 3 + 3 = 6
     }
 
+    // Scenario: Difficulty sets wave size and speed
+    #[test]
+    fn test_spawn_wave_with_difficulty() {
+        let insane = Difficulty::Insane.settings();
+        let mut rng = test_rng();
+        let asteroids = spawn_wave_with(
+            &insane,
+            3,
+            Vec2::new(400.0, 300.0),
+            150.0,
+            800.0,
+            600.0,
+            &mut rng,
+        );
+        assert_eq!(asteroids.len(), 10);
+        let (min, max) = insane.speed_range(3);
+        for a in &asteroids {
+            let speed = a.velocity.magnitude();
+            assert!(
+                speed >= min - 1e-9 && speed <= max + 1e-9,
+                "speed {}",
+                speed
+            );
+        }
+
+        // Normal is the classic wave, draw for draw
+        let classic = spawn_wave(
+            2,
+            Vec2::new(400.0, 300.0),
+            150.0,
+            800.0,
+            600.0,
+            &mut test_rng(),
+        );
+        let normal = spawn_wave_with(
+            &Difficulty::Normal.settings(),
+            2,
+            Vec2::new(400.0, 300.0),
+            150.0,
+            800.0,
+            600.0,
+            &mut test_rng(),
+        );
+        assert_eq!(classic.len(), normal.len());
+        for (a, b) in classic.iter().zip(&normal) {
+            assert_eq!((a.position, a.velocity), (b.position, b.velocity));
+        }
+    }
+
+    // Scenario: Split speed follows the difficulty
+    #[test]
+    fn test_split_at_speed() {
+        let a = Asteroid::new_with_shape(
+            Vec2::new(300.0, 300.0),
+            Vec2::new(100.0, 0.0),
+            AsteroidSize::Large,
+            0.0,
+            Vec::new(),
+        );
+        let children = a.split_at_speed(1.5, &mut test_rng()).unwrap();
+        assert!((children[0].velocity.magnitude() - 150.0).abs() < 1e-9);
+        let children = a.split(&mut test_rng()).unwrap();
+        assert!((children[1].velocity.magnitude() - 100.0 * SPLIT_SPEED).abs() < 1e-9);
+    }
+
     // === Requirement: Asteroid Scoring ===
 
     // Scenario: Destroying large asteroid awards 20 points
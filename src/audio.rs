@@ -1,6 +1,7 @@
 // Audio capability: event types, engine, procedural synthesis
 
-use crate::game::GameState;
+use crate::events::{Destroyer, GameEvent};
+use crate::pickups;
 use rodio::{OutputStream, OutputStreamHandle, Sink};
 use std::time::Duration;
 
@@ -16,13 +17,37 @@ pub enum AudioEvent {
     ShipDestroyed,
     ExtraLife,
     NewWave,
+    PowerUp,
+    SmartBomb,
+    ShieldBounce,
 }
 
-/// Result of a PlayingState::update() call, containing both state transition
-/// and audio events.
-pub struct UpdateResult {
-    pub state: Option<GameState>,
-    pub audio_events: Vec<AudioEvent>,
+impl AudioEvent {
+    /// The sound a game event makes, if any. Rocks a smart bomb destroys go up in its
+    /// blast rather than exploding one by one.
+    pub fn from_event(event: &GameEvent) -> Option<Self> {
+        match *event {
+            GameEvent::ShipFlew { thrusting, .. } => thrusting.then_some(AudioEvent::Thrust),
+            GameEvent::BulletFired { .. } => Some(AudioEvent::Fire),
+            GameEvent::AsteroidDestroyed {
+                size,
+                by: Destroyer::Bullet { .. },
+                ..
+            } => Some(crate::game::asteroid_explosion_event(size)),
+            GameEvent::AsteroidDestroyed { .. } => None,
+            GameEvent::ShipDestroyed { .. } => Some(AudioEvent::ShipDestroyed),
+            GameEvent::ShieldBounce { .. } => Some(AudioEvent::ShieldBounce),
+            GameEvent::PowerUpCollected {
+                kind: pickups::PowerUp::SmartBomb,
+                ..
+            } => Some(AudioEvent::SmartBomb),
+            GameEvent::PowerUpCollected { .. } => Some(AudioEvent::PowerUp),
+            GameEvent::PointsAwarded { .. } => None,
+            GameEvent::ExtraLife { .. } => Some(AudioEvent::ExtraLife),
+            GameEvent::WaveCleared { .. } => None,
+            GameEvent::WaveStarted { .. } => Some(AudioEvent::NewWave),
+        }
+    }
 }
 
 /// Audio engine that plays sounds for game events.
@@ -79,6 +104,9 @@ impl AudioEngine {
             AudioEvent::ShipDestroyed => synth_ship_destroyed(),
             AudioEvent::ExtraLife => synth_extra_life(),
             AudioEvent::NewWave => synth_new_wave(),
+            AudioEvent::PowerUp => synth_power_up(),
+            AudioEvent::SmartBomb => synth_smart_bomb(),
+            AudioEvent::ShieldBounce => synth_shield_bounce(),
         };
 
         // Play on a detached sink so it doesn't block
@@ -216,6 +244,37 @@ fn synth_new_wave() -> SynthSource {
     })
 }
 
+/// Power-up collected: quick rising arpeggio
+#[cfg(not(tarpaulin_include))]
+fn synth_power_up() -> SynthSource {
+    SynthSource::new(44100, 0.24, |t| {
+        let step = (t / 0.06).floor().min(3.0);
+        let freq = 660.0 * 2f32.powf(step / 4.0);
+        (t * freq * std::f32::consts::TAU).sin()
+    })
+}
+
+/// Smart bomb: deep boom with a falling whine
+#[cfg(not(tarpaulin_include))]
+fn synth_smart_bomb() -> SynthSource {
+    SynthSource::new(44100, 0.8, |t| {
+        let boom = (t * 60.0 * (1.0 - t).max(0.0) * std::f32::consts::TAU).sin();
+        let whine = (t * (1200.0 - 1000.0 * t) * std::f32::consts::TAU).sin() * 0.4;
+        boom + whine
+    })
+}
+
+/// Shield bounce: short metallic ping
+#[cfg(not(tarpaulin_include))]
+fn synth_shield_bounce() -> SynthSource {
+    SynthSource::new(44100, 0.15, |t| {
+        let decay = (1.0 - t / 0.15).max(0.0);
+        let ring = (t * 1400.0 * std::f32::consts::TAU).sin();
+        let body = (t * 350.0 * std::f32::consts::TAU).sin() * 0.5;
+        (ring + body) * decay
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -224,6 +283,7 @@ mod tests {
 
     // Scenario: All event variants are distinct
     #[test]
+    #[allow(clippy::useless_vec)]
     fn test_audio_event_variants_distinct() {
         let events = vec![
             AudioEvent::Fire,
@@ -234,6 +294,9 @@ mod tests {
             AudioEvent::ShipDestroyed,
             AudioEvent::ExtraLife,
             AudioEvent::NewWave,
+            AudioEvent::PowerUp,
+            AudioEvent::SmartBomb,
+            AudioEvent::ShieldBounce,
         ];
         // Each variant is distinct from all others
         for (i, a) in events.iter().enumerate() {
@@ -256,6 +319,9 @@ mod tests {
         let ship = AudioEvent::ShipDestroyed;
         let extra = AudioEvent::ExtraLife;
         let wave = AudioEvent::NewWave;
+        let power_up = AudioEvent::PowerUp;
+        let bomb = AudioEvent::SmartBomb;
+        let bounce = AudioEvent::ShieldBounce;
 
         assert!(matches!(fire, AudioEvent::Fire));
         assert!(matches!(thrust, AudioEvent::Thrust));
@@ -265,6 +331,9 @@ mod tests {
         assert!(matches!(ship, AudioEvent::ShipDestroyed));
         assert!(matches!(extra, AudioEvent::ExtraLife));
         assert!(matches!(wave, AudioEvent::NewWave));
+        assert!(matches!(power_up, AudioEvent::PowerUp));
+        assert!(matches!(bomb, AudioEvent::SmartBomb));
+        assert!(matches!(bounce, AudioEvent::ShieldBounce));
     }
 
     // Scenario: AudioEvent is non-exhaustive for future extension
@@ -283,6 +352,9 @@ mod tests {
             AudioEvent::ShipDestroyed => "ship",
             AudioEvent::ExtraLife => "extra",
             AudioEvent::NewWave => "wave",
+            AudioEvent::PowerUp => "power-up",
+            AudioEvent::SmartBomb => "bomb",
+            AudioEvent::ShieldBounce => "bounce",
             _ => "unknown",
         };
     }
@@ -297,49 +369,58 @@ mod tests {
         assert!(!debug.is_empty());
     }
 
-    // === UpdateResult tests ===
-
-    #[test]
-    fn test_update_result_with_no_state_change() {
-        let result = UpdateResult {
-            state: None,
-            audio_events: vec![AudioEvent::Fire],
-        };
-        assert!(result.state.is_none());
-        assert_eq!(result.audio_events.len(), 1);
-        assert_eq!(result.audio_events[0], AudioEvent::Fire);
-    }
+    // === Requirement: Audio Event Mapping ===
 
+    // Scenario: Game events map to their sounds
     #[test]
-    fn test_update_result_with_state_change() {
-        let result = UpdateResult {
-            state: Some(GameState::GameOver),
-            audio_events: vec![AudioEvent::ShipDestroyed],
-        };
-        assert_eq!(result.state, Some(GameState::GameOver));
-        assert_eq!(result.audio_events[0], AudioEvent::ShipDestroyed);
+    fn test_from_event() {
+        let sound = |event: GameEvent| AudioEvent::from_event(&event);
+        assert_eq!(
+            sound(GameEvent::ShieldBounce { player: 1 }),
+            Some(AudioEvent::ShieldBounce)
+        );
+        assert_eq!(
+            sound(GameEvent::ExtraLife { player: 0 }),
+            Some(AudioEvent::ExtraLife)
+        );
+        assert_eq!(
+            sound(GameEvent::WaveStarted { wave: 2 }),
+            Some(AudioEvent::NewWave)
+        );
+        assert_eq!(
+            sound(GameEvent::PowerUpCollected {
+                player: 0,
+                kind: pickups::PowerUp::SmartBomb
+            }),
+            Some(AudioEvent::SmartBomb)
+        );
+        assert_eq!(
+            sound(GameEvent::PowerUpCollected {
+                player: 0,
+                kind: pickups::PowerUp::RapidFire
+            }),
+            Some(AudioEvent::PowerUp)
+        );
     }
 
+    // Scenario: Some game events are silent
     #[test]
-    fn test_update_result_with_empty_events() {
-        let result = UpdateResult {
-            state: None,
-            audio_events: vec![],
+    fn test_silent_events() {
+        let bombed = GameEvent::AsteroidDestroyed {
+            size: crate::asteroids::AsteroidSize::Medium,
+            position: crate::physics::Vec2::new(0.0, 0.0),
+            by: Destroyer::SmartBomb { player: 0 },
         };
-        assert!(result.audio_events.is_empty());
-    }
-
-    #[test]
-    fn test_update_result_with_multiple_events() {
-        let result = UpdateResult {
-            state: None,
-            audio_events: vec![
-                AudioEvent::Fire,
-                AudioEvent::AsteroidExplosionLarge,
-                AudioEvent::Thrust,
-            ],
+        let coasting = GameEvent::ShipFlew {
+            player: 0,
+            dt: 0.1,
+            speed: 5.0,
+            thrusting: false,
         };
-        assert_eq!(result.audio_events.len(), 3);
+        let cleared = GameEvent::WaveCleared { player: 0 };
+        for event in [bombed, coasting, cleared] {
+            assert_eq!(AudioEvent::from_event(&event), None);
+        }
     }
 
     // === Requirement: Audio Engine Initialization ===
@@ -0,0 +1,590 @@
+// Bot capability: JSON-lines protocol for external bots over stdin/stdout or a Unix socket
+//
+// Each tick the game writes one Observation as a single JSON line and waits for the
+// bot to reply with one InputState as a single JSON line, e.g. {"thrust":true}.
+// Omitted actions are inactive. The simulation only advances after the reply, so the
+// bot and the game run in lockstep. When the game ends a final observation with
+// "done": true is sent and the session closes.
+
+use crate::asteroids::{Asteroid, AsteroidSize};
+use crate::bullets::Bullet;
+use crate::controller::Controller;
+use crate::game::{GameState, PlayingState, TIMESTEP};
+use crate::input::InputState;
+use serde::{Deserialize, Serialize};
+use std::io::{self, BufRead, Write};
+
+/// Ship pose and status as seen by a bot.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShipObservation {
+    pub x: f64,
+    pub y: f64,
+    pub vx: f64,
+    pub vy: f64,
+    pub rotation: f64,
+    pub lives: u32,
+    pub invulnerable: bool,
+}
+
+/// An asteroid with its world-space outline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AsteroidObservation {
+    pub x: f64,
+    pub y: f64,
+    pub vx: f64,
+    pub vy: f64,
+    pub size: AsteroidSize,
+    pub radius: f64,
+    pub vertices: Vec<[f64; 2]>,
+}
+
+/// A live bullet.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BulletObservation {
+    pub x: f64,
+    pub y: f64,
+    pub vx: f64,
+    pub vy: f64,
+}
+
+/// Everything a bot sees on one tick.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Observation {
+    pub tick: u64,
+    pub world_width: f64,
+    pub world_height: f64,
+    pub score: u32,
+    pub wave: u32,
+    pub ship: ShipObservation,
+    pub asteroids: Vec<AsteroidObservation>,
+    pub bullets: Vec<BulletObservation>,
+    pub done: bool,
+}
+
+impl Observation {
+    /// Capture the observable state of a game.
+    pub fn capture(
+        state: &PlayingState,
+        world_width: f64,
+        world_height: f64,
+        tick: u64,
+        done: bool,
+    ) -> Self {
+        let ship = &state.ship;
+        Self {
+            tick,
+            world_width,
+            world_height,
+            score: state.score,
+            wave: state.wave,
+            ship: ShipObservation {
+                x: ship.position.x,
+                y: ship.position.y,
+                vx: ship.velocity.x,
+                vy: ship.velocity.y,
+                rotation: ship.rotation,
+                lives: ship.lives,
+                invulnerable: ship.invulnerable,
+            },
+            asteroids: state.asteroids.iter().map(observe_asteroid).collect(),
+            bullets: state
+                .bullet_pool
+                .bullets
+                .iter()
+                .filter(|b| b.alive)
+                .map(observe_bullet)
+                .collect(),
+            done,
+        }
+    }
+}
+
+fn observe_asteroid(asteroid: &Asteroid) -> AsteroidObservation {
+    AsteroidObservation {
+        x: asteroid.position.x,
+        y: asteroid.position.y,
+        vx: asteroid.velocity.x,
+        vy: asteroid.velocity.y,
+        size: asteroid.size,
+        radius: asteroid.size.radius(),
+        vertices: asteroid
+            .world_vertices()
+            .iter()
+            .map(|v| [v.x, v.y])
+            .collect(),
+    }
+}
+
+fn observe_bullet(bullet: &Bullet) -> BulletObservation {
+    BulletObservation {
+        x: bullet.position.x,
+        y: bullet.position.y,
+        vx: bullet.velocity.x,
+        vy: bullet.velocity.y,
+    }
+}
+
+/// A controller backed by an external bot speaking the JSON-lines protocol.
+/// If the bot disconnects or sends a malformed reply, the controller records the
+/// error, reports finished, and asks the game to quit.
+pub struct BotController<R: BufRead, W: Write> {
+    reader: R,
+    writer: W,
+    tick: u64,
+    error: Option<String>,
+}
+
+impl<R: BufRead, W: Write> BotController<R, W> {
+    pub fn new(reader: R, writer: W) -> Self {
+        Self {
+            reader,
+            writer,
+            tick: 0,
+            error: None,
+        }
+    }
+
+    /// The protocol error that ended the session, if any.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Number of observations answered so far.
+    pub fn ticks(&self) -> u64 {
+        self.tick
+    }
+
+    /// Send the final observation with `done` set.
+    pub fn finish(&mut self, state: &PlayingState, world_width: f64, world_height: f64) {
+        let obs = Observation::capture(state, world_width, world_height, self.tick, true);
+        if let Err(e) = self.send(&obs) {
+            self.error.get_or_insert(e);
+        }
+    }
+
+    fn send(&mut self, obs: &Observation) -> Result<(), String> {
+        let line = serde_json::to_string(obs).map_err(|e| e.to_string())?;
+        writeln!(self.writer, "{}", line)
+            .and_then(|_| self.writer.flush())
+            .map_err(|e| format!("bot write failed: {}", e))
+    }
+
+    fn exchange(&mut self, obs: &Observation) -> Result<InputState, String> {
+        self.send(obs)?;
+        let mut line = String::new();
+        let read = self
+            .reader
+            .read_line(&mut line)
+            .map_err(|e| format!("bot read failed: {}", e))?;
+        if read == 0 {
+            return Err("bot disconnected".to_string());
+        }
+        serde_json::from_str(line.trim())
+            .map_err(|e| format!("invalid bot input on tick {}: {}", self.tick, e))
+    }
+}
+
+impl<R: BufRead, W: Write> Controller for BotController<R, W> {
+    fn next_input(
+        &mut self,
+        state: &PlayingState,
+        world_width: f64,
+        world_height: f64,
+    ) -> InputState {
+        let quit = InputState {
+            quit: true,
+            ..Default::default()
+        };
+        if self.error.is_some() {
+            return quit;
+        }
+        let obs = Observation::capture(state, world_width, world_height, self.tick, false);
+        match self.exchange(&obs) {
+            Ok(input) => {
+                self.tick += 1;
+                input
+            }
+            Err(e) => {
+                self.error = Some(e);
+                quit
+            }
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.error.is_some()
+    }
+}
+
+/// Outcome of a headless bot session.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionSummary {
+    pub ticks: u64,
+    pub score: u32,
+    pub wave: u32,
+    pub game_over: bool,
+}
+
+/// Run a headless lockstep session until game over, the bot quits, or `max_ticks`.
+/// Returns an error if the bot broke the protocol.
+pub fn run_session<R: BufRead, W: Write>(
+    bot: &mut BotController<R, W>,
+    state: &mut PlayingState,
+    world_width: f64,
+    world_height: f64,
+    max_ticks: Option<u64>,
+) -> Result<SessionSummary, String> {
+    let mut ticks = 0;
+    let mut game_over = false;
+    while max_ticks.is_none_or(|max| ticks < max) {
+        let input = bot.next_input(state, world_width, world_height);
+        if let Some(e) = bot.error() {
+            return Err(e.to_string());
+        }
+        let result = state.update(&input, TIMESTEP, world_width, world_height);
+        ticks += 1;
+        match result.state {
+            Some(GameState::GameOver) => {
+                game_over = true;
+                break;
+            }
+            Some(_) => break,
+            None => {}
+        }
+    }
+    bot.finish(state, world_width, world_height);
+    Ok(SessionSummary {
+        ticks,
+        score: state.score,
+        wave: state.wave,
+        game_over,
+    })
+}
+
+/// Serve one bot session over stdin/stdout. The summary goes to stderr.
+#[cfg(not(tarpaulin_include))]
+pub fn run_stdio(seed: u64, max_ticks: Option<u64>) -> io::Result<()> {
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    let mut bot = BotController::new(stdin.lock(), stdout.lock());
+    serve(&mut bot, seed, max_ticks)
+}
+
+/// Listen on a Unix domain socket and serve one bot session to the first client.
+#[cfg(all(unix, not(tarpaulin_include)))]
+pub fn run_unix_socket(
+    path: &std::path::Path,
+    seed: u64,
+    max_ticks: Option<u64>,
+) -> io::Result<()> {
+    use std::os::unix::net::UnixListener;
+
+    let listener = UnixListener::bind(path)?;
+    eprintln!("Waiting for a bot on {}", path.display());
+    let (stream, _) = listener.accept()?;
+    let reader = io::BufReader::new(stream.try_clone()?);
+    let mut bot = BotController::new(reader, stream);
+    let result = serve(&mut bot, seed, max_ticks);
+    let _ = std::fs::remove_file(path);
+    result
+}
+
+#[cfg(not(tarpaulin_include))]
+fn serve<R: BufRead, W: Write>(
+    bot: &mut BotController<R, W>,
+    seed: u64,
+    max_ticks: Option<u64>,
+) -> io::Result<()> {
+    use crate::game::{WORLD_HEIGHT, WORLD_WIDTH};
+
+    let mut state = PlayingState::new_seeded(WORLD_WIDTH, WORLD_HEIGHT, seed);
+    let summary = run_session(bot, &mut state, WORLD_WIDTH, WORLD_HEIGHT, max_ticks)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    eprintln!(
+        "Bot session (seed {}): {} ticks, score {}, wave {}{}",
+        seed,
+        summary.ticks,
+        summary.score,
+        summary.wave,
+        if summary.game_over { ", game over" } else { "" }
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::Vec2;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::io::{BufReader, Cursor};
+
+    fn state() -> PlayingState {
+        PlayingState::new_seeded(800.0, 600.0, 42)
+    }
+
+    fn reply_lines(lines: &[&str]) -> Cursor<Vec<u8>> {
+        let mut text = lines.join("\n");
+        text.push('\n');
+        Cursor::new(text.into_bytes())
+    }
+
+    fn sent_observations(written: &[u8]) -> Vec<Observation> {
+        String::from_utf8(written.to_vec())
+            .unwrap()
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect()
+    }
+
+    // === Requirement: Bot Observation ===
+
+    // Scenario: Observation captures ship, asteroids, bullets, score and lives
+    #[test]
+    fn test_observation_captures_state() {
+        let mut playing = state();
+        playing.score = 120;
+        playing.ship.velocity = Vec2::new(3.0, -4.0);
+        playing.bullet_pool.fire(Vec2::new(10.0, 20.0), 0.0);
+        let mut dead = Bullet::new(Vec2::new(0.0, 0.0), 0.0);
+        dead.alive = false;
+        playing.bullet_pool.bullets.push(dead);
+
+        let obs = Observation::capture(&playing, 800.0, 600.0, 7, false);
+        assert_eq!(obs.tick, 7);
+        assert_eq!(obs.score, 120);
+        assert_eq!(obs.wave, 1);
+        assert_eq!(obs.ship.x, playing.ship.position.x);
+        assert_eq!(obs.ship.vx, 3.0);
+        assert_eq!(obs.ship.vy, -4.0);
+        assert_eq!(obs.ship.lives, 3);
+        assert_eq!(obs.asteroids.len(), playing.asteroids.len());
+        assert_eq!(obs.bullets.len(), 1);
+        assert_eq!(obs.bullets[0].x, 10.0);
+        assert!(!obs.done);
+    }
+
+    // Scenario: Asteroid observations include world-space vertices
+    #[test]
+    fn test_observation_asteroid_vertices() {
+        let mut playing = state();
+        let mut rng = StdRng::seed_from_u64(1);
+        playing.asteroids = vec![Asteroid::new(
+            Vec2::new(100.0, 200.0),
+            Vec2::new(5.0, 0.0),
+            AsteroidSize::Medium,
+            &mut rng,
+        )];
+        let obs = Observation::capture(&playing, 800.0, 600.0, 0, false);
+        let asteroid = &obs.asteroids[0];
+        assert_eq!(asteroid.size, AsteroidSize::Medium);
+        assert_eq!(asteroid.radius, 20.0);
+        let expected = playing.asteroids[0].world_vertices();
+        assert_eq!(asteroid.vertices.len(), expected.len());
+        assert_eq!(asteroid.vertices[0], [expected[0].x, expected[0].y]);
+    }
+
+    // Scenario: Observation serializes as a single JSON line
+    #[test]
+    fn test_observation_json_line() {
+        let obs = Observation::capture(&state(), 800.0, 600.0, 0, false);
+        let json = serde_json::to_string(&obs).unwrap();
+        assert!(!json.contains('\n'));
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        for key in [
+            "tick",
+            "score",
+            "wave",
+            "ship",
+            "asteroids",
+            "bullets",
+            "done",
+        ] {
+            assert!(value.get(key).is_some(), "missing key {}", key);
+        }
+        assert_eq!(value["asteroids"][0]["size"], "large");
+    }
+
+    // === Requirement: Bot Input ===
+
+    // Scenario: Omitted actions are inactive
+    #[test]
+    fn test_input_partial_json() {
+        let input: InputState = serde_json::from_str(r#"{"thrust":true}"#).unwrap();
+        assert_eq!(
+            input,
+            InputState {
+                thrust: true,
+                ..Default::default()
+            }
+        );
+        let none: InputState = serde_json::from_str("{}").unwrap();
+        assert_eq!(none, InputState::default());
+    }
+
+    // Scenario: Bot controller sends an observation and reads the reply
+    #[test]
+    fn test_bot_controller_exchange() {
+        let mut written = Vec::new();
+        let input = {
+            let mut bot = BotController::new(reply_lines(&[r#"{"fire":true}"#]), &mut written);
+            let input = bot.next_input(&state(), 800.0, 600.0);
+            assert_eq!(bot.ticks(), 1);
+            assert!(!bot.is_finished());
+            input
+        };
+        assert!(input.fire);
+        let sent = sent_observations(&written);
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].tick, 0);
+    }
+
+    // Scenario: Malformed reply ends the session with an error
+    #[test]
+    fn test_bot_controller_malformed_reply() {
+        let mut bot = BotController::new(reply_lines(&["not json"]), Vec::new());
+        let input = bot.next_input(&state(), 800.0, 600.0);
+        assert!(input.quit);
+        assert!(bot.is_finished());
+        assert!(bot.error().unwrap().contains("tick 0"));
+        // Further ticks keep quitting without touching the streams
+        assert!(bot.next_input(&state(), 800.0, 600.0).quit);
+    }
+
+    // Scenario: Disconnected bot ends the session with an error
+    #[test]
+    fn test_bot_controller_disconnect() {
+        let mut bot = BotController::new(Cursor::new(Vec::new()), Vec::new());
+        assert!(bot.next_input(&state(), 800.0, 600.0).quit);
+        assert_eq!(bot.error(), Some("bot disconnected"));
+    }
+
+    // Scenario: Write failure ends the session with an error
+    #[test]
+    fn test_bot_controller_write_failure() {
+        struct Broken;
+        impl Write for Broken {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"))
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+        let mut bot = BotController::new(reply_lines(&["{}"]), Broken);
+        assert!(bot.next_input(&state(), 800.0, 600.0).quit);
+        assert!(bot.error().unwrap().starts_with("bot write failed"));
+        bot.finish(&state(), 800.0, 600.0);
+        assert!(bot.error().unwrap().starts_with("bot write failed"));
+    }
+
+    // Scenario: Read failure ends the session with an error
+    #[test]
+    fn test_bot_controller_read_failure() {
+        struct Broken;
+        impl io::Read for Broken {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::new(io::ErrorKind::ConnectionReset, "reset"))
+            }
+        }
+        let mut bot = BotController::new(BufReader::new(Broken), Vec::new());
+        assert!(bot.next_input(&state(), 800.0, 600.0).quit);
+        assert!(bot.error().unwrap().starts_with("bot read failed"));
+    }
+
+    // === Requirement: Lockstep Bot Session ===
+
+    // Scenario: Session advances one tick per reply and sends a final observation
+    #[test]
+    fn test_session_lockstep_max_ticks() {
+        let mut written = Vec::new();
+        let mut playing = state();
+        let summary = {
+            let mut bot = BotController::new(reply_lines(&["{}", "{}", "{}"]), &mut written);
+            run_session(&mut bot, &mut playing, 800.0, 600.0, Some(3)).unwrap()
+        };
+        assert_eq!(summary.ticks, 3);
+        assert!(!summary.game_over);
+        assert_eq!(playing.frame_count, 3);
+        let sent = sent_observations(&written);
+        let ticks: Vec<u64> = sent.iter().map(|o| o.tick).collect();
+        assert_eq!(ticks, vec![0, 1, 2, 3]);
+        assert!(sent.last().unwrap().done);
+    }
+
+    // Scenario: Session ends on game over
+    #[test]
+    fn test_session_ends_on_game_over() {
+        let mut playing = state();
+        let mut rng = StdRng::seed_from_u64(1);
+        playing.asteroids = vec![Asteroid::new(
+            playing.ship.position,
+            Vec2::new(0.0, 0.0),
+            AsteroidSize::Large,
+            &mut rng,
+        )];
+        playing.ship.lives = 1;
+        let mut bot = BotController::new(reply_lines(&["{}"]), Vec::new());
+        let summary = run_session(&mut bot, &mut playing, 800.0, 600.0, None).unwrap();
+        assert!(summary.game_over);
+        assert_eq!(summary.ticks, 1);
+    }
+
+    // Scenario: Session ends when the bot quits
+    #[test]
+    fn test_session_ends_on_bot_quit() {
+        let mut playing = state();
+        let mut bot = BotController::new(reply_lines(&["{}", r#"{"quit":true}"#]), Vec::new());
+        let summary = run_session(&mut bot, &mut playing, 800.0, 600.0, None).unwrap();
+        assert_eq!(summary.ticks, 2);
+        assert!(!summary.game_over);
+    }
+
+    // Scenario: Session reports a protocol error
+    #[test]
+    fn test_session_protocol_error() {
+        let mut playing = state();
+        let mut bot = BotController::new(reply_lines(&["{}", "oops"]), Vec::new());
+        let err = run_session(&mut bot, &mut playing, 800.0, 600.0, None).unwrap_err();
+        assert!(err.contains("tick 1"));
+        assert_eq!(playing.frame_count, 1);
+    }
+
+    // Scenario: A stub bot drives the game over a Unix socket
+    #[cfg(unix)]
+    #[test]
+    fn test_session_over_unix_socket() {
+        use std::os::unix::net::UnixStream;
+
+        let (server, client) = UnixStream::pair().unwrap();
+        // Stub bot: fire on every tick, stop when told the game is done
+        let stub = std::thread::spawn(move || {
+            let mut reader = BufReader::new(client.try_clone().unwrap());
+            let mut writer = client;
+            let mut seen = Vec::new();
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap() == 0 {
+                    break;
+                }
+                let obs: Observation = serde_json::from_str(&line).unwrap();
+                seen.push(obs.tick);
+                if obs.done {
+                    break;
+                }
+                writeln!(writer, r#"{{"fire":true}}"#).unwrap();
+            }
+            seen
+        });
+
+        let mut playing = state();
+        let reader = BufReader::new(server.try_clone().unwrap());
+        let mut bot = BotController::new(reader, server);
+        let summary = run_session(&mut bot, &mut playing, 800.0, 600.0, Some(10)).unwrap();
+        drop(bot);
+        let seen = stub.join().unwrap();
+        assert_eq!(summary.ticks, 10);
+        assert_eq!(seen, (0..=10).collect::<Vec<u64>>());
+        assert!(!playing.bullet_pool.bullets.is_empty());
+    }
+}
@@ -1,45 +1,119 @@
 // Bullets capability: projectile creation, lifetime, speed, screen limit
 
+use crate::collision::{Collider, Shape};
+use crate::entity::{self, Render, Update};
 use crate::physics::{self, Vec2};
+use crate::renderer::Playfield;
+use serde::{Deserialize, Serialize};
 
 pub const BULLET_SPEED: f64 = 500.0; // units per second
 pub const BULLET_RANGE_FRACTION: f64 = 0.8; // bullets travel 80% of world width (matches original Asteroids)
 pub const MAX_BULLETS: usize = 4;
 pub const BULLET_RADIUS: f64 = 2.0;
+pub const SPREAD_SHOTS: usize = 3; // bullets per shot with the spread power-up
+pub const SPREAD_ANGLE: f64 = 0.2; // radians between spread bullets
+pub const RAPID_FIRE_SPEED: f64 = 1.6; // bullet speed multiplier with rapid fire
+pub const EXTRA_BULLETS: usize = 4; // added to the limit by the extra bullets power-up
+pub const PIERCE_COOLDOWN: f64 = 0.1; // seconds a piercing bullet ignores rocks after a hit
 
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Bullet {
     pub position: Vec2,
     pub velocity: Vec2,
     pub distance_traveled: f64,
     pub alive: bool,
+    /// Carries on through the rocks it hits.
+    #[serde(default)]
+    pub piercing: bool,
+    /// Seconds until a piercing bullet can hit again, so it clears the rock it split.
+    #[serde(default)]
+    pub pierce_cooldown: f64,
 }
 
 impl Bullet {
     /// Create a bullet at the given position traveling in the given direction.
     pub fn new(position: Vec2, angle: f64) -> Self {
-        let velocity = Vec2::from_angle(angle).scale(BULLET_SPEED);
+        Self::with_speed(position, angle, BULLET_SPEED)
+    }
+
+    /// Create a bullet traveling at `speed` instead of `BULLET_SPEED`.
+    pub fn with_speed(position: Vec2, angle: f64, speed: f64) -> Self {
         Self {
             position,
-            velocity,
+            velocity: Vec2::from_angle(angle).scale(speed),
             distance_traveled: 0.0,
             alive: true,
+            piercing: false,
+            pierce_cooldown: 0.0,
         }
     }
 
+    /// Whether the bullet can hit a rock this tick.
+    pub fn can_hit(&self) -> bool {
+        self.alive && self.pierce_cooldown <= 0.0
+    }
+}
+
+impl Update for Bullet {
     /// Update bullet position and lifetime (distance-based, matching original Asteroids).
-    pub fn update(&mut self, dt: f64, world_width: f64, world_height: f64) {
+    fn update(&mut self, dt: f64, world_width: f64, world_height: f64) {
         self.position = physics::integrate_motion(self.position, self.velocity, dt);
         self.position = physics::wrap_position(self.position, world_width, world_height);
         self.distance_traveled += self.velocity.magnitude() * dt;
+        self.pierce_cooldown = (self.pierce_cooldown - dt).max(0.0);
         if self.distance_traveled >= world_width * BULLET_RANGE_FRACTION {
             self.alive = false;
         }
     }
+
+    fn expired(&self) -> bool {
+        !self.alive
+    }
+}
+
+impl Render for Bullet {
+    fn render(&self, playfield: &mut Playfield, _blink: bool) {
+        if self.alive {
+            playfield.add_dot(self.position);
+        }
+    }
+}
+
+impl Collider for Bullet {
+    fn position(&self) -> Vec2 {
+        self.position
+    }
+
+    fn shape(&self) -> Shape {
+        Shape::Circle {
+            radius: BULLET_RADIUS,
+        }
+    }
 }
 
 /// Manages the collection of active bullets.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct BulletPool {
     pub bullets: Vec<Bullet>,
+    /// Most bullets in flight at once.
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+    /// Seconds left on the spread shot power-up.
+    #[serde(default)]
+    pub spread_timer: f64,
+    /// Seconds left on the rapid fire power-up.
+    #[serde(default)]
+    pub rapid_timer: f64,
+    /// Seconds left on the extra bullets power-up.
+    #[serde(default)]
+    pub extra_timer: f64,
+    /// Seconds left on the piercing bullets power-up.
+    #[serde(default)]
+    pub piercing_timer: f64,
+}
+
+fn default_limit() -> usize {
+    MAX_BULLETS
 }
 
 impl Default for BulletPool {
@@ -52,31 +126,78 @@ impl BulletPool {
     pub fn new() -> Self {
         Self {
             bullets: Vec::new(),
+            limit: MAX_BULLETS,
+            spread_timer: 0.0,
+            rapid_timer: 0.0,
+            extra_timer: 0.0,
+            piercing_timer: 0.0,
+        }
+    }
+
+    /// Most bullets in flight at once with the running power-ups: extra bullets raise
+    /// the limit, and spread shots multiply it so the same number of shots fit.
+    pub fn effective_limit(&self) -> usize {
+        let mut limit = self.limit;
+        if self.extra_timer > 0.0 {
+            limit += EXTRA_BULLETS;
+        }
+        if self.spread_timer > 0.0 {
+            limit *= SPREAD_SHOTS;
         }
+        limit
     }
 
-    /// Try to fire a new bullet. Returns false if at max capacity.
+    /// Try to fire a shot: one bullet, or a fan of them with the spread power-up.
+    /// Returns false if the shot would go over the pool's limit.
     pub fn fire(&mut self, position: Vec2, angle: f64) -> bool {
-        if self.active_count() >= MAX_BULLETS {
+        let angles: Vec<f64> = if self.spread_timer > 0.0 {
+            let middle = (SPREAD_SHOTS - 1) as f64 / 2.0;
+            (0..SPREAD_SHOTS)
+                .map(|i| angle + (i as f64 - middle) * SPREAD_ANGLE)
+                .collect()
+        } else {
+            vec![angle]
+        };
+        if self.active_count() + angles.len() > self.effective_limit() {
             return false;
         }
-        self.bullets.push(Bullet::new(position, angle));
+        let speed = if self.rapid_timer > 0.0 {
+            BULLET_SPEED * RAPID_FIRE_SPEED
+        } else {
+            BULLET_SPEED
+        };
+        for angle in angles {
+            let mut bullet = Bullet::with_speed(position, angle, speed);
+            bullet.piercing = self.piercing_timer > 0.0;
+            self.bullets.push(bullet);
+        }
         true
     }
 
+    /// Lose every running power-up, as when the ship is destroyed.
+    pub fn clear_power_ups(&mut self) {
+        self.spread_timer = 0.0;
+        self.rapid_timer = 0.0;
+        self.extra_timer = 0.0;
+        self.piercing_timer = 0.0;
+    }
+
     /// Count of active (alive) bullets.
     pub fn active_count(&self) -> usize {
         self.bullets.iter().filter(|b| b.alive).count()
     }
 
-    /// Update all bullets and remove dead ones.
+    /// Update all bullets and remove dead ones, and run down power-up timers.
     pub fn update(&mut self, dt: f64, world_width: f64, world_height: f64) {
-        for bullet in &mut self.bullets {
-            if bullet.alive {
-                bullet.update(dt, world_width, world_height);
-            }
+        for timer in [
+            &mut self.spread_timer,
+            &mut self.rapid_timer,
+            &mut self.extra_timer,
+            &mut self.piercing_timer,
+        ] {
+            *timer = (*timer - dt).max(0.0);
         }
-        self.bullets.retain(|b| b.alive);
+        entity::update_all(&mut self.bullets, dt, world_width, world_height);
     }
 }
 
@@ -194,6 +315,20 @@ mod tests {
         assert!(!pool.fire(Vec2::new(0.0, 0.0), 0.0));
     }
 
+    // Scenario: Difficulty changes the limit
+    #[test]
+    fn test_custom_limit() {
+        let mut pool = BulletPool::new();
+        pool.limit = 2;
+        assert!(pool.fire(Vec2::new(0.0, 0.0), 0.0));
+        assert!(pool.fire(Vec2::new(0.0, 0.0), 0.0));
+        assert!(!pool.fire(Vec2::new(0.0, 0.0), 0.0));
+
+        // Pools saved before limits existed get the default
+        let pool: BulletPool = serde_json::from_str(r#"{"bullets":[]}"#).unwrap();
+        assert_eq!(pool.limit, MAX_BULLETS);
+    }
+
     // Scenario: Can fire again after bullet expires
     #[test]
     fn test_fire_after_expiry() {
@@ -234,4 +369,95 @@ mod tests {
         let pool = BulletPool::default();
         assert_eq!(pool.active_count(), 0);
     }
+
+    // === Requirement: Bullet Power-Ups ===
+
+    // Scenario: Spread shot fires a fan of bullets
+    #[test]
+    fn test_spread_shot() {
+        let mut pool = BulletPool::new();
+        pool.spread_timer = 5.0;
+        assert!(pool.fire(Vec2::new(0.0, 0.0), 0.0));
+        assert_eq!(pool.active_count(), SPREAD_SHOTS);
+        let angles: Vec<f64> = pool
+            .bullets
+            .iter()
+            .map(|b| b.velocity.y.atan2(b.velocity.x))
+            .collect();
+        assert!(approx_eq(angles[0], -SPREAD_ANGLE));
+        assert!(approx_eq(angles[1], 0.0));
+        assert!(approx_eq(angles[2], SPREAD_ANGLE));
+        // The limit counts shots, so as many spread shots fit as single ones
+        for _ in 1..MAX_BULLETS {
+            assert!(pool.fire(Vec2::new(0.0, 0.0), 0.0));
+        }
+        assert!(!pool.fire(Vec2::new(0.0, 0.0), 0.0));
+    }
+
+    // Scenario: Extra bullets raise the limit
+    #[test]
+    fn test_extra_bullets() {
+        let mut pool = BulletPool::new();
+        pool.extra_timer = 5.0;
+        assert_eq!(pool.effective_limit(), MAX_BULLETS + EXTRA_BULLETS);
+        for _ in 0..MAX_BULLETS + EXTRA_BULLETS {
+            assert!(pool.fire(Vec2::new(0.0, 0.0), 0.0));
+        }
+        assert!(!pool.fire(Vec2::new(0.0, 0.0), 0.0));
+    }
+
+    // Scenario: Rapid fire bullets fly faster over the same range
+    #[test]
+    fn test_rapid_fire() {
+        let mut pool = BulletPool::new();
+        pool.rapid_timer = 5.0;
+        pool.fire(Vec2::new(0.0, 0.0), 0.0);
+        let bullet = &pool.bullets[0];
+        assert!(approx_eq(
+            bullet.velocity.magnitude(),
+            BULLET_SPEED * RAPID_FIRE_SPEED
+        ));
+        let frames = (0..)
+            .take_while(|_| {
+                pool.update(1.0 / 60.0, 800.0, 600.0);
+                pool.active_count() > 0
+            })
+            .count();
+        // ~48 frames instead of ~77 at normal speed
+        assert!(frames < 50, "{frames}");
+    }
+
+    // Scenario: Piercing bullets are marked when fired
+    #[test]
+    fn test_piercing_bullets() {
+        let mut pool = BulletPool::new();
+        pool.fire(Vec2::new(0.0, 0.0), 0.0);
+        pool.piercing_timer = 5.0;
+        pool.fire(Vec2::new(0.0, 0.0), 0.0);
+        assert!(!pool.bullets[0].piercing);
+        assert!(pool.bullets[1].piercing);
+        pool.bullets[1].pierce_cooldown = PIERCE_COOLDOWN;
+        assert!(!pool.bullets[1].can_hit());
+        pool.update(PIERCE_COOLDOWN, 800.0, 600.0);
+        assert!(pool.bullets[1].can_hit());
+    }
+
+    // Scenario: Power-up timers run down and can be cleared
+    #[test]
+    fn test_power_up_timers() {
+        let mut pool = BulletPool::new();
+        pool.spread_timer = 1.0;
+        pool.rapid_timer = 0.2;
+        pool.update(0.5, 800.0, 600.0);
+        assert!(approx_eq(pool.spread_timer, 0.5));
+        assert_eq!(pool.rapid_timer, 0.0);
+        pool.extra_timer = 3.0;
+        pool.piercing_timer = 3.0;
+        pool.clear_power_ups();
+        assert_eq!(pool.effective_limit(), MAX_BULLETS);
+        assert_eq!(
+            pool.spread_timer + pool.extra_timer + pool.piercing_timer,
+            0.0
+        );
+    }
 }
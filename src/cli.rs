@@ -0,0 +1,517 @@
+// CLI capability: command-line argument parsing for play, bot, network, spectator and server modes
+
+use crate::input::ControlScheme;
+use crate::netplay::{DEFAULT_HASH_INTERVAL, DEFAULT_INPUT_DELAY};
+use crate::ship::ExtraLives;
+use crate::spectate::Endpoint;
+use std::path::PathBuf;
+
+pub const USAGE: &str = "\
+Usage: tuisteroids [OPTIONS]
+
+Options:
+  --bot                 Serve the JSON-lines bot protocol on stdin/stdout
+  --bot-socket <PATH>   Serve the bot protocol on a Unix domain socket
+  --host <ADDR>         Host a network game, listening on ADDR (e.g. 0.0.0.0:7777)
+  --join <ADDR>         Join a network game hosted at ADDR
+  --input-delay <N>     Network input delay in ticks (host only, default: 3)
+  --hash-interval <N>   Compare state hashes every N ticks (host only, default: 60)
+  --versus              Host a versus game instead of co-op
+  --headless            Play a network game without a terminal, flown by the demo AI
+  --broadcast <ADDR>    Stream the game to watchers on a TCP address or Unix socket path
+  --watch <ADDR>        Watch a game streamed with --broadcast
+  --serve <ADDR>        Serve a game to every telnet client that connects to ADDR
+  --level <PATH>        Play the waves of a TOML level pack in one-player games
+  --edit <PATH>         Open the level editor on PATH, creating it when saved
+  --extra-life <N>      Award an extra life every N points in local games (0: never, default: 10000)
+  --max-lives <N>       Never award extra lives beyond N lives in local games
+  --controls <SCHEME>   Steer with the keyboard, or aim with the mouse alongside it, in
+                        local games: keyboard or mouse (default: keyboard)
+  --seed <N>            RNG seed for bot sessions and hosted games (default: random)
+  --max-ticks <N>       Stop a bot session or headless network game after N ticks
+  -h, --help            Show this help";
+
+/// Where a bot session talks to its bot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BotTransport {
+    Stdio,
+    UnixSocket(PathBuf),
+}
+
+/// Which end of a network game this process is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetRole {
+    Host(String),
+    Join(String),
+}
+
+/// What the binary should do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Play {
+        broadcast: Option<Endpoint>,
+        /// Level pack for one-player games.
+        level: Option<PathBuf>,
+        extra_lives: ExtraLives,
+        /// How player 1 steers.
+        controls: ControlScheme,
+    },
+    Watch(Endpoint),
+    /// Run the telnet server on an address.
+    Serve(String),
+    /// Open the level editor on a level file.
+    Edit(PathBuf),
+    Bot {
+        transport: BotTransport,
+        seed: Option<u64>,
+        max_ticks: Option<u64>,
+    },
+    Net {
+        role: NetRole,
+        seed: Option<u64>,
+        input_delay: u32,
+        hash_interval: u64,
+        versus: bool,
+        headless: bool,
+        max_ticks: Option<u64>,
+    },
+    Help,
+}
+
+/// Parse command-line arguments (excluding the program name).
+pub fn parse_args<I, S>(args: I) -> Result<Command, String>
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    let mut transport = None;
+    let mut role = None;
+    let mut seed = None;
+    let mut max_ticks = None;
+    let mut input_delay = None;
+    let mut hash_interval = None;
+    let mut versus = false;
+    let mut headless = false;
+    let mut broadcast = None;
+    let mut watch = None;
+    let mut serve = None;
+    let mut level = None;
+    let mut edit = None;
+    let mut extra_life = None;
+    let mut max_lives = None;
+    let mut controls = None;
+    let mut args = args.into_iter().map(Into::into);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--bot" => transport = Some(BotTransport::Stdio),
+            "--bot-socket" => {
+                let path = args.next().ok_or("--bot-socket requires a path")?;
+                transport = Some(BotTransport::UnixSocket(PathBuf::from(path)));
+            }
+            "--host" => {
+                let addr = args.next().ok_or("--host requires an address")?;
+                role = Some(NetRole::Host(addr));
+            }
+            "--join" => {
+                let addr = args.next().ok_or("--join requires an address")?;
+                role = Some(NetRole::Join(addr));
+            }
+            "--broadcast" => {
+                let addr = args.next().ok_or("--broadcast requires an address")?;
+                broadcast = Some(Endpoint::parse(&addr));
+            }
+            "--watch" => {
+                let addr = args.next().ok_or("--watch requires an address")?;
+                watch = Some(Endpoint::parse(&addr));
+            }
+            "--serve" => {
+                let addr = args.next().ok_or("--serve requires an address")?;
+                serve = Some(addr);
+            }
+            "--level" => {
+                let path = args.next().ok_or("--level requires a path")?;
+                level = Some(PathBuf::from(path));
+            }
+            "--edit" => {
+                let path = args.next().ok_or("--edit requires a path")?;
+                edit = Some(PathBuf::from(path));
+            }
+            "--controls" => {
+                let value = args.next().ok_or("--controls requires a scheme")?;
+                let scheme = ControlScheme::parse(&value).ok_or_else(|| {
+                    format!("--controls expects keyboard or mouse, got '{}'", value)
+                })?;
+                controls = Some(scheme);
+            }
+            "--extra-life" => extra_life = Some(parse_number(&arg, args.next())?),
+            "--max-lives" => max_lives = Some(parse_number(&arg, args.next())?),
+            "--seed" => seed = Some(parse_number(&arg, args.next())?),
+            "--max-ticks" => max_ticks = Some(parse_number(&arg, args.next())?),
+            "--input-delay" => input_delay = Some(parse_number(&arg, args.next())?),
+            "--hash-interval" => hash_interval = Some(parse_number(&arg, args.next())?),
+            "--versus" => versus = true,
+            "--headless" => headless = true,
+            other => return Err(format!("unknown argument: {}", other)),
+        }
+    }
+
+    let other_mode = transport.is_some() || role.is_some();
+    let play_options = extra_life.is_some() || max_lives.is_some() || controls.is_some();
+    if let Some(path) = edit {
+        let net_options = input_delay.is_some() || hash_interval.is_some() || versus || headless;
+        let local_options = broadcast.is_some() || watch.is_some() || serve.is_some();
+        let session_options = level.is_some() || seed.is_some() || max_ticks.is_some();
+        if other_mode || net_options || local_options || session_options || play_options {
+            return Err("--edit cannot be combined with other options".to_string());
+        }
+        return Ok(Command::Edit(path));
+    }
+    if level.is_some() && (other_mode || watch.is_some() || serve.is_some()) {
+        return Err("--level only applies to local play".to_string());
+    }
+    if play_options && (other_mode || watch.is_some() || serve.is_some()) {
+        return Err(
+            "--extra-life, --max-lives and --controls only apply to local play".to_string(),
+        );
+    }
+    let extra_lives = ExtraLives {
+        every: match extra_life {
+            Some(points) => u32::try_from(points)
+                .map_err(|_| format!("--extra-life is too large: {}", points))?,
+            None => ExtraLives::default().every,
+        },
+        max_lives: max_lives
+            .map(|lives| {
+                u32::try_from(lives).map_err(|_| format!("--max-lives is too large: {}", lives))
+            })
+            .transpose()?,
+    };
+    if let Some(endpoint) = watch {
+        if other_mode || broadcast.is_some() || seed.is_some() || max_ticks.is_some() {
+            return Err("--watch cannot be combined with other options".to_string());
+        }
+        return Ok(Command::Watch(endpoint));
+    }
+    if let Some(addr) = serve {
+        let other_options = other_mode || broadcast.is_some() || headless || versus;
+        if other_options || seed.is_some() || max_ticks.is_some() {
+            return Err("--serve cannot be combined with other options".to_string());
+        }
+        return Ok(Command::Serve(addr));
+    }
+    if broadcast.is_some() && other_mode {
+        return Err("--broadcast only applies to local play".to_string());
+    }
+
+    let host_settings =
+        seed.is_some() || input_delay.is_some() || hash_interval.is_some() || versus;
+    match (transport, role) {
+        (Some(_), Some(_)) => Err("choose either a bot mode or a network mode".to_string()),
+        (_, None) if input_delay.is_some() || hash_interval.is_some() || versus || headless => Err(
+            "--input-delay, --hash-interval, --versus and --headless require --host or --join"
+                .to_string(),
+        ),
+        (Some(transport), None) => Ok(Command::Bot {
+            transport,
+            seed,
+            max_ticks,
+        }),
+        (None, Some(NetRole::Join(_))) if host_settings => Err(
+            "--seed, --input-delay, --hash-interval and --versus are chosen by the host"
+                .to_string(),
+        ),
+        (None, Some(_)) if max_ticks.is_some() && !headless => {
+            Err("--max-ticks requires --headless in a network game".to_string())
+        }
+        (None, Some(role)) => Ok(Command::Net {
+            role,
+            seed,
+            input_delay: match input_delay {
+                Some(delay) => u32::try_from(delay)
+                    .map_err(|_| format!("--input-delay is too large: {}", delay))?,
+                None => DEFAULT_INPUT_DELAY,
+            },
+            hash_interval: hash_interval.unwrap_or(DEFAULT_HASH_INTERVAL),
+            versus,
+            headless,
+            max_ticks,
+        }),
+        (None, None) if seed.is_some() || max_ticks.is_some() => {
+            Err("--seed and --max-ticks require --bot, --bot-socket, --host or --join".to_string())
+        }
+        (None, None) => Ok(Command::Play {
+            broadcast,
+            level,
+            extra_lives,
+            controls: controls.unwrap_or_default(),
+        }),
+    }
+}
+
+fn parse_number(flag: &str, value: Option<String>) -> Result<u64, String> {
+    let value = value.ok_or_else(|| format!("{} requires a number", flag))?;
+    value
+        .parse()
+        .map_err(|_| format!("{} expects a number, got '{}'", flag, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Command, String> {
+        parse_args(args.iter().copied())
+    }
+
+    // === Requirement: Command-Line Modes ===
+
+    // Scenario: No arguments starts the interactive game
+    #[test]
+    fn test_no_args_plays() {
+        assert_eq!(
+            parse(&[]),
+            Ok(Command::Play {
+                broadcast: None,
+                level: None,
+                extra_lives: ExtraLives::default(),
+                controls: ControlScheme::Keyboard,
+            })
+        );
+    }
+
+    // Scenario: Help flag shows usage
+    #[test]
+    fn test_help() {
+        assert_eq!(parse(&["--help"]), Ok(Command::Help));
+        assert_eq!(parse(&["-h"]), Ok(Command::Help));
+    }
+
+    // Scenario: --bot selects stdio with optional seed and tick limit
+    #[test]
+    fn test_bot_stdio() {
+        assert_eq!(
+            parse(&["--bot", "--seed", "7", "--max-ticks", "100"]),
+            Ok(Command::Bot {
+                transport: BotTransport::Stdio,
+                seed: Some(7),
+                max_ticks: Some(100),
+            })
+        );
+    }
+
+    // Scenario: --bot-socket selects a Unix socket path
+    #[test]
+    fn test_bot_socket() {
+        assert_eq!(
+            parse(&["--bot-socket", "/tmp/bot.sock"]),
+            Ok(Command::Bot {
+                transport: BotTransport::UnixSocket(PathBuf::from("/tmp/bot.sock")),
+                seed: None,
+                max_ticks: None,
+            })
+        );
+    }
+
+    // Scenario: Invalid arguments are rejected with a message
+    #[test]
+    fn test_invalid_args() {
+        assert!(parse(&["--bogus"]).unwrap_err().contains("--bogus"));
+        assert!(parse(&["--bot-socket"]).is_err());
+        assert!(parse(&["--bot", "--seed"]).is_err());
+        assert!(parse(&["--bot", "--seed", "abc"])
+            .unwrap_err()
+            .contains("abc"));
+        assert!(parse(&["--seed", "1"]).is_err());
+    }
+
+    // Scenario: --host and --join select a network game
+    #[test]
+    fn test_net_modes() {
+        assert_eq!(
+            parse(&["--host", "0.0.0.0:7777", "--versus", "--input-delay", "5"]),
+            Ok(Command::Net {
+                role: NetRole::Host("0.0.0.0:7777".to_string()),
+                seed: None,
+                input_delay: 5,
+                hash_interval: DEFAULT_HASH_INTERVAL,
+                versus: true,
+                headless: false,
+                max_ticks: None,
+            })
+        );
+        assert_eq!(
+            parse(&[
+                "--join",
+                "127.0.0.1:7777",
+                "--headless",
+                "--max-ticks",
+                "600"
+            ]),
+            Ok(Command::Net {
+                role: NetRole::Join("127.0.0.1:7777".to_string()),
+                seed: None,
+                input_delay: DEFAULT_INPUT_DELAY,
+                hash_interval: DEFAULT_HASH_INTERVAL,
+                versus: false,
+                headless: true,
+                max_ticks: Some(600),
+            })
+        );
+    }
+
+    // Scenario: Conflicting network arguments are rejected
+    #[test]
+    fn test_invalid_net_args() {
+        assert!(parse(&["--host"]).is_err());
+        assert!(parse(&["--join"]).is_err());
+        assert!(parse(&["--bot", "--host", "0.0.0.0:1"]).is_err());
+        assert!(parse(&["--versus"]).unwrap_err().contains("--host"));
+        assert!(parse(&["--headless"]).is_err());
+        assert!(parse(&["--join", "h:1", "--seed", "3"])
+            .unwrap_err()
+            .contains("host"));
+        assert!(parse(&["--host", "h:1", "--max-ticks", "3"])
+            .unwrap_err()
+            .contains("--headless"));
+        assert!(parse(&["--host", "h:1", "--input-delay", "99999999999"])
+            .unwrap_err()
+            .contains("too large"));
+    }
+
+    // Scenario: --broadcast streams local play and --watch spectates
+    #[test]
+    fn test_spectator_modes() {
+        assert_eq!(
+            parse(&["--broadcast", "0.0.0.0:7000"]),
+            Ok(Command::Play {
+                broadcast: Some(Endpoint::Tcp("0.0.0.0:7000".to_string())),
+                level: None,
+                extra_lives: ExtraLives::default(),
+                controls: ControlScheme::Keyboard,
+            })
+        );
+        assert_eq!(
+            parse(&["--watch", "/tmp/game.sock"]),
+            Ok(Command::Watch(Endpoint::Unix(PathBuf::from(
+                "/tmp/game.sock"
+            ))))
+        );
+        assert!(parse(&["--watch"]).is_err());
+        assert!(parse(&["--broadcast"]).is_err());
+        assert!(parse(&["--watch", "h:1", "--broadcast", "h:2"]).is_err());
+        assert!(parse(&["--watch", "h:1", "--seed", "2"]).is_err());
+        assert!(parse(&["--broadcast", "h:1", "--bot"])
+            .unwrap_err()
+            .contains("local play"));
+    }
+
+    // Scenario: --level plays a level pack locally
+    #[test]
+    fn test_level() {
+        assert_eq!(
+            parse(&["--level", "levels/gauntlet.toml", "--broadcast", "h:1"]),
+            Ok(Command::Play {
+                broadcast: Some(Endpoint::Tcp("h:1".to_string())),
+                level: Some(PathBuf::from("levels/gauntlet.toml")),
+                extra_lives: ExtraLives::default(),
+                controls: ControlScheme::Keyboard,
+            })
+        );
+        assert!(parse(&["--level"]).is_err());
+        for other in [["--serve", "h:1"], ["--watch", "h:1"], ["--host", "h:1"]] {
+            let mut args = vec!["--level", "a.toml"];
+            args.extend(other);
+            assert!(parse(&args).unwrap_err().contains("local play"));
+        }
+        assert!(parse(&["--bot", "--level", "a.toml"]).is_err());
+    }
+
+    // Scenario: --extra-life and --max-lives set the extra life rule for local play
+    #[test]
+    fn test_extra_lives() {
+        assert_eq!(
+            parse(&["--extra-life", "5000", "--max-lives", "6"]),
+            Ok(Command::Play {
+                broadcast: None,
+                level: None,
+                extra_lives: ExtraLives {
+                    every: 5_000,
+                    max_lives: Some(6),
+                },
+                controls: ControlScheme::Keyboard,
+            })
+        );
+        let Ok(Command::Play { extra_lives, .. }) = parse(&["--extra-life", "0"]) else {
+            panic!("expected play");
+        };
+        assert_eq!(extra_lives.every, 0);
+        assert!(parse(&["--extra-life"]).is_err());
+        assert!(parse(&["--max-lives", "lots"]).is_err());
+        assert!(parse(&["--extra-life", "99999999999"])
+            .unwrap_err()
+            .contains("too large"));
+        assert!(parse(&["--host", "h:1", "--max-lives", "5"])
+            .unwrap_err()
+            .contains("local play"));
+        assert!(parse(&["--edit", "a.toml", "--extra-life", "5000"]).is_err());
+    }
+
+    // Scenario: --controls picks keyboard or mouse steering for local play
+    #[test]
+    fn test_controls() {
+        let Ok(Command::Play { controls, .. }) = parse(&["--controls", "mouse"]) else {
+            panic!("expected play");
+        };
+        assert_eq!(controls, ControlScheme::Mouse);
+        let Ok(Command::Play { controls, .. }) = parse(&["--controls", "keyboard"]) else {
+            panic!("expected play");
+        };
+        assert_eq!(controls, ControlScheme::Keyboard);
+        assert!(parse(&["--controls"]).is_err());
+        assert!(parse(&["--controls", "joystick"])
+            .unwrap_err()
+            .contains("keyboard or mouse"));
+        assert!(parse(&["--bot", "--controls", "mouse"])
+            .unwrap_err()
+            .contains("local play"));
+        assert!(parse(&["--edit", "a.toml", "--controls", "mouse"]).is_err());
+    }
+
+    // Scenario: --edit opens the level editor
+    #[test]
+    fn test_edit() {
+        assert_eq!(
+            parse(&["--edit", "levels/mine.toml"]),
+            Ok(Command::Edit(PathBuf::from("levels/mine.toml")))
+        );
+        assert!(parse(&["--edit"]).is_err());
+        for other in [
+            &["--level", "a.toml"][..],
+            &["--broadcast", "h:1"],
+            &["--serve", "h:1"],
+            &["--bot"],
+            &["--seed", "3"],
+        ] {
+            let mut args = vec!["--edit", "b.toml"];
+            args.extend(other);
+            assert!(parse(&args).unwrap_err().contains("--edit"), "{:?}", args);
+        }
+    }
+
+    // Scenario: --serve runs the telnet server
+    #[test]
+    fn test_serve_mode() {
+        assert_eq!(
+            parse(&["--serve", "0.0.0.0:2323"]),
+            Ok(Command::Serve("0.0.0.0:2323".to_string()))
+        );
+        assert!(parse(&["--serve"]).is_err());
+        assert!(parse(&["--serve", "h:1", "--bot"])
+            .unwrap_err()
+            .contains("--serve"));
+        assert!(parse(&["--serve", "h:1", "--broadcast", "h:2"]).is_err());
+        assert!(parse(&["--serve", "h:1", "--seed", "3"]).is_err());
+    }
+}
@@ -1,6 +1,9 @@
-// Collision capability: circle-circle detection with toroidal distance
+// Collision capability: colliders, circle-circle detection with toroidal distance
 
+use crate::asteroids::{Asteroid, AsteroidSize};
+use crate::bullets::Bullet;
 use crate::physics::Vec2;
+use crate::ship::Ship;
 
 /// Calculate the shortest distance between two points on a toroidal surface.
 pub fn toroidal_distance(a: Vec2, b: Vec2, width: f64, height: f64) -> f64 {
@@ -48,6 +51,61 @@ pub fn circles_collide_toroidal(
     dist < radius_a + radius_b
 }
 
+/// The outline a collider is tested for contact with.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Shape {
+    Circle { radius: f64 },
+}
+
+/// Anything in the world that can touch something else: ships, asteroids, bullets and
+/// pickups. New entity types plug into collision checks by implementing it.
+pub trait Collider {
+    fn position(&self) -> Vec2;
+    fn shape(&self) -> Shape;
+
+    /// Radius of the circle around the body.
+    fn radius(&self) -> f64 {
+        match self.shape() {
+            Shape::Circle { radius } => radius,
+        }
+    }
+}
+
+/// Check if two bodies touch on a toroidal surface.
+pub fn collide<A, B>(a: &A, b: &B, world_width: f64, world_height: f64) -> bool
+where
+    A: Collider + ?Sized,
+    B: Collider + ?Sized,
+{
+    collide_with_margin(a, b, 0.0, world_width, world_height)
+}
+
+/// Check if two bodies come within `margin` of touching on a toroidal surface.
+pub fn collide_with_margin<A, B>(
+    a: &A,
+    b: &B,
+    margin: f64,
+    world_width: f64,
+    world_height: f64,
+) -> bool
+where
+    A: Collider + ?Sized,
+    B: Collider + ?Sized,
+{
+    match (a.shape(), b.shape()) {
+        (Shape::Circle { radius: radius_a }, Shape::Circle { radius: radius_b }) => {
+            circles_collide_toroidal(
+                a.position(),
+                radius_a + margin,
+                b.position(),
+                radius_b,
+                world_width,
+                world_height,
+            )
+        }
+    }
+}
+
 /// Result of processing a ship-asteroid collision.
 #[derive(Debug, PartialEq)]
 pub enum ShipCollisionResult {
@@ -56,95 +114,118 @@ pub enum ShipCollisionResult {
     GameOver,
 }
 
-/// Check ship-asteroid collision and determine result.
-#[allow(clippy::too_many_arguments)]
+/// Check ship-asteroid collision and determine result. Invulnerable and shielded ships
+/// pass through rocks.
 pub fn check_ship_asteroid_collision(
-    ship_pos: Vec2,
-    ship_radius: f64,
-    ship_lives: u32,
-    ship_invulnerable: bool,
-    asteroid_pos: Vec2,
-    asteroid_radius: f64,
+    ship: &Ship,
+    asteroid: &Asteroid,
     world_width: f64,
     world_height: f64,
 ) -> ShipCollisionResult {
-    if ship_invulnerable {
+    if ship.invulnerable || ship.shielded() {
         return ShipCollisionResult::NoCollision;
     }
 
-    if !circles_collide_toroidal(
-        ship_pos,
-        ship_radius,
-        asteroid_pos,
-        asteroid_radius,
-        world_width,
-        world_height,
-    ) {
+    if !collide(ship, asteroid, world_width, world_height) {
         return ShipCollisionResult::NoCollision;
     }
 
-    if ship_lives <= 1 {
+    if ship.lives <= 1 {
         ShipCollisionResult::GameOver
     } else {
         ShipCollisionResult::ShipDestroyed {
-            lives_remaining: ship_lives - 1,
+            lives_remaining: ship.lives - 1,
         }
     }
 }
 
-/// Size of an asteroid for splitting logic.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum AsteroidSize {
-    Large,
-    Medium,
-    Small,
-}
-
 /// Result of a bullet hitting an asteroid.
 #[derive(Debug, PartialEq)]
 pub enum BulletAsteroidResult {
     NoCollision,
+    /// The asteroid breaks into two asteroids of `new_size`.
     AsteroidSplit {
         new_size: AsteroidSize,
-        count: usize,
     },
     AsteroidDestroyed,
 }
 
-/// Check bullet-asteroid collision and determine split result.
+/// Check bullet-asteroid collision and determine split result. Bullets that cannot
+/// hit, dead or just through another rock, pass through.
 pub fn check_bullet_asteroid_collision(
-    bullet_pos: Vec2,
-    bullet_radius: f64,
-    asteroid_pos: Vec2,
-    asteroid_radius: f64,
-    asteroid_size: AsteroidSize,
+    bullet: &Bullet,
+    asteroid: &Asteroid,
     world_width: f64,
     world_height: f64,
 ) -> BulletAsteroidResult {
-    if !circles_collide_toroidal(
-        bullet_pos,
-        bullet_radius,
-        asteroid_pos,
-        asteroid_radius,
-        world_width,
-        world_height,
-    ) {
+    if !bullet.can_hit() || !collide(bullet, asteroid, world_width, world_height) {
         return BulletAsteroidResult::NoCollision;
     }
 
-    match asteroid_size {
-        AsteroidSize::Large => BulletAsteroidResult::AsteroidSplit {
-            new_size: AsteroidSize::Medium,
-            count: 2,
-        },
-        AsteroidSize::Medium => BulletAsteroidResult::AsteroidSplit {
-            new_size: AsteroidSize::Small,
-            count: 2,
-        },
-        AsteroidSize::Small => BulletAsteroidResult::AsteroidDestroyed,
+    match asteroid.size.split_into() {
+        Some(new_size) => BulletAsteroidResult::AsteroidSplit { new_size },
+        None => BulletAsteroidResult::AsteroidDestroyed,
     }
 }
 
+/// Elastic collision between two touching bodies on a toroidal surface, along the
+/// shortest line between their centres. Returns their new velocities, or None if they
+/// are already moving apart.
+#[allow(clippy::too_many_arguments)]
+pub fn bounce(
+    pos_a: Vec2,
+    vel_a: Vec2,
+    mass_a: f64,
+    pos_b: Vec2,
+    vel_b: Vec2,
+    mass_b: f64,
+    world_width: f64,
+    world_height: f64,
+) -> Option<(Vec2, Vec2)> {
+    let normal = toroidal_direction(pos_a, pos_b, world_width, world_height).normalize();
+    let closing = (vel_a - vel_b).dot(normal);
+    if closing <= 0.0 {
+        return None;
+    }
+    let impulse = 2.0 * closing / (1.0 / mass_a + 1.0 / mass_b);
+    Some((
+        vel_a - normal.scale(impulse / mass_a),
+        vel_b + normal.scale(impulse / mass_b),
+    ))
+}
+
+/// Bounce two bodies off each other if they touch. Returns their new velocities, or
+/// None if they are apart or already moving apart.
+#[allow(clippy::too_many_arguments)]
+pub fn bounce_on_contact<A, B>(
+    a: &A,
+    vel_a: Vec2,
+    mass_a: f64,
+    b: &B,
+    vel_b: Vec2,
+    mass_b: f64,
+    world_width: f64,
+    world_height: f64,
+) -> Option<(Vec2, Vec2)>
+where
+    A: Collider + ?Sized,
+    B: Collider + ?Sized,
+{
+    if !collide(a, b, world_width, world_height) {
+        return None;
+    }
+    bounce(
+        a.position(),
+        vel_a,
+        mass_a,
+        b.position(),
+        vel_b,
+        mass_b,
+        world_width,
+        world_height,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -181,6 +262,103 @@ mod tests {
         assert!(circles_collide(a, 10.0, b, 10.0));
     }
 
+    // === Requirement: Elastic Bounce ===
+
+    // Scenario: Equal masses swap velocities head-on
+    #[test]
+    fn test_bounce_equal_masses() {
+        let (a, b) = bounce(
+            Vec2::new(100.0, 100.0),
+            Vec2::new(50.0, 0.0),
+            1.0,
+            Vec2::new(120.0, 100.0),
+            Vec2::new(0.0, 0.0),
+            1.0,
+            800.0,
+            600.0,
+        )
+        .unwrap();
+        assert!(approx_eq(a.x, 0.0) && approx_eq(a.y, 0.0));
+        assert!(approx_eq(b.x, 50.0) && approx_eq(b.y, 0.0));
+    }
+
+    // Scenario: Light body rebounds off a heavy one, conserving momentum
+    #[test]
+    fn test_bounce_conserves_momentum() {
+        let (vel_a, vel_b) = (Vec2::new(0.0, 30.0), Vec2::new(10.0, -20.0));
+        let (a, b) = bounce(
+            Vec2::new(400.0, 290.0),
+            vel_a,
+            1.0,
+            Vec2::new(400.0, 310.0),
+            vel_b,
+            16.0,
+            800.0,
+            600.0,
+        )
+        .unwrap();
+        assert!(a.y < 0.0, "rebounds");
+        assert!(
+            approx_eq(a.x, 0.0) && approx_eq(b.x, 10.0),
+            "tangential kept"
+        );
+        let before = vel_a + vel_b.scale(16.0);
+        let after = a + b.scale(16.0);
+        assert!((before - after).magnitude() < 1e-9);
+        let energy = |v: Vec2, m: f64| 0.5 * m * v.dot(v);
+        let before = energy(vel_a, 1.0) + energy(vel_b, 16.0);
+        let after = energy(a, 1.0) + energy(b, 16.0);
+        assert!((before - after).abs() < 1e-6);
+    }
+
+    // Scenario: Bounce across the wrap edge pushes the right way
+    #[test]
+    fn test_bounce_across_edge() {
+        let (a, _) = bounce(
+            Vec2::new(5.0, 300.0),
+            Vec2::new(-40.0, 0.0),
+            1.0,
+            Vec2::new(790.0, 300.0),
+            Vec2::new(0.0, 0.0),
+            16.0,
+            800.0,
+            600.0,
+        )
+        .unwrap();
+        assert!(a.x > 0.0);
+    }
+
+    // Scenario: Only touching bodies bounce on contact
+    #[test]
+    fn test_bounce_on_contact() {
+        let ship = ship_at(Vec2::new(400.0, 300.0), 3, false);
+        let asteroid = asteroid_at(Vec2::new(400.0, 325.0), AsteroidSize::Small);
+        let (towards, still) = (Vec2::new(0.0, 50.0), Vec2::new(0.0, 0.0));
+        assert!(!collide(&ship, &asteroid, 800.0, 600.0));
+        let bounced = |a: &dyn Collider| {
+            bounce_on_contact(a, towards, 1.0, &asteroid, still, 1.0, 800.0, 600.0)
+        };
+        assert_eq!(bounced(&ship), None);
+        let (a, b) = bounced(&ship.shield()).unwrap();
+        assert!(approx_eq(a.y, 0.0) && approx_eq(b.y, 50.0));
+    }
+
+    // Scenario: Bodies moving apart do not bounce
+    #[test]
+    fn test_no_bounce_when_separating() {
+        assert!(bounce(
+            Vec2::new(100.0, 100.0),
+            Vec2::new(-50.0, 0.0),
+            1.0,
+            Vec2::new(120.0, 100.0),
+            Vec2::new(0.0, 0.0),
+            1.0,
+            800.0,
+            600.0,
+        )
+        .is_none());
+    }
+
     // === Requirement: Toroidal Distance Calculation ===
 
     // Scenario: Direct distance is shortest
@@ -220,18 +398,79 @@ mod tests {
         assert!(approx_eq(dist, expected));
     }
 
+    fn ship_at(position: Vec2, lives: u32, invulnerable: bool) -> Ship {
+        let mut ship = Ship::new(position.x, position.y);
+        ship.lives = lives;
+        ship.invulnerable = invulnerable;
+        ship
+    }
+
+    fn asteroid_at(position: Vec2, size: AsteroidSize) -> Asteroid {
+        Asteroid::new_with_shape(position, Vec2::new(0.0, 0.0), size, 0.0, vec![])
+    }
+
+    // === Requirement: Colliders ===
+
+    // Scenario: Entities report their position and radius
+    #[test]
+    fn test_entity_colliders() {
+        let ship = ship_at(Vec2::new(400.0, 300.0), 3, false);
+        assert_eq!(ship.position(), Vec2::new(400.0, 300.0));
+        assert!(approx_eq(ship.radius(), crate::ship::SHIP_RADIUS));
+        let asteroid = asteroid_at(Vec2::new(10.0, 20.0), AsteroidSize::Large);
+        assert_eq!(
+            asteroid.shape(),
+            Shape::Circle {
+                radius: AsteroidSize::Large.radius()
+            }
+        );
+        let bullet = Bullet::new(Vec2::new(1.0, 2.0), 0.0);
+        assert!(approx_eq(bullet.radius(), crate::bullets::BULLET_RADIUS));
+    }
+
+    // Scenario: Any two colliders can be tested for contact
+    #[test]
+    fn test_collide_any_entities() {
+        let ship = ship_at(Vec2::new(5.0, 300.0), 3, false);
+        let bullet = Bullet::new(Vec2::new(795.0, 300.0), 0.0);
+        assert!(collide(&ship, &bullet, 800.0, 600.0));
+        let far = Bullet::new(Vec2::new(400.0, 300.0), 0.0);
+        assert!(!collide(&ship, &far, 800.0, 600.0));
+        let colliders: [&dyn Collider; 2] = [&ship, &bullet];
+        assert!(collide(colliders[0], colliders[1], 800.0, 600.0));
+    }
+
+    // Scenario: Bodies within a margin of touching are close
+    #[test]
+    fn test_collide_with_margin() {
+        let ship = ship_at(Vec2::new(400.0, 300.0), 3, false);
+        let asteroid = asteroid_at(Vec2::new(400.0, 350.0), AsteroidSize::Small);
+        let gap = 50.0 - crate::ship::SHIP_RADIUS - AsteroidSize::Small.radius();
+        assert!(!collide(&ship, &asteroid, 800.0, 600.0));
+        assert!(collide_with_margin(
+            &ship,
+            &asteroid,
+            gap + 1.0,
+            800.0,
+            600.0
+        ));
+        assert!(!collide_with_margin(
+            &ship,
+            &asteroid,
+            gap - 1.0,
+            800.0,
+            600.0
+        ));
+    }
+
     // === Requirement: Ship-Asteroid Collision ===
 
     // Scenario: Ship hits asteroid and loses life
     #[test]
     fn test_ship_hits_asteroid_loses_life() {
         let result = check_ship_asteroid_collision(
-            Vec2::new(400.0, 300.0),
-            12.0,
-            3,
-            false,
-            Vec2::new(405.0, 300.0),
-            30.0,
+            &ship_at(Vec2::new(400.0, 300.0), 3, false),
+            &asteroid_at(Vec2::new(405.0, 300.0), AsteroidSize::Large),
             800.0,
             600.0,
         );
@@ -245,12 +484,8 @@ mod tests {
     #[test]
     fn test_invulnerable_ship_ignores_collision() {
         let result = check_ship_asteroid_collision(
-            Vec2::new(400.0, 300.0),
-            12.0,
-            3,
-            true,
-            Vec2::new(405.0, 300.0),
-            30.0,
+            &ship_at(Vec2::new(400.0, 300.0), 3, true),
+            &asteroid_at(Vec2::new(405.0, 300.0), AsteroidSize::Large),
             800.0,
             600.0,
         );
@@ -261,12 +496,8 @@ mod tests {
     #[test]
     fn test_ship_last_life_game_over() {
         let result = check_ship_asteroid_collision(
-            Vec2::new(400.0, 300.0),
-            12.0,
-            1,
-            false,
-            Vec2::new(405.0, 300.0),
-            30.0,
+            &ship_at(Vec2::new(400.0, 300.0), 1, false),
+            &asteroid_at(Vec2::new(405.0, 300.0), AsteroidSize::Large),
             800.0,
             600.0,
         );
@@ -279,11 +510,8 @@ mod tests {
     #[test]
     fn test_bullet_hits_large_asteroid() {
         let result = check_bullet_asteroid_collision(
-            Vec2::new(200.0, 200.0),
-            2.0,
-            Vec2::new(205.0, 200.0),
-            30.0,
-            AsteroidSize::Large,
+            &Bullet::new(Vec2::new(200.0, 200.0), 0.0),
+            &asteroid_at(Vec2::new(205.0, 200.0), AsteroidSize::Large),
             800.0,
             600.0,
         );
@@ -291,7 +519,6 @@ mod tests {
             result,
             BulletAsteroidResult::AsteroidSplit {
                 new_size: AsteroidSize::Medium,
-                count: 2,
             }
         );
     }
@@ -300,11 +527,8 @@ mod tests {
     #[test]
     fn test_bullet_hits_medium_asteroid() {
         let result = check_bullet_asteroid_collision(
-            Vec2::new(200.0, 200.0),
-            2.0,
-            Vec2::new(205.0, 200.0),
-            20.0,
-            AsteroidSize::Medium,
+            &Bullet::new(Vec2::new(200.0, 200.0), 0.0),
+            &asteroid_at(Vec2::new(205.0, 200.0), AsteroidSize::Medium),
             800.0,
             600.0,
         );
@@ -312,7 +536,6 @@ mod tests {
             result,
             BulletAsteroidResult::AsteroidSplit {
                 new_size: AsteroidSize::Small,
-                count: 2,
             }
         );
     }
@@ -321,17 +544,28 @@ mod tests {
     #[test]
     fn test_bullet_hits_small_asteroid() {
         let result = check_bullet_asteroid_collision(
-            Vec2::new(200.0, 200.0),
-            2.0,
-            Vec2::new(205.0, 200.0),
-            10.0,
-            AsteroidSize::Small,
+            &Bullet::new(Vec2::new(200.0, 200.0), 0.0),
+            &asteroid_at(Vec2::new(205.0, 200.0), AsteroidSize::Small),
             800.0,
             600.0,
         );
         assert_eq!(result, BulletAsteroidResult::AsteroidDestroyed);
     }
 
+    // Scenario: Spent bullet passes through
+    #[test]
+    fn test_dead_bullet_passes_through() {
+        let mut bullet = Bullet::new(Vec2::new(200.0, 200.0), 0.0);
+        bullet.alive = false;
+        let result = check_bullet_asteroid_collision(
+            &bullet,
+            &asteroid_at(Vec2::new(205.0, 200.0), AsteroidSize::Small),
+            800.0,
+            600.0,
+        );
+        assert_eq!(result, BulletAsteroidResult::NoCollision);
+    }
+
     // === Requirement: Toroidal Direction Calculation ===
 
     // Scenario: Direct direction is shortest
@@ -394,11 +628,8 @@ mod tests {
     #[test]
     fn test_bullet_asteroid_wrapping_boundary() {
         let result = check_bullet_asteroid_collision(
-            Vec2::new(5.0, 300.0),
-            2.0,
-            Vec2::new(795.0, 300.0),
-            30.0,
-            AsteroidSize::Large,
+            &Bullet::new(Vec2::new(5.0, 300.0), 0.0),
+            &asteroid_at(Vec2::new(795.0, 300.0), AsteroidSize::Large),
             800.0,
             600.0,
         );
@@ -406,7 +637,6 @@ mod tests {
             result,
             BulletAsteroidResult::AsteroidSplit {
                 new_size: AsteroidSize::Medium,
-                count: 2,
             }
         );
     }
@@ -0,0 +1,421 @@
+// Controller capability: pluggable sources of per-tick ship input
+
+use crate::demo_ai::{self, AiDifficulty};
+use crate::game::PlayingState;
+use crate::input::{InputState, MouseAim};
+use crate::ship::SHIP_RADIUS;
+
+const AIM_DEADZONE: f64 = 0.05; // radians — under one tick's turn, so the ship settles on the cursor
+
+/// Produces the ship's InputState for each fixed-timestep update.
+pub trait Controller {
+    /// Produce the input for the next tick from an observation of the game.
+    fn next_input(
+        &mut self,
+        state: &PlayingState,
+        world_width: f64,
+        world_height: f64,
+    ) -> InputState;
+
+    /// Receive the keyboard state polled by the run loop. Ignored by non-keyboard controllers.
+    fn feed_keyboard(&mut self, _keys: &InputState) {}
+
+    /// Receive the mouse state tracked by the run loop. Ignored by non-mouse controllers.
+    fn feed_mouse(&mut self, _mouse: &MouseAim) {}
+
+    /// True once a finite controller (replay, script) has no more input to give.
+    fn is_finished(&self) -> bool {
+        false
+    }
+}
+
+/// Passes through the keyboard state polled by the run loop.
+#[derive(Debug, Default)]
+pub struct KeyboardController {
+    keys: InputState,
+}
+
+impl KeyboardController {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Controller for KeyboardController {
+    fn next_input(&mut self, _state: &PlayingState, _w: f64, _h: f64) -> InputState {
+        self.keys.clone()
+    }
+
+    fn feed_keyboard(&mut self, keys: &InputState) {
+        self.keys = keys.clone();
+    }
+}
+
+/// The keyboard plus mouse aiming: the ship turns toward the cursor unless a rotate key
+/// is held, and the right mouse button thrusts. Left-clicks reach it as fire presses
+/// through the keyboard state.
+#[derive(Debug, Default)]
+pub struct MouseController {
+    keys: InputState,
+    mouse: MouseAim,
+}
+
+impl MouseController {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Controller for MouseController {
+    fn next_input(&mut self, state: &PlayingState, _w: f64, _h: f64) -> InputState {
+        let mut input = self.keys.clone();
+        input.thrust |= self.mouse.thrust;
+        let ship = &state.ship;
+        let steering = input.rotate_left || input.rotate_right;
+        // Aim at the cursor as the screen shows it, not across the wrap; a cursor on
+        // the ship gives no direction
+        let aim = self
+            .mouse
+            .target
+            .map(|target| target - ship.position)
+            .filter(|offset| !steering && offset.magnitude() > SHIP_RADIUS);
+        if let Some(offset) = aim {
+            let error = demo_ai::heading_error(offset, ship.rotation);
+            input.rotate_right = error > AIM_DEADZONE;
+            input.rotate_left = error < -AIM_DEADZONE;
+        }
+        input
+    }
+
+    fn feed_keyboard(&mut self, keys: &InputState) {
+        self.keys = keys.clone();
+    }
+
+    fn feed_mouse(&mut self, mouse: &MouseAim) {
+        self.mouse = *mouse;
+    }
+}
+
+/// Drives the ship with the demo AI at a fixed difficulty.
+#[derive(Debug, Default)]
+pub struct DemoAiController {
+    pub difficulty: AiDifficulty,
+}
+
+impl DemoAiController {
+    pub fn new(difficulty: AiDifficulty) -> Self {
+        Self { difficulty }
+    }
+}
+
+impl Controller for DemoAiController {
+    fn next_input(
+        &mut self,
+        state: &PlayingState,
+        world_width: f64,
+        world_height: f64,
+    ) -> InputState {
+        demo_ai::generate_ai_input(
+            &state.ship,
+            &state.asteroids,
+            &state.bullet_pool,
+            self.difficulty,
+            world_width,
+            world_height,
+        )
+    }
+}
+
+/// Plays back a recorded input log, one InputState per tick.
+/// Combined with a seeded PlayingState this reproduces a game exactly.
+#[derive(Debug, Default)]
+pub struct ReplayController {
+    frames: Vec<InputState>,
+    cursor: usize,
+}
+
+impl ReplayController {
+    pub fn new(frames: Vec<InputState>) -> Self {
+        Self { frames, cursor: 0 }
+    }
+
+    /// Number of ticks already played back.
+    pub fn position(&self) -> usize {
+        self.cursor
+    }
+}
+
+impl Controller for ReplayController {
+    fn next_input(&mut self, _state: &PlayingState, _w: f64, _h: f64) -> InputState {
+        let input = self.frames.get(self.cursor).cloned().unwrap_or_default();
+        self.cursor = (self.cursor + 1).min(self.frames.len());
+        input
+    }
+
+    fn is_finished(&self) -> bool {
+        self.cursor >= self.frames.len()
+    }
+}
+
+/// Runs a tick-by-tick script: each step holds an InputState for a number of ticks.
+#[derive(Debug, Default)]
+pub struct ScriptedController {
+    steps: Vec<(u32, InputState)>,
+    step: usize,
+    ticks_in_step: u32,
+}
+
+impl ScriptedController {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a step that holds `input` for `ticks` ticks.
+    pub fn then(mut self, ticks: u32, input: InputState) -> Self {
+        if ticks > 0 {
+            self.steps.push((ticks, input));
+        }
+        self
+    }
+
+    /// Append a step with no input active for `ticks` ticks.
+    pub fn wait(self, ticks: u32) -> Self {
+        self.then(ticks, InputState::default())
+    }
+}
+
+impl Controller for ScriptedController {
+    fn next_input(&mut self, _state: &PlayingState, _w: f64, _h: f64) -> InputState {
+        let Some((ticks, input)) = self.steps.get(self.step) else {
+            return InputState::default();
+        };
+        let input = input.clone();
+        self.ticks_in_step += 1;
+        if self.ticks_in_step >= *ticks {
+            self.step += 1;
+            self.ticks_in_step = 0;
+        }
+        input
+    }
+
+    fn is_finished(&self) -> bool {
+        self.step >= self.steps.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::physics::Vec2;
+
+    fn state() -> PlayingState {
+        PlayingState::new_seeded(800.0, 600.0, 42)
+    }
+
+    fn thrust() -> InputState {
+        InputState {
+            thrust: true,
+            ..Default::default()
+        }
+    }
+
+    fn fire() -> InputState {
+        InputState {
+            fire: true,
+            ..Default::default()
+        }
+    }
+
+    // === Requirement: Keyboard Controller ===
+
+    // Scenario: Keyboard controller passes through polled keys
+    #[test]
+    fn test_keyboard_passes_through_keys() {
+        let mut controller = KeyboardController::new();
+        assert_eq!(
+            controller.next_input(&state(), 800.0, 600.0),
+            InputState::default()
+        );
+        controller.feed_keyboard(&thrust());
+        assert_eq!(controller.next_input(&state(), 800.0, 600.0), thrust());
+        assert!(!controller.is_finished());
+    }
+
+    // === Requirement: Mouse Controller ===
+
+    fn aim_at(x: f64, y: f64) -> MouseAim {
+        MouseAim {
+            target: Some(Vec2::new(x, y)),
+            thrust: false,
+        }
+    }
+
+    // Scenario: The ship turns toward the cursor
+    #[test]
+    fn test_mouse_turns_toward_cursor() {
+        let mut playing = state();
+        playing.ship.position = Vec2::new(400.0, 300.0);
+        let mut controller = MouseController::new();
+        assert_eq!(
+            controller.next_input(&playing, 800.0, 600.0),
+            InputState::default()
+        );
+        controller.feed_mouse(&aim_at(600.0, 300.0));
+        assert!(controller.next_input(&playing, 800.0, 600.0).rotate_right);
+        controller.feed_mouse(&aim_at(200.0, 300.0));
+        assert!(controller.next_input(&playing, 800.0, 600.0).rotate_left);
+        controller.feed_mouse(&aim_at(400.0, 100.0));
+        let on_target = controller.next_input(&playing, 800.0, 600.0);
+        assert!(!on_target.rotate_left && !on_target.rotate_right);
+        controller.feed_mouse(&aim_at(402.0, 305.0));
+        assert_eq!(
+            controller.next_input(&playing, 800.0, 600.0),
+            InputState::default(),
+            "cursor on the ship"
+        );
+    }
+
+    // Scenario: The ship settles on the cursor
+    #[test]
+    fn test_mouse_aim_settles() {
+        let mut playing = state();
+        playing.ship.position = Vec2::new(400.0, 300.0);
+        let mut controller = MouseController::new();
+        controller.feed_mouse(&aim_at(600.0, 350.0));
+        for _ in 0..120 {
+            let input = controller.next_input(&playing, 800.0, 600.0);
+            playing
+                .ship
+                .rotate(input.rotate_left, input.rotate_right, 1.0 / 60.0);
+        }
+        let error = demo_ai::heading_error(Vec2::new(200.0, 50.0), playing.ship.rotation);
+        assert!(error.abs() <= AIM_DEADZONE, "error {}", error);
+        let input = controller.next_input(&playing, 800.0, 600.0);
+        assert!(!input.rotate_left && !input.rotate_right);
+    }
+
+    // Scenario: Keys and buttons work alongside the aim
+    #[test]
+    fn test_mouse_with_keyboard() {
+        let mut playing = state();
+        playing.ship.position = Vec2::new(400.0, 300.0);
+        let mut controller = MouseController::new();
+        controller.feed_mouse(&MouseAim {
+            target: Some(Vec2::new(600.0, 300.0)),
+            thrust: true,
+        });
+        controller.feed_keyboard(&InputState {
+            rotate_left: true,
+            fire: true,
+            ..Default::default()
+        });
+        let input = controller.next_input(&playing, 800.0, 600.0);
+        assert!(
+            input.rotate_left && !input.rotate_right,
+            "keys override the aim"
+        );
+        assert!(input.thrust && input.fire);
+    }
+
+    // Scenario: Other controllers ignore the mouse
+    #[test]
+    fn test_keyboard_ignores_mouse() {
+        let mut controller = KeyboardController::new();
+        controller.feed_mouse(&MouseAim {
+            target: Some(Vec2::new(600.0, 300.0)),
+            thrust: true,
+        });
+        assert_eq!(
+            controller.next_input(&state(), 800.0, 600.0),
+            InputState::default()
+        );
+    }
+
+    // === Requirement: Demo AI Controller ===
+
+    // Scenario: Demo AI controller matches the AI for its difficulty
+    #[test]
+    fn test_demo_ai_controller_matches_ai() {
+        let playing = state();
+        let mut controller = DemoAiController::new(AiDifficulty::Hard);
+        let expected = demo_ai::generate_ai_input(
+            &playing.ship,
+            &playing.asteroids,
+            &playing.bullet_pool,
+            AiDifficulty::Hard,
+            800.0,
+            600.0,
+        );
+        assert_eq!(controller.next_input(&playing, 800.0, 600.0), expected);
+    }
+
+    // Scenario: Demo AI controller ignores the keyboard
+    #[test]
+    fn test_demo_ai_controller_ignores_keyboard() {
+        let playing = state();
+        let mut controller = DemoAiController::default();
+        let before = controller.next_input(&playing, 800.0, 600.0);
+        controller.feed_keyboard(&InputState {
+            quit: true,
+            ..Default::default()
+        });
+        assert_eq!(controller.next_input(&playing, 800.0, 600.0), before);
+        assert!(!controller.is_finished());
+    }
+
+    // === Requirement: Replay Controller ===
+
+    // Scenario: Replay plays frames in order
+    #[test]
+    fn test_replay_plays_frames_in_order() {
+        let mut controller = ReplayController::new(vec![thrust(), fire()]);
+        assert!(!controller.is_finished());
+        assert_eq!(controller.next_input(&state(), 800.0, 600.0), thrust());
+        assert_eq!(controller.next_input(&state(), 800.0, 600.0), fire());
+        assert_eq!(controller.position(), 2);
+        assert!(controller.is_finished());
+    }
+
+    // Scenario: Exhausted replay produces no input
+    #[test]
+    fn test_replay_exhausted() {
+        let mut controller = ReplayController::new(vec![fire()]);
+        controller.next_input(&state(), 800.0, 600.0);
+        assert_eq!(
+            controller.next_input(&state(), 800.0, 600.0),
+            InputState::default()
+        );
+        assert_eq!(controller.position(), 1);
+    }
+
+    // === Requirement: Scripted Controller ===
+
+    // Scenario: Script holds each step for its tick count
+    #[test]
+    fn test_script_holds_steps() {
+        let mut controller = ScriptedController::new()
+            .then(2, thrust())
+            .wait(1)
+            .then(1, fire());
+        let inputs: Vec<InputState> = (0..4)
+            .map(|_| controller.next_input(&state(), 800.0, 600.0))
+            .collect();
+        assert_eq!(
+            inputs,
+            vec![thrust(), thrust(), InputState::default(), fire()]
+        );
+        assert!(controller.is_finished());
+        assert_eq!(
+            controller.next_input(&state(), 800.0, 600.0),
+            InputState::default()
+        );
+    }
+
+    // Scenario: Zero-tick steps are skipped
+    #[test]
+    fn test_script_skips_zero_tick_steps() {
+        let mut controller = ScriptedController::new().then(0, thrust()).then(1, fire());
+        assert_eq!(controller.next_input(&state(), 800.0, 600.0), fire());
+        assert!(controller.is_finished());
+    }
+}
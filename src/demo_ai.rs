@@ -1,57 +1,282 @@
-// Demo AI capability: AI-controlled ship input for attract mode
+// Demo AI capability: AI-controlled ship input for attract mode and sparring
 
 use crate::asteroids::Asteroid;
+use crate::bullets::{self, BulletPool};
 use crate::collision;
 use crate::input::InputState;
-use crate::ship::Ship;
+use crate::physics::Vec2;
+use crate::ship::{self, Ship};
 use std::f64::consts::PI;
 
 const ROTATION_DEADZONE: f64 = 0.1; // radians — prevents jittery oscillation
-const FIRE_THRESHOLD: f64 = 0.2; // radians — how aligned to fire
+const MIN_FIRE_THRESHOLD: f64 = 0.05; // radians — narrowest firing cone for distant targets
 const THRUST_THRESHOLD: f64 = 0.5; // radians — how aligned to thrust
+const COLLISION_MARGIN: f64 = 8.0; // extra clearance added to ship-asteroid radii
 
-/// Generate AI input for the demo ship. Pure function: reads ship/asteroids, returns InputState.
+/// How well the AI plays. Easy suits the attract mode; Hard makes a sparring opponent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AiDifficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+/// Tuning parameters derived from an `AiDifficulty`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AiProfile {
+    /// Aim at the predicted intercept point instead of the asteroid's current position.
+    pub lead_targeting: bool,
+    /// Seconds ahead an approaching asteroid is ranked as a threat.
+    pub threat_horizon: f64,
+    /// Seconds-to-collision below which the AI thrusts out of the way.
+    pub dodge_time: f64,
+    /// Widest aim error (radians) at which the AI will fire.
+    pub fire_threshold: f64,
+    /// Maximum bullets the AI keeps in flight.
+    pub bullet_budget: usize,
+    /// The AI does not thrust above this speed.
+    pub cruise_speed: f64,
+    /// The AI only closes in on targets farther away than this.
+    pub engage_distance: f64,
+}
+
+impl AiDifficulty {
+    pub fn profile(self) -> AiProfile {
+        match self {
+            AiDifficulty::Easy => AiProfile {
+                lead_targeting: false,
+                threat_horizon: 1.0,
+                dodge_time: 0.0,
+                fire_threshold: 0.3,
+                bullet_budget: 2,
+                cruise_speed: 150.0,
+                engage_distance: 250.0,
+            },
+            AiDifficulty::Normal => AiProfile {
+                lead_targeting: true,
+                threat_horizon: 2.0,
+                dodge_time: 0.6,
+                fire_threshold: 0.2,
+                bullet_budget: 3,
+                cruise_speed: 120.0,
+                engage_distance: 250.0,
+            },
+            AiDifficulty::Hard => AiProfile {
+                lead_targeting: true,
+                threat_horizon: 3.0,
+                dodge_time: 1.0,
+                fire_threshold: 0.12,
+                bullet_budget: bullets::MAX_BULLETS,
+                cruise_speed: 100.0,
+                engage_distance: 200.0,
+            },
+        }
+    }
+}
+
+/// Normalize an angle difference to [-PI, PI].
+fn normalize_angle(mut angle: f64) -> f64 {
+    while angle > PI {
+        angle -= 2.0 * PI;
+    }
+    while angle < -PI {
+        angle += 2.0 * PI;
+    }
+    angle
+}
+
+/// Signed angle the ship must turn through to face along `direction`.
+pub(crate) fn heading_error(direction: Vec2, rotation: f64) -> f64 {
+    normalize_angle(direction.y.atan2(direction.x) - rotation)
+}
+
+/// Smallest positive root of a*t^2 + 2*b*t + c = 0, if any.
+fn smallest_positive_root(a: f64, b: f64, c: f64) -> Option<f64> {
+    if a.abs() < f64::EPSILON {
+        if b.abs() < f64::EPSILON {
+            return None;
+        }
+        let t = -c / (2.0 * b);
+        return (t > 0.0).then_some(t);
+    }
+    let disc = b * b - a * c;
+    if disc < 0.0 {
+        return None;
+    }
+    let sqrt_disc = disc.sqrt();
+    let t1 = (-b - sqrt_disc) / a;
+    let t2 = (-b + sqrt_disc) / a;
+    let (lo, hi) = if t1 < t2 { (t1, t2) } else { (t2, t1) };
+    if lo > 0.0 {
+        Some(lo)
+    } else if hi > 0.0 {
+        Some(hi)
+    } else {
+        None
+    }
+}
+
+/// Solve for where a projectile fired now meets a target moving at constant velocity.
+/// `offset` is the shortest toroidal vector from shooter to target. Returns the aim
+/// vector (relative to the shooter) and the time of flight, or None if the target
+/// outruns the projectile.
+pub fn intercept(
+    offset: Vec2,
+    target_velocity: Vec2,
+    projectile_speed: f64,
+) -> Option<(Vec2, f64)> {
+    let a = target_velocity.dot(target_velocity) - projectile_speed * projectile_speed;
+    let b = offset.dot(target_velocity);
+    let c = offset.dot(offset);
+    if c == 0.0 {
+        return Some((offset, 0.0));
+    }
+    let t = smallest_positive_root(a, b, c)?;
+    Some((offset + target_velocity.scale(t), t))
+}
+
+/// Seconds until the asteroid touches the ship assuming both keep their velocity.
+/// Returns Some(0.0) if already overlapping and None if they never meet.
+pub fn time_to_collision(
+    ship: &Ship,
+    asteroid: &Asteroid,
+    world_width: f64,
+    world_height: f64,
+) -> Option<f64> {
+    let offset =
+        collision::toroidal_direction(ship.position, asteroid.position, world_width, world_height);
+    let relative_velocity = asteroid.velocity - ship.velocity;
+    let reach = asteroid.size.radius() + ship::SHIP_RADIUS + COLLISION_MARGIN;
+    let c = offset.dot(offset) - reach * reach;
+    if c <= 0.0 {
+        return Some(0.0);
+    }
+    let a = relative_velocity.dot(relative_velocity);
+    if a == 0.0 {
+        return None;
+    }
+    smallest_positive_root(a, offset.dot(relative_velocity), c)
+}
+
+/// Generate AI input for the demo ship at the default difficulty.
 pub fn generate_demo_input(
     ship: &Ship,
     asteroids: &[Asteroid],
+    bullets: &BulletPool,
+    world_width: f64,
+    world_height: f64,
+) -> InputState {
+    generate_ai_input(
+        ship,
+        asteroids,
+        bullets,
+        AiDifficulty::default(),
+        world_width,
+        world_height,
+    )
+}
+
+/// Generate AI input for a ship. Pure function: reads ship/asteroids/bullets, returns InputState.
+///
+/// Asteroids are ranked by time to collision; the most imminent threat inside the
+/// profile's horizon becomes the target, otherwise the nearest asteroid. The AI aims
+/// at the intercept point, fires only inside its bullet budget and range, dodges
+/// rocks that are about to hit, and only thrusts toward distant targets at low speed.
+pub fn generate_ai_input(
+    ship: &Ship,
+    asteroids: &[Asteroid],
+    bullets: &BulletPool,
+    difficulty: AiDifficulty,
     world_width: f64,
     world_height: f64,
 ) -> InputState {
     if asteroids.is_empty() {
         return InputState::default();
     }
+    let profile = difficulty.profile();
 
-    // Find nearest asteroid using toroidal distance
-    let nearest = asteroids
+    // Rank threats by time to collision
+    let threat = asteroids
         .iter()
-        .min_by(|a, b| {
-            let da =
-                collision::toroidal_distance(ship.position, a.position, world_width, world_height);
-            let db =
-                collision::toroidal_distance(ship.position, b.position, world_width, world_height);
-            da.partial_cmp(&db).unwrap()
+        .filter_map(|a| {
+            time_to_collision(ship, a, world_width, world_height)
+                .filter(|&t| t <= profile.threat_horizon)
+                .map(|t| (a, t))
         })
-        .unwrap();
+        .min_by(|(_, ta), (_, tb)| ta.partial_cmp(tb).unwrap());
 
-    // Compute direction to nearest asteroid via shortest toroidal path
-    let direction =
-        collision::toroidal_direction(ship.position, nearest.position, world_width, world_height);
-    let target_angle = direction.y.atan2(direction.x);
+    // Without a threat, fall back to the nearest asteroid using toroidal distance
+    let target = match threat {
+        Some((asteroid, _)) => asteroid,
+        None => asteroids
+            .iter()
+            .min_by(|a, b| {
+                let da = collision::toroidal_distance(
+                    ship.position,
+                    a.position,
+                    world_width,
+                    world_height,
+                );
+                let db = collision::toroidal_distance(
+                    ship.position,
+                    b.position,
+                    world_width,
+                    world_height,
+                );
+                da.partial_cmp(&db).unwrap()
+            })
+            .unwrap(),
+    };
 
-    // Signed angle difference, normalized to [-PI, PI]
-    let mut angle_diff = target_angle - ship.rotation;
-    while angle_diff > PI {
-        angle_diff -= 2.0 * PI;
-    }
-    while angle_diff < -PI {
-        angle_diff += 2.0 * PI;
-    }
+    let offset =
+        collision::toroidal_direction(ship.position, target.position, world_width, world_height);
+    let aim = if profile.lead_targeting {
+        intercept(offset, target.velocity, bullets::BULLET_SPEED)
+            .map(|(aim, _)| aim)
+            .unwrap_or(offset)
+    } else {
+        offset
+    };
+    let aim_error = heading_error(aim, ship.rotation);
+    let distance = aim.magnitude();
+
+    // Fire inside a cone that narrows with distance, within budget and range
+    let fire_threshold = (target.size.radius() / distance.max(1.0))
+        .atan()
+        .clamp(MIN_FIRE_THRESHOLD, profile.fire_threshold);
+    let in_range = distance <= world_width * bullets::BULLET_RANGE_FRACTION;
+    let fire = aim_error.abs() < fire_threshold
+        && in_range
+        && bullets.active_count() < profile.bullet_budget;
+
+    let evading = !ship.invulnerable && threat.is_some_and(|(_, t)| t < profile.dodge_time);
+    let (steer_error, thrust) = if evading {
+        // Head perpendicular to the rock's approach, on the side away from it
+        let approach = target.velocity - ship.velocity;
+        let mut escape = Vec2::new(-approach.y, approach.x);
+        if escape.dot(offset) > 0.0 {
+            escape = escape.scale(-1.0);
+        }
+        if escape.magnitude() == 0.0 {
+            escape = offset.scale(-1.0);
+        }
+        let escape_error = heading_error(escape, ship.rotation);
+        (escape_error, escape_error.abs() < THRUST_THRESHOLD)
+    } else {
+        let thrust = threat.is_none()
+            && ship.velocity.magnitude() < profile.cruise_speed
+            && distance > profile.engage_distance
+            && aim_error.abs() < THRUST_THRESHOLD;
+        (aim_error, thrust)
+    };
 
     InputState {
-        rotate_left: angle_diff < -ROTATION_DEADZONE,
-        rotate_right: angle_diff > ROTATION_DEADZONE,
-        thrust: angle_diff.abs() < THRUST_THRESHOLD,
-        fire: angle_diff.abs() < FIRE_THRESHOLD,
+        rotate_left: steer_error < -ROTATION_DEADZONE,
+        rotate_right: steer_error > ROTATION_DEADZONE,
+        thrust,
+        fire,
+        shield: false,
         quit: false,
     }
 }
@@ -74,6 +299,11 @@ mod tests {
         )
     }
 
+    fn make_moving_asteroid(x: f64, y: f64, vx: f64, vy: f64, size: AsteroidSize) -> Asteroid {
+        let mut rng = StdRng::seed_from_u64(1);
+        Asteroid::new(Vec2::new(x, y), Vec2::new(vx, vy), size, &mut rng)
+    }
+
     fn make_ship(x: f64, y: f64, rotation: f64) -> Ship {
         let mut ship = Ship::new(x, y);
         ship.rotation = rotation;
@@ -88,11 +318,11 @@ mod tests {
         let ship = make_ship(400.0, 300.0, 0.0);
         let asteroids = vec![
             make_asteroid(100.0, 100.0), // far
-            make_asteroid(450.0, 310.0), // near
+            make_asteroid(500.0, 320.0), // near, outside collision reach
         ];
-        let input = generate_demo_input(&ship, &asteroids, 800.0, 600.0);
-        // Nearest asteroid is at (450, 310) — slightly below-right.
-        // Angle to it: atan2(10, 50) ≈ 0.197 rad. Ship facing 0.
+        let input = generate_demo_input(&ship, &asteroids, &BulletPool::new(), 800.0, 600.0);
+        // Nearest asteroid is at (500, 320) — slightly below-right.
+        // Angle to it: atan2(20, 100) ≈ 0.197 rad. Ship facing 0.
         // angle_diff ≈ 0.197 > ROTATION_DEADZONE(0.1) → rotate_right
         assert!(input.rotate_right);
         assert!(!input.rotate_left);
@@ -102,7 +332,7 @@ mod tests {
     #[test]
     fn test_ai_handles_no_asteroids() {
         let ship = make_ship(400.0, 300.0, 0.0);
-        let input = generate_demo_input(&ship, &[], 800.0, 600.0);
+        let input = generate_demo_input(&ship, &[], &BulletPool::new(), 800.0, 600.0);
         assert!(!input.rotate_left);
         assert!(!input.rotate_right);
         assert!(!input.thrust);
@@ -116,11 +346,11 @@ mod tests {
         let ship = make_ship(10.0, 300.0, 0.0);
         let asteroids = vec![
             make_asteroid(200.0, 300.0), // direct distance 190
-            make_asteroid(790.0, 300.0), // toroidal distance 20 (wraps left)
+            make_asteroid(700.0, 300.0), // toroidal distance 110 (wraps left)
         ];
-        let input = generate_demo_input(&ship, &asteroids, 800.0, 600.0);
-        // Nearest via toroidal: (790, 300). Direction from (10,300) wraps to (-20, 0).
-        // target_angle = atan2(0, -20) = PI. Ship facing 0. angle_diff = PI.
+        let input = generate_demo_input(&ship, &asteroids, &BulletPool::new(), 800.0, 600.0);
+        // Nearest via toroidal: (700, 300). Direction from (10,300) wraps to (-110, 0).
+        // target_angle = atan2(0, -110) = PI. Ship facing 0. angle_diff = PI.
         // After normalization: PI > THRUST_THRESHOLD → no thrust
         // PI > FIRE_THRESHOLD → no fire
         // PI > ROTATION_DEADZONE → but normalized to PI, which triggers rotate_left
@@ -139,7 +369,7 @@ mod tests {
         // Ship facing right (0), asteroid is below (angle ~PI/2)
         let ship = make_ship(400.0, 300.0, 0.0);
         let asteroids = vec![make_asteroid(400.0, 500.0)];
-        let input = generate_demo_input(&ship, &asteroids, 800.0, 600.0);
+        let input = generate_demo_input(&ship, &asteroids, &BulletPool::new(), 800.0, 600.0);
         // target_angle = atan2(200, 0) = PI/2. angle_diff = PI/2 > 0 → rotate_right
         assert!(input.rotate_right);
         assert!(!input.rotate_left);
@@ -151,7 +381,7 @@ mod tests {
         // Ship facing right (0), asteroid is above (angle ~-PI/2)
         let ship = make_ship(400.0, 300.0, 0.0);
         let asteroids = vec![make_asteroid(400.0, 100.0)];
-        let input = generate_demo_input(&ship, &asteroids, 800.0, 600.0);
+        let input = generate_demo_input(&ship, &asteroids, &BulletPool::new(), 800.0, 600.0);
         // target_angle = atan2(-200, 0) = -PI/2. angle_diff = -PI/2 < 0 → rotate_left
         assert!(input.rotate_left);
         assert!(!input.rotate_right);
@@ -163,7 +393,7 @@ mod tests {
         // Ship facing right (0), asteroid is directly right
         let ship = make_ship(400.0, 300.0, 0.0);
         let asteroids = vec![make_asteroid(600.0, 300.0)];
-        let input = generate_demo_input(&ship, &asteroids, 800.0, 600.0);
+        let input = generate_demo_input(&ship, &asteroids, &BulletPool::new(), 800.0, 600.0);
         // target_angle = atan2(0, 200) = 0. angle_diff = 0 within deadzone.
         assert!(!input.rotate_left);
         assert!(!input.rotate_right);
@@ -176,7 +406,7 @@ mod tests {
     fn test_ai_fires_when_aligned() {
         let ship = make_ship(400.0, 300.0, 0.0);
         let asteroids = vec![make_asteroid(600.0, 300.0)]; // directly ahead
-        let input = generate_demo_input(&ship, &asteroids, 800.0, 600.0);
+        let input = generate_demo_input(&ship, &asteroids, &BulletPool::new(), 800.0, 600.0);
         assert!(input.fire);
     }
 
@@ -185,18 +415,18 @@ mod tests {
     fn test_ai_no_fire_when_misaligned() {
         let ship = make_ship(400.0, 300.0, 0.0);
         let asteroids = vec![make_asteroid(400.0, 100.0)]; // above, angle_diff = -PI/2
-        let input = generate_demo_input(&ship, &asteroids, 800.0, 600.0);
+        let input = generate_demo_input(&ship, &asteroids, &BulletPool::new(), 800.0, 600.0);
         assert!(!input.fire);
     }
 
     // === Requirement: Demo AI Thrust ===
 
-    // Scenario: AI thrusts when roughly facing target
+    // Scenario: AI thrusts when roughly facing a distant target
     #[test]
     fn test_ai_thrusts_when_roughly_facing() {
         let ship = make_ship(400.0, 300.0, 0.0);
-        let asteroids = vec![make_asteroid(600.0, 300.0)]; // directly ahead
-        let input = generate_demo_input(&ship, &asteroids, 800.0, 600.0);
+        let asteroids = vec![make_asteroid(750.0, 300.0)]; // directly ahead, beyond engage distance
+        let input = generate_demo_input(&ship, &asteroids, &BulletPool::new(), 800.0, 600.0);
         assert!(input.thrust);
     }
 
@@ -205,7 +435,7 @@ mod tests {
     fn test_ai_no_thrust_when_facing_away() {
         let ship = make_ship(400.0, 300.0, 0.0);
         let asteroids = vec![make_asteroid(400.0, 100.0)]; // above, angle_diff = -PI/2 > THRUST_THRESHOLD
-        let input = generate_demo_input(&ship, &asteroids, 800.0, 600.0);
+        let input = generate_demo_input(&ship, &asteroids, &BulletPool::new(), 800.0, 600.0);
         assert!(!input.thrust);
     }
 
@@ -216,7 +446,7 @@ mod tests {
     fn test_ai_never_quits() {
         let ship = make_ship(400.0, 300.0, 0.0);
         let asteroids = vec![make_asteroid(600.0, 300.0)];
-        let input = generate_demo_input(&ship, &asteroids, 800.0, 600.0);
+        let input = generate_demo_input(&ship, &asteroids, &BulletPool::new(), 800.0, 600.0);
         assert!(!input.quit);
     }
 
@@ -225,10 +455,10 @@ mod tests {
     fn test_ai_single_asteroid() {
         let ship = make_ship(400.0, 300.0, 0.0);
         let asteroids = vec![make_asteroid(500.0, 300.0)];
-        let input = generate_demo_input(&ship, &asteroids, 800.0, 600.0);
+        let input = generate_demo_input(&ship, &asteroids, &BulletPool::new(), 800.0, 600.0);
         // Should target the only asteroid
         assert!(input.fire); // aligned
-        assert!(input.thrust); // facing roughly right
+        assert!(!input.thrust); // already within engage distance
     }
 
     // Edge case: AI wrapping angle calculation (ship at ~PI, target across boundary)
@@ -237,7 +467,7 @@ mod tests {
         // Ship facing left (PI), asteroid directly behind (angle ~0 = right)
         let ship = make_ship(400.0, 300.0, PI);
         let asteroids = vec![make_asteroid(600.0, 300.0)];
-        let input = generate_demo_input(&ship, &asteroids, 800.0, 600.0);
+        let input = generate_demo_input(&ship, &asteroids, &BulletPool::new(), 800.0, 600.0);
         // target_angle = 0, ship.rotation = PI. angle_diff = 0 - PI = -PI.
         // Normalized: -PI stays -PI. -PI < -ROTATION_DEADZONE → rotate_left
         assert!(input.rotate_left || input.rotate_right); // must rotate to face target
@@ -252,7 +482,7 @@ mod tests {
         // angle_diff = 0 - (-3.5) = 3.5 > PI → 3.5 - 2*PI ≈ -2.78 → rotate_left
         let ship = make_ship(400.0, 300.0, -3.5);
         let asteroids = vec![make_asteroid(600.0, 300.0)];
-        let input = generate_demo_input(&ship, &asteroids, 800.0, 600.0);
+        let input = generate_demo_input(&ship, &asteroids, &BulletPool::new(), 800.0, 600.0);
         assert!(input.rotate_left);
     }
 
@@ -263,7 +493,317 @@ mod tests {
         // angle_diff = 0 - 3.5 = -3.5 < -PI → -3.5 + 2*PI ≈ 2.78 → rotate_right
         let ship = make_ship(400.0, 300.0, 3.5);
         let asteroids = vec![make_asteroid(600.0, 300.0)];
-        let input = generate_demo_input(&ship, &asteroids, 800.0, 600.0);
+        let input = generate_demo_input(&ship, &asteroids, &BulletPool::new(), 800.0, 600.0);
         assert!(input.rotate_right);
     }
+
+    // === Requirement: Demo AI Lead Targeting ===
+
+    // Scenario: Intercept of a stationary target is its position
+    #[test]
+    fn test_intercept_stationary_target() {
+        let (aim, t) = intercept(Vec2::new(200.0, 0.0), Vec2::new(0.0, 0.0), 500.0).unwrap();
+        assert!((aim.x - 200.0).abs() < 1e-9);
+        assert!(aim.y.abs() < 1e-9);
+        assert!((t - 0.4).abs() < 1e-9);
+    }
+
+    // Scenario: Intercept leads a crossing target
+    #[test]
+    fn test_intercept_leads_crossing_target() {
+        let (aim, t) = intercept(Vec2::new(200.0, 0.0), Vec2::new(0.0, 100.0), 500.0).unwrap();
+        // Aim point is ahead of the target along its velocity
+        assert!(aim.y > 0.0);
+        // Bullet and target arrive together
+        assert!((aim.magnitude() - 500.0 * t).abs() < 1e-6);
+    }
+
+    // Scenario: Target faster than the bullet cannot be intercepted
+    #[test]
+    fn test_intercept_outrun() {
+        assert!(intercept(Vec2::new(200.0, 0.0), Vec2::new(600.0, 0.0), 500.0).is_none());
+    }
+
+    // Scenario: Target at the shooter's position is hit immediately
+    #[test]
+    fn test_intercept_zero_offset() {
+        let (_, t) = intercept(Vec2::new(0.0, 0.0), Vec2::new(10.0, 0.0), 500.0).unwrap();
+        assert_eq!(t, 0.0);
+    }
+
+    // Scenario: Target receding at exactly bullet speed uses the linear solution
+    #[test]
+    fn test_intercept_equal_speed() {
+        // Approaching head-on at bullet speed: closing speed 1000 over 200 units
+        let (_, t) = intercept(Vec2::new(200.0, 0.0), Vec2::new(-500.0, 0.0), 500.0).unwrap();
+        assert!((t - 0.2).abs() < 1e-9);
+        // Receding at bullet speed never closes
+        assert!(intercept(Vec2::new(200.0, 0.0), Vec2::new(500.0, 0.0), 500.0).is_none());
+        // Crossing at bullet speed with no closing component never closes either
+        assert!(intercept(Vec2::new(200.0, 0.0), Vec2::new(0.0, 500.0), 500.0).is_none());
+    }
+
+    // Scenario: AI leads a moving target
+    #[test]
+    fn test_ai_leads_moving_target() {
+        // Target directly ahead, moving down; ship faces the target's current position
+        let ship = make_ship(400.0, 300.0, 0.0);
+        let asteroids = vec![make_moving_asteroid(
+            600.0,
+            300.0,
+            0.0,
+            150.0,
+            AsteroidSize::Large,
+        )];
+        let input = generate_ai_input(
+            &ship,
+            &asteroids,
+            &BulletPool::new(),
+            AiDifficulty::Normal,
+            800.0,
+            600.0,
+        );
+        assert!(input.rotate_right); // turn toward the predicted intercept below
+        let easy = generate_ai_input(
+            &ship,
+            &asteroids,
+            &BulletPool::new(),
+            AiDifficulty::Easy,
+            800.0,
+            600.0,
+        );
+        assert!(!easy.rotate_right); // Easy aims at the current position
+    }
+
+    // === Requirement: Demo AI Threat Avoidance ===
+
+    // Scenario: Time to collision for an approaching asteroid
+    #[test]
+    fn test_time_to_collision_approaching() {
+        let ship = make_ship(400.0, 300.0, 0.0);
+        let asteroid = make_moving_asteroid(600.0, 300.0, -100.0, 0.0, AsteroidSize::Large);
+        let t = time_to_collision(&ship, &asteroid, 800.0, 600.0).unwrap();
+        // Gap = 200 - (40 + 12 + margin) closed at 100 u/s
+        let expected = (200.0 - (40.0 + ship::SHIP_RADIUS + COLLISION_MARGIN)) / 100.0;
+        assert!((t - expected).abs() < 1e-9);
+    }
+
+    // Scenario: Receding or stationary asteroids are not threats
+    #[test]
+    fn test_time_to_collision_none() {
+        let ship = make_ship(400.0, 300.0, 0.0);
+        let receding = make_moving_asteroid(600.0, 300.0, 100.0, 0.0, AsteroidSize::Large);
+        let passing = make_moving_asteroid(600.0, 100.0, -100.0, 0.0, AsteroidSize::Small);
+        let still = make_asteroid(600.0, 300.0);
+        assert!(time_to_collision(&ship, &receding, 800.0, 600.0).is_none());
+        assert!(time_to_collision(&ship, &passing, 800.0, 600.0).is_none());
+        assert!(time_to_collision(&ship, &still, 800.0, 600.0).is_none());
+    }
+
+    // Scenario: Overlapping asteroid collides immediately
+    #[test]
+    fn test_time_to_collision_overlapping() {
+        let ship = make_ship(400.0, 300.0, 0.0);
+        let asteroid = make_asteroid(420.0, 300.0);
+        assert_eq!(time_to_collision(&ship, &asteroid, 800.0, 600.0), Some(0.0));
+    }
+
+    // Scenario: Time to collision uses toroidal distance
+    #[test]
+    fn test_time_to_collision_toroidal() {
+        let ship = make_ship(10.0, 300.0, 0.0);
+        // Rock at the far right edge moving right wraps onto the ship
+        let asteroid = make_moving_asteroid(700.0, 300.0, 100.0, 0.0, AsteroidSize::Large);
+        assert!(time_to_collision(&ship, &asteroid, 800.0, 600.0).is_some());
+    }
+
+    // Scenario: AI targets the most imminent threat over the nearest asteroid
+    #[test]
+    fn test_ai_targets_most_imminent_threat() {
+        let ship = make_ship(400.0, 300.0, 0.0);
+        let asteroids = vec![
+            make_asteroid(400.0, 160.0), // nearest, stationary, above
+            make_moving_asteroid(650.0, 300.0, -150.0, 0.0, AsteroidSize::Small), // incoming from right
+        ];
+        let input = generate_ai_input(
+            &ship,
+            &asteroids,
+            &BulletPool::new(),
+            AiDifficulty::Hard,
+            800.0,
+            600.0,
+        );
+        // Facing the threat already — fire at it instead of turning to the nearer rock
+        assert!(input.fire);
+        assert!(!input.rotate_left);
+    }
+
+    // Scenario: AI dodges an asteroid about to hit
+    #[test]
+    fn test_ai_dodges_imminent_collision() {
+        // Rock approaching from the right; ship faces up (perpendicular to approach)
+        let ship = make_ship(400.0, 300.0, -PI / 2.0);
+        let asteroids = vec![make_moving_asteroid(
+            500.0,
+            300.0,
+            -100.0,
+            0.0,
+            AsteroidSize::Large,
+        )];
+        let input = generate_ai_input(
+            &ship,
+            &asteroids,
+            &BulletPool::new(),
+            AiDifficulty::Normal,
+            800.0,
+            600.0,
+        );
+        assert!(input.thrust);
+    }
+
+    // Scenario: Easy AI does not dodge
+    #[test]
+    fn test_easy_ai_does_not_dodge() {
+        let ship = make_ship(400.0, 300.0, -PI / 2.0);
+        let asteroids = vec![make_moving_asteroid(
+            500.0,
+            300.0,
+            -100.0,
+            0.0,
+            AsteroidSize::Large,
+        )];
+        let input = generate_ai_input(
+            &ship,
+            &asteroids,
+            &BulletPool::new(),
+            AiDifficulty::Easy,
+            800.0,
+            600.0,
+        );
+        assert!(!input.thrust);
+    }
+
+    // Scenario: Invulnerable ship does not dodge
+    #[test]
+    fn test_invulnerable_ai_does_not_dodge() {
+        let mut ship = make_ship(400.0, 300.0, -PI / 2.0);
+        ship.invulnerable = true;
+        let asteroids = vec![make_moving_asteroid(
+            500.0,
+            300.0,
+            -100.0,
+            0.0,
+            AsteroidSize::Large,
+        )];
+        let input = generate_ai_input(
+            &ship,
+            &asteroids,
+            &BulletPool::new(),
+            AiDifficulty::Normal,
+            800.0,
+            600.0,
+        );
+        assert!(!input.thrust);
+    }
+
+    // Scenario: AI with no relative motion escapes directly away
+    #[test]
+    fn test_ai_dodges_matching_velocity() {
+        // Overlapping rock moving with the ship: escape is directly away (left)
+        let mut ship = make_ship(400.0, 300.0, PI);
+        ship.velocity = Vec2::new(30.0, 0.0);
+        let asteroids = vec![make_moving_asteroid(
+            440.0,
+            300.0,
+            30.0,
+            0.0,
+            AsteroidSize::Large,
+        )];
+        let input = generate_ai_input(
+            &ship,
+            &asteroids,
+            &BulletPool::new(),
+            AiDifficulty::Normal,
+            800.0,
+            600.0,
+        );
+        assert!(input.thrust);
+        assert!(!input.rotate_left && !input.rotate_right);
+    }
+
+    // === Requirement: Demo AI Bullet Budget ===
+
+    // Scenario: AI holds fire when its bullet budget is spent
+    #[test]
+    fn test_ai_respects_bullet_budget() {
+        let ship = make_ship(400.0, 300.0, 0.0);
+        let asteroids = vec![make_asteroid(600.0, 300.0)];
+        let mut pool = BulletPool::new();
+        for _ in 0..AiDifficulty::Normal.profile().bullet_budget {
+            pool.fire(Vec2::new(0.0, 0.0), 0.0);
+        }
+        let input = generate_demo_input(&ship, &asteroids, &pool, 800.0, 600.0);
+        assert!(!input.fire);
+    }
+
+    // Scenario: AI does not fire at targets out of bullet range
+    #[test]
+    fn test_ai_no_fire_out_of_range() {
+        // 300x800 world: range is 80% of width = 240, target is 350 below
+        let ship = make_ship(150.0, 50.0, PI / 2.0);
+        let asteroids = vec![make_asteroid(150.0, 400.0)];
+        let input = generate_demo_input(&ship, &asteroids, &BulletPool::new(), 300.0, 800.0);
+        assert!(!input.fire);
+    }
+
+    // === Requirement: Demo AI Thrust Discipline ===
+
+    // Scenario: AI does not thrust above cruise speed
+    #[test]
+    fn test_ai_no_thrust_above_cruise_speed() {
+        let mut ship = make_ship(400.0, 300.0, 0.0);
+        ship.velocity = Vec2::new(0.0, AiDifficulty::Normal.profile().cruise_speed + 1.0);
+        let asteroids = vec![make_asteroid(750.0, 300.0)];
+        let input = generate_demo_input(&ship, &asteroids, &BulletPool::new(), 800.0, 600.0);
+        assert!(!input.thrust);
+    }
+
+    // === Requirement: Demo AI Difficulty Levels ===
+
+    // Scenario: Harder difficulties aim tighter and react earlier
+    #[test]
+    fn test_difficulty_profiles_ordered() {
+        let easy = AiDifficulty::Easy.profile();
+        let normal = AiDifficulty::Normal.profile();
+        let hard = AiDifficulty::Hard.profile();
+        assert!(easy.fire_threshold > normal.fire_threshold);
+        assert!(normal.fire_threshold > hard.fire_threshold);
+        assert!(easy.threat_horizon < normal.threat_horizon);
+        assert!(normal.threat_horizon < hard.threat_horizon);
+        assert!(easy.dodge_time < normal.dodge_time);
+        assert!(normal.dodge_time < hard.dodge_time);
+        assert!(easy.bullet_budget <= normal.bullet_budget);
+        assert!(hard.bullet_budget <= crate::bullets::MAX_BULLETS);
+        assert!(!easy.lead_targeting);
+        assert!(hard.lead_targeting);
+    }
+
+    // Scenario: Demo input uses the default difficulty
+    #[test]
+    fn test_demo_input_uses_default_difficulty() {
+        assert_eq!(AiDifficulty::default(), AiDifficulty::Normal);
+        let ship = make_ship(400.0, 300.0, 0.3);
+        let asteroids = vec![make_moving_asteroid(
+            650.0,
+            200.0,
+            -20.0,
+            40.0,
+            AsteroidSize::Medium,
+        )];
+        let pool = BulletPool::new();
+        assert_eq!(
+            generate_demo_input(&ship, &asteroids, &pool, 800.0, 600.0),
+            generate_ai_input(&ship, &asteroids, &pool, AiDifficulty::Normal, 800.0, 600.0)
+        );
+    }
 }
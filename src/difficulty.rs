@@ -0,0 +1,243 @@
+// Difficulty capability: presets scaling wave size, rock speed and bullet limits
+
+use serde::{Deserialize, Serialize};
+
+/// Steps the adaptive option can ease or harden the preset by.
+pub const ADAPTIVE_RANGE: i32 = 2;
+/// Rock speed change per adaptive step.
+pub const ADAPTIVE_SPEED_STEP: f64 = 0.15;
+/// Deaths in one wave that make the next one easier.
+pub const ADAPTIVE_EASE_DEATHS: u32 = 2;
+
+/// How hard waves are.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Difficulty {
+    /// Fewer, slower rocks, capped at 8, and six bullets.
+    Easy,
+    /// One more rock each wave with no cap, as the game has always played.
+    #[default]
+    Normal,
+    /// The arcade curve: two more rocks a wave up to 11, speeding up as waves go.
+    Arcade,
+    /// Crowded, fast waves and only three bullets.
+    Insane,
+}
+
+/// Wave composition and limits for a difficulty.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WaveSettings {
+    /// Large rocks in wave 1.
+    pub initial_count: u32,
+    /// Extra rocks per wave after the first.
+    pub growth: f64,
+    /// Most rocks a wave starts with, if limited.
+    pub max_count: Option<u32>,
+    /// Spawn speed range in wave 1.
+    pub min_speed: f64,
+    pub max_speed: f64,
+    /// Added to both ends of the speed range each wave.
+    pub speed_growth: f64,
+    /// Fastest a rock spawns, however late the wave.
+    pub speed_cap: f64,
+    /// Children fly this much faster than the rock that split.
+    pub split_speed: f64,
+    /// Bullets a ship can have in flight.
+    pub max_bullets: usize,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 4] = [
+        Difficulty::Easy,
+        Difficulty::Normal,
+        Difficulty::Arcade,
+        Difficulty::Insane,
+    ];
+
+    /// Name shown on the menu.
+    pub fn name(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "EASY",
+            Difficulty::Normal => "NORMAL",
+            Difficulty::Arcade => "ARCADE",
+            Difficulty::Insane => "INSANE",
+        }
+    }
+
+    /// The difficulty after this one in menu order, wrapping around.
+    pub fn next(self) -> Difficulty {
+        let index = Self::ALL.iter().position(|&d| d == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    pub fn settings(self) -> WaveSettings {
+        match self {
+            Difficulty::Easy => WaveSettings {
+                initial_count: 3,
+                growth: 0.5,
+                max_count: Some(8),
+                min_speed: 15.0,
+                max_speed: 50.0,
+                speed_growth: 2.0,
+                speed_cap: 70.0,
+                split_speed: 1.1,
+                max_bullets: 6,
+            },
+            Difficulty::Normal => WaveSettings {
+                initial_count: 4,
+                growth: 1.0,
+                max_count: None,
+                min_speed: 20.0,
+                max_speed: 80.0,
+                speed_growth: 0.0,
+                speed_cap: 80.0,
+                split_speed: 1.2,
+                max_bullets: 4,
+            },
+            Difficulty::Arcade => WaveSettings {
+                initial_count: 4,
+                growth: 2.0,
+                max_count: Some(11),
+                min_speed: 20.0,
+                max_speed: 80.0,
+                speed_growth: 5.0,
+                speed_cap: 120.0,
+                split_speed: 1.3,
+                max_bullets: 4,
+            },
+            Difficulty::Insane => WaveSettings {
+                initial_count: 6,
+                growth: 2.0,
+                max_count: Some(16),
+                min_speed: 40.0,
+                max_speed: 110.0,
+                speed_growth: 10.0,
+                speed_cap: 200.0,
+                split_speed: 1.5,
+                max_bullets: 3,
+            },
+        }
+    }
+}
+
+impl WaveSettings {
+    /// Large rocks in `wave` (1-based).
+    pub fn asteroid_count(&self, wave: u32) -> u32 {
+        let grown = self.growth * wave.saturating_sub(1) as f64;
+        let count = self.initial_count.saturating_add(grown as u32);
+        self.max_count.map_or(count, |max| count.min(max))
+    }
+
+    /// Spawn speed range for `wave`; the low end never passes the high end.
+    pub fn speed_range(&self, wave: u32) -> (f64, f64) {
+        let extra = self.speed_growth * wave.saturating_sub(1) as f64;
+        let max = (self.max_speed + extra).min(self.speed_cap);
+        let min = (self.min_speed + extra).min(max);
+        (min, max)
+    }
+
+    /// Ease (negative `level`) or harden the settings by adaptive steps: one rock and
+    /// `ADAPTIVE_SPEED_STEP` of speed per step.
+    pub fn adjusted(self, level: i32) -> WaveSettings {
+        let level = level.clamp(-ADAPTIVE_RANGE, ADAPTIVE_RANGE);
+        let speed = 1.0 + ADAPTIVE_SPEED_STEP * level as f64;
+        WaveSettings {
+            initial_count: self.initial_count.saturating_add_signed(level).max(1),
+            max_count: self
+                .max_count
+                .map(|max| max.saturating_add_signed(level).max(1)),
+            min_speed: self.min_speed * speed,
+            max_speed: self.max_speed * speed,
+            speed_cap: self.speed_cap * speed,
+            ..self
+        }
+    }
+}
+
+/// The adaptive step after a wave with `deaths` deaths: easier after a rough wave,
+/// harder after a clean one.
+pub fn adapt(level: i32, deaths: u32) -> i32 {
+    let step = match deaths {
+        0 => 1,
+        d if d >= ADAPTIVE_EASE_DEATHS => -1,
+        _ => 0,
+    };
+    (level + step).clamp(-ADAPTIVE_RANGE, ADAPTIVE_RANGE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // === Requirement: Difficulty Presets ===
+
+    // Scenario: Normal keeps the classic wave curve
+    #[test]
+    fn test_normal_matches_classic() {
+        let normal = Difficulty::default().settings();
+        for wave in 1..30 {
+            assert_eq!(
+                normal.asteroid_count(wave),
+                crate::asteroids::wave_asteroid_count(wave)
+            );
+            assert_eq!(normal.speed_range(wave), (20.0, 80.0));
+        }
+        assert_eq!(normal.split_speed, crate::asteroids::SPLIT_SPEED);
+        assert_eq!(normal.max_bullets, crate::bullets::MAX_BULLETS);
+    }
+
+    // Scenario: Presets grow waves and speeds up to their caps
+    #[test]
+    fn test_growth_and_caps() {
+        let arcade = Difficulty::Arcade.settings();
+        let counts: Vec<u32> = (1..=6).map(|wave| arcade.asteroid_count(wave)).collect();
+        assert_eq!(counts, vec![4, 6, 8, 10, 11, 11]);
+        assert_eq!(arcade.speed_range(3), (30.0, 90.0));
+        assert_eq!(arcade.speed_range(50).1, 120.0);
+
+        let easy = Difficulty::Easy.settings();
+        assert_eq!(easy.asteroid_count(1), 3);
+        assert_eq!(easy.asteroid_count(3), 4);
+        assert_eq!(easy.asteroid_count(100), 8);
+
+        // The range never inverts, even far past the cap
+        let (min, max) = Difficulty::Insane.settings().speed_range(1000);
+        assert!(min <= max && max == 200.0);
+        assert!(
+            Difficulty::Insane.settings().max_bullets < Difficulty::Easy.settings().max_bullets
+        );
+    }
+
+    // Scenario: Difficulties cycle in menu order
+    #[test]
+    fn test_next_cycles() {
+        let names: Vec<&str> = Difficulty::ALL.iter().map(|d| d.next().name()).collect();
+        assert_eq!(names, vec!["NORMAL", "ARCADE", "INSANE", "EASY"]);
+    }
+
+    // === Requirement: Adaptive Difficulty ===
+
+    // Scenario: Rough waves ease the next one and clean waves harden it
+    #[test]
+    fn test_adapt() {
+        assert_eq!(adapt(0, 0), 1);
+        assert_eq!(adapt(0, 1), 0);
+        assert_eq!(adapt(0, 2), -1);
+        assert_eq!(adapt(ADAPTIVE_RANGE, 0), ADAPTIVE_RANGE);
+        assert_eq!(adapt(-ADAPTIVE_RANGE, 5), -ADAPTIVE_RANGE);
+    }
+
+    // Scenario: Adaptive steps change rock count and speed
+    #[test]
+    fn test_adjusted() {
+        let normal = Difficulty::Normal.settings();
+        assert_eq!(normal.adjusted(0), normal);
+        let harder = normal.adjusted(1);
+        assert_eq!(harder.asteroid_count(1), 5);
+        assert!((harder.speed_range(1).1 - 92.0).abs() < 1e-9);
+        let easier = Difficulty::Easy.settings().adjusted(-10);
+        assert_eq!(easier.asteroid_count(1), 1);
+        assert_eq!(easier.max_count, Some(6));
+        assert!((easier.speed_range(1).0 - 10.5).abs() < 1e-9);
+    }
+}
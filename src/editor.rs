@@ -0,0 +1,778 @@
+// Editor capability: building level packs with the mouse on the toroidal field
+//
+// The editor works on the standard 800x600 field that level files use. Each wave is a
+// list of rocks with fixed positions, velocities, outlines and spins. Tools decide
+// what the mouse does: the rock tool places, moves and deletes rocks, the velocity
+// tool drags out where a rock will be a second later, and the shape tool drags or
+// removes outline vertices. Everything measures across the wrap, so a rock near one
+// edge can be grabbed from the other.
+
+use crate::asteroids::{Asteroid, AsteroidSize};
+use crate::collision;
+use crate::game::{GameRng, WORLD_HEIGHT, WORLD_WIDTH};
+use crate::level::{LevelPack, Motion, Placement, RockGroup, WaveDef, WinCondition};
+use crate::physics::{self, Vec2};
+use crate::renderer::{self, Playfield, EDITOR_FOOTER_ROWS};
+use crate::save;
+use crossterm::event::{KeyCode, MouseButton, MouseEvent, MouseEventKind};
+use rand::SeedableRng;
+use std::path::PathBuf;
+
+/// Farthest a click can be from a vertex and still grab it, in field units.
+pub const VERTEX_GRAB: f64 = 12.0;
+/// Fewest vertices a rock outline keeps.
+pub const MIN_VERTICES: usize = 3;
+/// Time limits T steps through, in seconds.
+pub const TIME_LIMITS: [Option<f64>; 4] = [None, Some(30.0), Some(60.0), Some(120.0)];
+/// Time limit given to a survive wave that has none.
+pub const SURVIVE_TIME: f64 = 30.0;
+/// Target of score waves made in the editor.
+pub const SCORE_TARGET: u32 = 1000;
+pub const NEW_LEVEL_NAME: &str = "Custom level";
+/// File the editor saves to when no level file is given.
+pub const LEVEL_FILE: &str = "level.toml";
+pub const HELP: &str =
+    "1-3 Size  Tab Tool  N New wave  [ ] Wave  T Time  W Win  Enter Test  S Save  Q Menu";
+
+/// What the left and right mouse buttons do.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Tool {
+    /// Left places or moves a rock, right deletes it.
+    #[default]
+    Rocks,
+    /// Left drags out a rock's velocity, right stops it.
+    Velocity,
+    /// Left drags a vertex, right removes it.
+    Shape,
+}
+
+impl Tool {
+    pub fn name(self) -> &'static str {
+        match self {
+            Tool::Rocks => "ROCKS",
+            Tool::Velocity => "VELOCITY",
+            Tool::Shape => "SHAPE",
+        }
+    }
+
+    pub fn next(self) -> Tool {
+        match self {
+            Tool::Rocks => Tool::Velocity,
+            Tool::Velocity => Tool::Shape,
+            Tool::Shape => Tool::Rocks,
+        }
+    }
+}
+
+/// A mouse action on the field, in field coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Pointer {
+    Press(MouseButton, Vec2),
+    Drag(Vec2),
+    Release,
+}
+
+/// What the game should do after a key in the editor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditorCommand {
+    None,
+    TestPlay,
+    Save,
+    Exit,
+}
+
+/// One wave as laid out in the editor.
+#[derive(Clone)]
+pub struct EditorWave {
+    pub rocks: Vec<Asteroid>,
+    pub time_limit: Option<f64>,
+    pub win: WinCondition,
+}
+
+impl EditorWave {
+    fn new() -> Self {
+        Self {
+            rocks: Vec::new(),
+            time_limit: None,
+            win: WinCondition::Clear,
+        }
+    }
+}
+
+/// What the held mouse button is doing.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Drag {
+    /// Moving a rock, keeping the grab point's offset from its centre.
+    Move {
+        rock: usize,
+        offset: Vec2,
+    },
+    Velocity {
+        rock: usize,
+    },
+    Vertex {
+        rock: usize,
+        vertex: usize,
+    },
+}
+
+pub struct Editor {
+    pub name: String,
+    pub waves: Vec<EditorWave>,
+    /// Index of the wave on screen.
+    pub current: usize,
+    /// Size of rocks placed next.
+    pub size: AsteroidSize,
+    pub tool: Tool,
+    drag: Option<Drag>,
+    rng: GameRng,
+}
+
+impl Default for Editor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Editor {
+    /// A new level with one empty wave.
+    pub fn new() -> Self {
+        Self {
+            name: NEW_LEVEL_NAME.to_string(),
+            waves: vec![EditorWave::new()],
+            current: 0,
+            size: AsteroidSize::Large,
+            tool: Tool::Rocks,
+            drag: None,
+            rng: GameRng::from_entropy(),
+        }
+    }
+
+    /// Edit an existing pack. Rocks the file places or aims at random are fixed where
+    /// they first land.
+    pub fn from_level(pack: &LevelPack) -> Self {
+        let mut editor = Self::new();
+        editor.name = pack.name.clone();
+        let center = Vec2::new(WORLD_WIDTH / 2.0, WORLD_HEIGHT / 2.0);
+        editor.waves = (1..=pack.waves.len() as u32)
+            .zip(&pack.waves)
+            .map(|(wave, def)| EditorWave {
+                rocks: pack
+                    .spawn(wave, center, WORLD_WIDTH, WORLD_HEIGHT, &mut editor.rng)
+                    .into_iter()
+                    .map(unrotated)
+                    .collect(),
+                time_limit: def.time_limit,
+                win: def.win,
+            })
+            .collect();
+        editor
+    }
+
+    pub fn wave(&self) -> &EditorWave {
+        &self.waves[self.current]
+    }
+
+    fn rocks_mut(&mut self) -> &mut Vec<Asteroid> {
+        &mut self.waves[self.current].rocks
+    }
+
+    /// The topmost rock under `point`, if any.
+    pub fn rock_at(&self, point: Vec2) -> Option<usize> {
+        self.wave().rocks.iter().rposition(|rock| {
+            collision::toroidal_distance(rock.position, point, WORLD_WIDTH, WORLD_HEIGHT)
+                < rock.size.radius()
+        })
+    }
+
+    /// The rock and index of the vertex nearest `point`, within `VERTEX_GRAB`.
+    pub fn vertex_at(&self, point: Vec2) -> Option<(usize, usize)> {
+        let mut nearest = None;
+        let mut best = VERTEX_GRAB;
+        for (r, rock) in self.wave().rocks.iter().enumerate() {
+            for (v, vertex) in rock.world_vertices().into_iter().enumerate() {
+                let distance =
+                    collision::toroidal_distance(vertex, point, WORLD_WIDTH, WORLD_HEIGHT);
+                if distance <= best {
+                    best = distance;
+                    nearest = Some((r, v));
+                }
+            }
+        }
+        nearest
+    }
+
+    /// Apply a mouse action with the current tool.
+    pub fn pointer(&mut self, pointer: Pointer) {
+        match pointer {
+            Pointer::Press(MouseButton::Left, point) => self.press(point),
+            Pointer::Press(MouseButton::Right, point) => self.remove(point),
+            Pointer::Press(MouseButton::Middle, _) => {}
+            Pointer::Drag(point) => self.drag_to(point),
+            Pointer::Release => self.drag = None,
+        }
+    }
+
+    fn press(&mut self, point: Vec2) {
+        self.drag = match self.tool {
+            Tool::Rocks => match self.rock_at(point) {
+                Some(rock) => Some(Drag::Move {
+                    rock,
+                    offset: toroidal_offset(point, self.wave().rocks[rock].position),
+                }),
+                None => {
+                    let rock = unrotated(Asteroid::new(
+                        point,
+                        Vec2::new(0.0, 0.0),
+                        self.size,
+                        &mut self.rng,
+                    ));
+                    self.rocks_mut().push(rock);
+                    Some(Drag::Move {
+                        rock: self.wave().rocks.len() - 1,
+                        offset: Vec2::new(0.0, 0.0),
+                    })
+                }
+            },
+            Tool::Velocity => self.rock_at(point).map(|rock| Drag::Velocity { rock }),
+            Tool::Shape => self
+                .vertex_at(point)
+                .map(|(rock, vertex)| Drag::Vertex { rock, vertex }),
+        };
+    }
+
+    fn remove(&mut self, point: Vec2) {
+        self.drag = None;
+        match self.tool {
+            Tool::Rocks => {
+                if let Some(rock) = self.rock_at(point) {
+                    self.rocks_mut().remove(rock);
+                }
+            }
+            Tool::Velocity => {
+                if let Some(rock) = self.rock_at(point) {
+                    self.rocks_mut()[rock].velocity = Vec2::new(0.0, 0.0);
+                }
+            }
+            Tool::Shape => {
+                if let Some((rock, vertex)) = self.vertex_at(point) {
+                    let vertices = &mut self.rocks_mut()[rock].vertices;
+                    if vertices.len() > MIN_VERTICES {
+                        vertices.remove(vertex);
+                    }
+                }
+            }
+        }
+    }
+
+    fn drag_to(&mut self, point: Vec2) {
+        let Some(drag) = self.drag else {
+            return;
+        };
+        match drag {
+            Drag::Move { rock, offset } => {
+                let position = Vec2::new(point.x + offset.x, point.y + offset.y);
+                self.rocks_mut()[rock].position =
+                    physics::wrap_position(position, WORLD_WIDTH, WORLD_HEIGHT);
+            }
+            Drag::Velocity { rock } => {
+                let rock = &mut self.rocks_mut()[rock];
+                rock.velocity = toroidal_offset(rock.position, point);
+            }
+            Drag::Vertex { rock, vertex } => {
+                let rock = &mut self.rocks_mut()[rock];
+                rock.vertices[vertex] = toroidal_offset(rock.position, point);
+            }
+        }
+    }
+
+    /// Handle a key. Returns what the game should do next, if anything.
+    pub fn handle_key(&mut self, code: KeyCode) -> EditorCommand {
+        self.drag = None;
+        match code {
+            KeyCode::Char('1') => self.size = AsteroidSize::Large,
+            KeyCode::Char('2') => self.size = AsteroidSize::Medium,
+            KeyCode::Char('3') => self.size = AsteroidSize::Small,
+            KeyCode::Tab => self.tool = self.tool.next(),
+            KeyCode::Char('n') | KeyCode::Char('N') => {
+                self.current += 1;
+                self.waves.insert(self.current, EditorWave::new());
+            }
+            KeyCode::Char('[') | KeyCode::PageUp => self.current = self.current.saturating_sub(1),
+            KeyCode::Char(']') | KeyCode::PageDown => {
+                self.current = (self.current + 1).min(self.waves.len() - 1)
+            }
+            KeyCode::Char('t') | KeyCode::Char('T') => self.next_time_limit(),
+            KeyCode::Char('w') | KeyCode::Char('W') => self.next_win(),
+            KeyCode::Enter => return EditorCommand::TestPlay,
+            KeyCode::Char('s') | KeyCode::Char('S') => return EditorCommand::Save,
+            KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => return EditorCommand::Exit,
+            _ => {}
+        }
+        EditorCommand::None
+    }
+
+    /// Step the wave's time limit through `TIME_LIMITS`. A survive wave cannot lose
+    /// its limit, so it becomes a clear wave instead.
+    fn next_time_limit(&mut self) {
+        let wave = &mut self.waves[self.current];
+        let index = TIME_LIMITS
+            .iter()
+            .position(|&limit| limit == wave.time_limit)
+            .unwrap_or(0);
+        wave.time_limit = TIME_LIMITS[(index + 1) % TIME_LIMITS.len()];
+        if wave.time_limit.is_none() && wave.win == WinCondition::Survive {
+            wave.win = WinCondition::Clear;
+        }
+    }
+
+    /// Step the wave's win condition: clear, survive, then score. Surviving needs a
+    /// time limit, so one is set if the wave has none.
+    fn next_win(&mut self) {
+        let wave = &mut self.waves[self.current];
+        wave.win = match wave.win {
+            WinCondition::Clear => WinCondition::Survive,
+            WinCondition::Survive => WinCondition::Score(SCORE_TARGET),
+            WinCondition::Score(_) => WinCondition::Clear,
+        };
+        if wave.win == WinCondition::Survive && wave.time_limit.is_none() {
+            wave.time_limit = Some(SURVIVE_TIME);
+        }
+    }
+
+    /// The whole level as a pack, leaving out empty waves. None if no wave has rocks.
+    pub fn level(&self) -> Option<LevelPack> {
+        self.pack(&self.waves)
+    }
+
+    /// The level from the wave on screen onwards, for test play.
+    pub fn test_level(&self) -> Option<LevelPack> {
+        self.pack(&self.waves[self.current..])
+    }
+
+    fn pack(&self, waves: &[EditorWave]) -> Option<LevelPack> {
+        let waves: Vec<WaveDef> = waves
+            .iter()
+            .filter(|wave| !wave.rocks.is_empty())
+            .map(|wave| WaveDef {
+                rocks: wave.rocks.iter().map(rock_group).collect(),
+                time_limit: wave.time_limit,
+                win: wave.win,
+            })
+            .collect();
+        (!waves.is_empty()).then(|| LevelPack {
+            name: self.name.clone(),
+            waves,
+        })
+    }
+
+    /// The wave on screen: rock outlines, a line to where each moving rock will be in
+    /// a second, and vertex handles with the shape tool.
+    pub fn playfield(&self) -> Playfield {
+        let mut playfield = Playfield::new(WORLD_WIDTH, WORLD_HEIGHT);
+        for rock in &self.wave().rocks {
+            let outline = rock.world_vertices();
+            playfield.add_polygon(&outline);
+            if rock.velocity.magnitude() > 0.0 {
+                let end = Vec2::new(
+                    rock.position.x + rock.velocity.x,
+                    rock.position.y + rock.velocity.y,
+                );
+                playfield.add_polygon(&[rock.position, end]);
+            }
+            if self.tool == Tool::Shape {
+                for vertex in outline {
+                    playfield.add_dot(vertex);
+                }
+            }
+        }
+        playfield
+    }
+
+    /// Status line: wave, rock count, tool, size, time limit and win condition.
+    pub fn status(&self) -> String {
+        let wave = self.wave();
+        let time = match wave.time_limit {
+            Some(limit) => format!("{}s", limit),
+            None => "none".to_string(),
+        };
+        let win = match wave.win {
+            WinCondition::Clear => "clear".to_string(),
+            WinCondition::Survive => "survive".to_string(),
+            WinCondition::Score(score) => format!("score {}", score),
+        };
+        format!(
+            "{}  Wave {}/{}  Rocks: {}  Tool: {}  Size: {}  Time: {}  Win: {}",
+            self.name,
+            self.current + 1,
+            self.waves.len(),
+            wave.rocks.len(),
+            self.tool.name(),
+            size_name(self.size),
+            time,
+            win
+        )
+    }
+}
+
+/// The shortest offset from `from` to `to` across the wrap.
+fn toroidal_offset(from: Vec2, to: Vec2) -> Vec2 {
+    collision::toroidal_direction(from, to, WORLD_WIDTH, WORLD_HEIGHT)
+}
+
+/// The rock with its rotation turned into its outline, so outlines are edited and
+/// saved as they look.
+fn unrotated(rock: Asteroid) -> Asteroid {
+    let vertices = rock
+        .world_vertices()
+        .into_iter()
+        .map(|v| Vec2::new(v.x - rock.position.x, v.y - rock.position.y))
+        .collect();
+    Asteroid {
+        rotation: 0.0,
+        vertices,
+        ..rock
+    }
+}
+
+/// Round to a tenth, so saved files stay readable.
+fn tidy(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
+
+fn tidy_vec(v: Vec2) -> Vec2 {
+    Vec2::new(tidy(v.x), tidy(v.y))
+}
+
+fn rock_group(rock: &Asteroid) -> RockGroup {
+    RockGroup {
+        size: rock.size,
+        count: 1,
+        placement: Placement::At(tidy_vec(rock.position)),
+        motion: Motion::Velocity(tidy_vec(rock.velocity)),
+        shape: Some(rock.vertices.iter().copied().map(tidy_vec).collect()),
+        spin: Some(tidy(rock.angular_velocity)),
+    }
+}
+
+fn size_name(size: AsteroidSize) -> &'static str {
+    match size {
+        AsteroidSize::Large => "LARGE",
+        AsteroidSize::Medium => "MEDIUM",
+        AsteroidSize::Small => "SMALL",
+    }
+}
+
+/// Where the editor keeps its level when no file is given, next to the quicksave.
+pub fn default_path() -> PathBuf {
+    save::data_dir().join(LEVEL_FILE)
+}
+
+/// Turn a terminal mouse event into an editor action, given the terminal's size.
+/// Presses on the footer lines are ignored; drags past the field stop at its edge.
+pub fn pointer_from_mouse(event: &MouseEvent, cols: u16, rows: u16) -> Option<Pointer> {
+    let field_rows = (rows as usize).saturating_sub(EDITOR_FOOTER_ROWS);
+    if cols == 0 || field_rows == 0 {
+        return None;
+    }
+    let row = event.row as usize;
+    let point = |row: usize| {
+        renderer::cell_to_world(
+            event.column,
+            row as u16,
+            cols as usize,
+            field_rows,
+            WORLD_WIDTH,
+            WORLD_HEIGHT,
+        )
+    };
+    match event.kind {
+        MouseEventKind::Down(button) if row < field_rows => {
+            Some(Pointer::Press(button, point(row)))
+        }
+        MouseEventKind::Drag(_) => Some(Pointer::Drag(point(row.min(field_rows - 1)))),
+        MouseEventKind::Up(_) => Some(Pointer::Release),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn left(x: f64, y: f64) -> Pointer {
+        Pointer::Press(MouseButton::Left, Vec2::new(x, y))
+    }
+
+    fn right(x: f64, y: f64) -> Pointer {
+        Pointer::Press(MouseButton::Right, Vec2::new(x, y))
+    }
+
+    fn drag(x: f64, y: f64) -> Pointer {
+        Pointer::Drag(Vec2::new(x, y))
+    }
+
+    fn square(position: Vec2) -> Asteroid {
+        let vertices = [(-20.0, -20.0), (20.0, -20.0), (20.0, 20.0), (-20.0, 20.0)]
+            .map(|(x, y)| Vec2::new(x, y))
+            .to_vec();
+        Asteroid::new_with_shape(
+            position,
+            Vec2::new(0.0, 0.0),
+            AsteroidSize::Large,
+            0.0,
+            vertices,
+        )
+    }
+
+    // === Requirement: Placing Rocks ===
+
+    // Scenario: Left click places a rock of the selected size and drags it
+    #[test]
+    fn test_place_and_move() {
+        let mut editor = Editor::new();
+        editor.handle_key(KeyCode::Char('2'));
+        editor.pointer(left(100.0, 100.0));
+        editor.pointer(drag(150.0, 120.0));
+        editor.pointer(Pointer::Release);
+        let rocks = &editor.wave().rocks;
+        assert_eq!(rocks.len(), 1);
+        assert_eq!(rocks[0].size, AsteroidSize::Medium);
+        assert_eq!(rocks[0].position, Vec2::new(150.0, 120.0));
+        assert_eq!(rocks[0].rotation, 0.0);
+
+        // Grabbing off-centre keeps the offset
+        editor.pointer(left(155.0, 120.0));
+        editor.pointer(drag(205.0, 220.0));
+        assert_eq!(editor.wave().rocks[0].position, Vec2::new(200.0, 220.0));
+        assert_eq!(editor.wave().rocks.len(), 1);
+
+        // Later drags without a press do nothing
+        editor.pointer(Pointer::Release);
+        editor.pointer(drag(500.0, 500.0));
+        assert_eq!(editor.wave().rocks[0].position, Vec2::new(200.0, 220.0));
+    }
+
+    // Scenario: Rocks wrap and are grabbed across the edges
+    #[test]
+    fn test_toroidal_editing() {
+        let mut editor = Editor::new();
+        editor.pointer(left(10.0, 300.0));
+        editor.pointer(drag(-30.0, 300.0));
+        editor.pointer(Pointer::Release);
+        assert_eq!(editor.wave().rocks[0].position, Vec2::new(770.0, 300.0));
+
+        // A click just past the left edge lands on the rock at the right edge
+        assert_eq!(editor.rock_at(Vec2::new(5.0, 300.0)), Some(0));
+        editor.pointer(right(5.0, 300.0));
+        assert!(editor.wave().rocks.is_empty());
+    }
+
+    // Scenario: Right click deletes the rock under the pointer
+    #[test]
+    fn test_delete() {
+        let mut editor = Editor::new();
+        editor.pointer(left(100.0, 100.0));
+        editor.pointer(Pointer::Release);
+        editor.handle_key(KeyCode::Char('3'));
+        editor.pointer(left(400.0, 300.0));
+        editor.pointer(Pointer::Release);
+        editor.pointer(right(600.0, 100.0));
+        assert_eq!(editor.wave().rocks.len(), 2);
+        editor.pointer(right(100.0, 100.0));
+        assert_eq!(editor.wave().rocks.len(), 1);
+        assert_eq!(editor.wave().rocks[0].size, AsteroidSize::Small);
+    }
+
+    // === Requirement: Velocity and Shape Tools ===
+
+    // Scenario: Dragging from a rock sets its velocity
+    #[test]
+    fn test_velocity_tool() {
+        let mut editor = Editor::new();
+        editor.waves[0].rocks.push(square(Vec2::new(400.0, 300.0)));
+        editor.handle_key(KeyCode::Tab);
+        assert_eq!(editor.tool, Tool::Velocity);
+        editor.pointer(left(400.0, 300.0));
+        editor.pointer(drag(460.0, 220.0));
+        editor.pointer(Pointer::Release);
+        assert_eq!(editor.wave().rocks[0].velocity, Vec2::new(60.0, -80.0));
+        // The velocity line shows where the rock will be in a second
+        let playfield = editor.playfield();
+        assert_eq!(playfield.polygons[1], vec![[400.0, 300.0], [460.0, 220.0]]);
+
+        // Across the wrap, the short way round
+        editor.pointer(left(400.0, 300.0));
+        editor.pointer(Pointer::Release);
+        editor.waves[0].rocks[0].position = Vec2::new(790.0, 300.0);
+        editor.pointer(left(790.0, 300.0));
+        editor.pointer(drag(20.0, 300.0));
+        assert_eq!(editor.wave().rocks[0].velocity, Vec2::new(30.0, 0.0));
+
+        editor.pointer(right(790.0, 300.0));
+        assert_eq!(editor.wave().rocks[0].velocity, Vec2::new(0.0, 0.0));
+        assert_eq!(editor.playfield().polygons.len(), 1);
+    }
+
+    // Scenario: The shape tool drags and removes vertices
+    #[test]
+    fn test_shape_tool() {
+        let mut editor = Editor::new();
+        editor.waves[0].rocks.push(square(Vec2::new(400.0, 300.0)));
+        editor.tool = Tool::Shape;
+        assert_eq!(editor.playfield().dots.len(), 4);
+
+        editor.pointer(left(423.0, 282.0));
+        editor.pointer(drag(450.0, 250.0));
+        editor.pointer(Pointer::Release);
+        assert_eq!(editor.wave().rocks[0].vertices[1], Vec2::new(50.0, -50.0));
+
+        // Clicking away from every vertex grabs nothing
+        editor.pointer(left(400.0, 300.0));
+        editor.pointer(drag(0.0, 0.0));
+        assert_eq!(editor.wave().rocks[0].position, Vec2::new(400.0, 300.0));
+
+        editor.pointer(right(450.0, 250.0));
+        assert_eq!(editor.wave().rocks[0].vertices.len(), 3);
+        editor.pointer(right(380.0, 280.0));
+        assert_eq!(editor.wave().rocks[0].vertices.len(), MIN_VERTICES);
+    }
+
+    // === Requirement: Editor Waves ===
+
+    // Scenario: Keys pick the size, tool, wave, time limit and win condition
+    #[test]
+    fn test_keys() {
+        let mut editor = Editor::new();
+        assert_eq!(editor.handle_key(KeyCode::Char('3')), EditorCommand::None);
+        assert_eq!(editor.size, AsteroidSize::Small);
+        for _ in 0..3 {
+            editor.handle_key(KeyCode::Tab);
+        }
+        assert_eq!(editor.tool, Tool::Rocks);
+
+        editor.handle_key(KeyCode::Char('n'));
+        editor.handle_key(KeyCode::Char('n'));
+        assert_eq!((editor.current, editor.waves.len()), (2, 3));
+        editor.handle_key(KeyCode::Char('['));
+        editor.handle_key(KeyCode::PageUp);
+        editor.handle_key(KeyCode::PageUp);
+        assert_eq!(editor.current, 0);
+        editor.handle_key(KeyCode::Char(']'));
+        assert_eq!(editor.current, 1);
+
+        editor.handle_key(KeyCode::Char('w'));
+        assert_eq!(editor.wave().win, WinCondition::Survive);
+        assert_eq!(editor.wave().time_limit, Some(SURVIVE_TIME));
+        editor.handle_key(KeyCode::Char('t'));
+        editor.handle_key(KeyCode::Char('t'));
+        assert_eq!(editor.wave().time_limit, Some(120.0));
+        editor.handle_key(KeyCode::Char('t'));
+        assert_eq!(editor.wave().time_limit, None);
+        assert_eq!(editor.wave().win, WinCondition::Clear);
+        editor.handle_key(KeyCode::Char('w'));
+        editor.handle_key(KeyCode::Char('w'));
+        assert_eq!(editor.wave().win, WinCondition::Score(SCORE_TARGET));
+        assert_eq!(
+            editor.status(),
+            "Custom level  Wave 2/3  Rocks: 0  Tool: ROCKS  Size: SMALL  Time: 30s  Win: score 1000"
+        );
+
+        assert_eq!(editor.handle_key(KeyCode::Enter), EditorCommand::TestPlay);
+        assert_eq!(editor.handle_key(KeyCode::Char('s')), EditorCommand::Save);
+        assert_eq!(editor.handle_key(KeyCode::Esc), EditorCommand::Exit);
+        assert_eq!(editor.handle_key(KeyCode::Char('q')), EditorCommand::Exit);
+    }
+
+    // === Requirement: Saving and Test Play ===
+
+    // Scenario: The layout becomes a level pack without its empty waves
+    #[test]
+    fn test_level() {
+        let mut editor = Editor::new();
+        assert!(editor.level().is_none());
+        let mut rock = square(Vec2::new(123.456, 300.0));
+        rock.velocity = Vec2::new(10.04, -5.0);
+        rock.angular_velocity = 0.5;
+        editor.waves[0].rocks.push(rock);
+        editor.handle_key(KeyCode::Char('n'));
+        editor.handle_key(KeyCode::Char('n'));
+        editor.waves[2].rocks.push(square(Vec2::new(700.0, 500.0)));
+        editor.handle_key(KeyCode::Char('w'));
+
+        let pack = editor.level().unwrap();
+        assert_eq!(pack.name, NEW_LEVEL_NAME);
+        assert_eq!(pack.waves.len(), 2);
+        let group = &pack.waves[0].rocks[0];
+        assert_eq!(group.placement, Placement::At(Vec2::new(123.5, 300.0)));
+        assert_eq!(group.motion, Motion::Velocity(Vec2::new(10.0, -5.0)));
+        assert_eq!(group.shape.as_ref().unwrap().len(), 4);
+        assert_eq!(group.spin, Some(0.5));
+        assert_eq!(pack.waves[1].win, WinCondition::Survive);
+
+        // Test play starts at the wave on screen
+        let test = editor.test_level().unwrap();
+        assert_eq!(test.waves.len(), 1);
+        assert_eq!(test.waves[0], pack.waves[1]);
+        editor.handle_key(KeyCode::Char('['));
+        assert_eq!(editor.test_level().unwrap().waves.len(), 1);
+
+        // What the editor saves, it opens again as it was
+        let saved = LevelPack::from_toml(&pack.to_toml()).unwrap();
+        let reopened = Editor::from_level(&saved);
+        assert_eq!(reopened.level().unwrap(), saved);
+    }
+
+    // Scenario: Opening a pack fixes randomly placed rocks where they land
+    #[test]
+    fn test_from_level() {
+        let pack = LevelPack::from_toml(include_str!("../examples/trial.toml")).unwrap();
+        let editor = Editor::from_level(&pack);
+        assert_eq!(editor.name, "Trial");
+        assert_eq!(editor.waves.len(), 3);
+        assert_eq!(editor.waves[0].rocks.len(), 3);
+        assert_eq!(editor.waves[1].rocks.len(), 3);
+        assert_eq!(editor.waves[1].time_limit, Some(30.0));
+        assert_eq!(editor.waves[2].win, WinCondition::Score(1000));
+        for rock in editor.waves.iter().flat_map(|wave| &wave.rocks) {
+            assert_eq!(rock.rotation, 0.0);
+        }
+        let level = editor.level().unwrap();
+        assert!(level.waves[0]
+            .rocks
+            .iter()
+            .all(|group| matches!(group.placement, Placement::At(_))));
+    }
+
+    // === Requirement: Mouse Input ===
+
+    // Scenario: Terminal cells map onto the field above the footer
+    #[test]
+    fn test_pointer_from_mouse() {
+        let mouse = |kind, column, row| MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        };
+        // 80x32 terminal: 30 field rows, then the help and status lines
+        let down = mouse(MouseEventKind::Down(MouseButton::Left), 0, 0);
+        assert_eq!(
+            pointer_from_mouse(&down, 80, 32),
+            Some(Pointer::Press(MouseButton::Left, Vec2::new(5.0, 10.0)))
+        );
+        let on_footer = mouse(MouseEventKind::Down(MouseButton::Right), 40, 30);
+        assert_eq!(pointer_from_mouse(&on_footer, 80, 32), None);
+        let dragged = mouse(MouseEventKind::Drag(MouseButton::Left), 79, 31);
+        assert_eq!(
+            pointer_from_mouse(&dragged, 80, 32),
+            Some(Pointer::Drag(Vec2::new(795.0, 590.0)))
+        );
+        let up = mouse(MouseEventKind::Up(MouseButton::Left), 3, 3);
+        assert_eq!(pointer_from_mouse(&up, 80, 32), Some(Pointer::Release));
+        let moved = mouse(MouseEventKind::Moved, 3, 3);
+        assert_eq!(pointer_from_mouse(&moved, 80, 32), None);
+        assert_eq!(pointer_from_mouse(&down, 80, 2), None);
+    }
+}
@@ -0,0 +1,287 @@
+// Entity capability: shared update and render traits for world entities, and the
+// systems that move, draw and collide them in a fixed order
+
+use crate::collision::{self, Collider};
+use crate::physics::Vec2;
+use crate::renderer::Playfield;
+
+/// An entity that moves and ages on its own.
+pub trait Update {
+    /// Advance `dt` seconds on a wrapping world.
+    fn update(&mut self, dt: f64, world_width: f64, world_height: f64);
+
+    /// Gone from the world, to be dropped after the update.
+    fn expired(&self) -> bool {
+        false
+    }
+}
+
+/// An entity that draws itself onto the playfield.
+pub trait Render {
+    /// Draw in world coordinates. `blink` is the shared on/off phase of flashing
+    /// entities.
+    fn render(&self, playfield: &mut Playfield, blink: bool);
+}
+
+/// An entity with momentum, which bounces off what it touches.
+pub trait Body: Collider {
+    fn velocity(&self) -> Vec2;
+    fn set_velocity(&mut self, velocity: Vec2);
+    fn mass(&self) -> f64;
+}
+
+/// Update every live entity in order, then drop the ones that expired.
+pub fn update_all<T: Update>(entities: &mut Vec<T>, dt: f64, world_width: f64, world_height: f64) {
+    for entity in entities.iter_mut().filter(|entity| !entity.expired()) {
+        entity.update(dt, world_width, world_height);
+    }
+    entities.retain(|entity| !entity.expired());
+}
+
+/// Draw every entity in order.
+pub fn render_all<T: Render>(entities: &[T], playfield: &mut Playfield, blink: bool) {
+    for entity in entities {
+        entity.render(playfield, blink);
+    }
+}
+
+/// Remove the entities touching `collider` and return them in order.
+pub fn take_touching<C, T>(
+    collider: &C,
+    entities: &mut Vec<T>,
+    world_width: f64,
+    world_height: f64,
+) -> Vec<T>
+where
+    C: Collider + ?Sized,
+    T: Collider,
+{
+    let (touching, rest) = std::mem::take(entities)
+        .into_iter()
+        .partition(|entity| collision::collide(collider, entity, world_width, world_height));
+    *entities = rest;
+    touching
+}
+
+/// Index of the first entity touching `collider`.
+pub fn first_touching<C, T>(
+    collider: &C,
+    entities: &[T],
+    world_width: f64,
+    world_height: f64,
+) -> Option<usize>
+where
+    C: Collider + ?Sized,
+    T: Collider,
+{
+    entities
+        .iter()
+        .position(|entity| collision::collide(collider, entity, world_width, world_height))
+}
+
+/// Pair each hitter that `can_hit`, in order, with the first entity it touches that no
+/// earlier hitter took, marking that entity in `taken`. Returns (hitter, entity) index
+/// pairs.
+pub fn first_contacts<H, T>(
+    hitters: &[H],
+    can_hit: impl Fn(&H) -> bool,
+    entities: &[T],
+    taken: &mut [bool],
+    world_width: f64,
+    world_height: f64,
+) -> Vec<(usize, usize)>
+where
+    H: Collider,
+    T: Collider,
+{
+    let mut contacts = Vec::new();
+    for (h, hitter) in hitters.iter().enumerate() {
+        if !can_hit(hitter) {
+            continue;
+        }
+        let hit = entities.iter().enumerate().position(|(e, entity)| {
+            !taken[e] && collision::collide(hitter, entity, world_width, world_height)
+        });
+        if let Some(e) = hit {
+            taken[e] = true;
+            contacts.push((h, e));
+        }
+    }
+    contacts
+}
+
+/// Bounce a body shaped like `collider`, with `velocity` and `mass`, off every entity it
+/// touches, in order. Returns how many it bounced off.
+pub fn bounce_all<C, T>(
+    collider: &C,
+    velocity: &mut Vec2,
+    mass: f64,
+    entities: &mut [T],
+    world_width: f64,
+    world_height: f64,
+) -> usize
+where
+    C: Collider + ?Sized,
+    T: Body,
+{
+    let mut bounces = 0;
+    for entity in entities {
+        let bounced = collision::bounce_on_contact(
+            collider,
+            *velocity,
+            mass,
+            entity,
+            entity.velocity(),
+            entity.mass(),
+            world_width,
+            world_height,
+        );
+        if let Some((own, other)) = bounced {
+            *velocity = own;
+            entity.set_velocity(other);
+            bounces += 1;
+        }
+    }
+    bounces
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asteroids::{Asteroid, AsteroidSize};
+    use crate::bullets::Bullet;
+    use crate::pickups::{Pickup, PowerUp, PICKUP_LIFETIME};
+    use crate::scoring::{Popup, POPUP_LIFETIME};
+    use crate::ship::Ship;
+
+    // === Requirement: Entity Systems ===
+
+    // Scenario: Entities update in order and expired ones are dropped
+    #[test]
+    fn test_update_all_drops_expired() {
+        let mut popups = vec![
+            Popup::new(Vec2::new(100.0, 100.0), 20, 1),
+            Popup::new(Vec2::new(200.0, 100.0), 50, 1),
+        ];
+        popups[0].age = POPUP_LIFETIME - 0.1;
+        update_all(&mut popups, 0.2, 800.0, 600.0);
+        assert_eq!(popups.len(), 1);
+        assert_eq!(popups[0].points, 50);
+        assert!(popups[0].position.y < 100.0, "rises");
+
+        let mut bullets = vec![Bullet::new(Vec2::new(100.0, 100.0), 0.0)];
+        bullets[0].alive = false;
+        update_all(&mut bullets, 0.1, 800.0, 600.0);
+        assert!(bullets.is_empty());
+    }
+
+    // Scenario: Entities draw themselves onto the playfield
+    #[test]
+    fn test_render_all_draws_entities() {
+        let mut playfield = Playfield::new(800.0, 600.0);
+        let mut bullets = vec![
+            Bullet::new(Vec2::new(100.0, 100.0), 0.0),
+            Bullet::new(Vec2::new(200.0, 100.0), 0.0),
+        ];
+        bullets[1].alive = false;
+        render_all(&bullets, &mut playfield, true);
+        render_all(
+            &[Popup::new(Vec2::new(5.0, 6.0), 20, 2)],
+            &mut playfield,
+            true,
+        );
+        assert_eq!(playfield.dots, vec![[100.0, 100.0]]);
+        assert_eq!(playfield.labels, vec![([5.0, 6.0], "20 x2".to_string())]);
+
+        let mut pickup = Pickup::new(
+            PowerUp::Shield,
+            Vec2::new(300.0, 300.0),
+            Vec2::new(0.0, 0.0),
+        );
+        pickup.age = PICKUP_LIFETIME - 0.1;
+        render_all(&[pickup], &mut playfield, false);
+        assert!(playfield.polygons.is_empty(), "expiring pickup blinks off");
+    }
+
+    // Scenario: Touching entities are taken in order
+    #[test]
+    fn test_take_touching() {
+        let ship = Ship::new(400.0, 300.0);
+        let still = Vec2::new(0.0, 0.0);
+        let mut pickups = vec![
+            Pickup::new(PowerUp::Shield, Vec2::new(405.0, 300.0), still),
+            Pickup::new(PowerUp::Spread, Vec2::new(100.0, 100.0), still),
+            Pickup::new(PowerUp::RapidFire, Vec2::new(400.0, 310.0), still),
+        ];
+        let taken = take_touching(&ship, &mut pickups, 800.0, 600.0);
+        let kinds: Vec<PowerUp> = taken.iter().map(|pickup| pickup.kind).collect();
+        assert_eq!(kinds, vec![PowerUp::Shield, PowerUp::RapidFire]);
+        assert_eq!(pickups.len(), 1);
+        assert_eq!(pickups[0].kind, PowerUp::Spread);
+    }
+
+    fn rock(x: f64, y: f64, size: AsteroidSize) -> Asteroid {
+        Asteroid::new_with_shape(
+            Vec2::new(x, y),
+            Vec2::new(0.0, 0.0),
+            size,
+            0.0,
+            vec![
+                Vec2::new(1.0, 0.0),
+                Vec2::new(0.0, 1.0),
+                Vec2::new(-1.0, 0.0),
+            ],
+        )
+    }
+
+    // Scenario: The first touching entity is found
+    #[test]
+    fn test_first_touching() {
+        let ship = Ship::new(400.0, 300.0);
+        let rocks = [
+            rock(100.0, 100.0, AsteroidSize::Small),
+            rock(420.0, 300.0, AsteroidSize::Small),
+            rock(400.0, 320.0, AsteroidSize::Small),
+        ];
+        assert_eq!(first_touching(&ship, &rocks, 800.0, 600.0), Some(1));
+        assert_eq!(first_touching(&ship, &rocks[..1], 800.0, 600.0), None);
+    }
+
+    // Scenario: Each hitter takes the first entity no earlier hitter took
+    #[test]
+    fn test_first_contacts() {
+        let mut bullets = vec![
+            Bullet::new(Vec2::new(100.0, 100.0), 0.0),
+            Bullet::new(Vec2::new(102.0, 100.0), 0.0),
+            Bullet::new(Vec2::new(104.0, 100.0), 0.0),
+            Bullet::new(Vec2::new(500.0, 500.0), 0.0),
+        ];
+        bullets[2].alive = false;
+        let rocks = [
+            rock(100.0, 100.0, AsteroidSize::Small),
+            rock(105.0, 100.0, AsteroidSize::Small),
+        ];
+        let mut taken = [false; 2];
+        let contacts = first_contacts(&bullets, Bullet::can_hit, &rocks, &mut taken, 800.0, 600.0);
+        assert_eq!(contacts, vec![(0, 0), (1, 1)]);
+        assert_eq!(taken, [true, true]);
+        let again = first_contacts(&bullets, Bullet::can_hit, &rocks, &mut taken, 800.0, 600.0);
+        assert!(again.is_empty(), "taken entities are not hit twice");
+    }
+
+    // Scenario: A body bounces off every entity it touches
+    #[test]
+    fn test_bounce_all() {
+        let ship = Ship::new(400.0, 300.0);
+        let mut rocks = vec![
+            rock(400.0, 320.0, AsteroidSize::Medium),
+            rock(100.0, 100.0, AsteroidSize::Small),
+        ];
+        let mut velocity = Vec2::new(0.0, 50.0);
+        let bounces = bounce_all(&ship, &mut velocity, 1.0, &mut rocks, 800.0, 600.0);
+        assert_eq!(bounces, 1);
+        assert!(velocity.y < 0.0, "rebounds");
+        assert!(rocks[0].velocity.y > 0.0, "pushed away");
+        assert_eq!(rocks[1].velocity, Vec2::new(0.0, 0.0));
+    }
+}
@@ -0,0 +1,553 @@
+// Env capability: gym-style reset/step environment for reinforcement learning
+//
+// Wraps a PlayingState behind reset(seed) and step(action). Actions are indices into a
+// fixed discrete space of InputState combinations. Rewards are the points scored during
+// the step, less an optional penalty per life lost.
+
+use crate::bot::Observation;
+use crate::collision;
+use crate::game::{GameState, PlayingState, TIMESTEP, WORLD_HEIGHT, WORLD_WIDTH};
+use crate::input::InputState;
+use crate::physics::Vec2;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+/// Rotation choices in the discrete action space: none, left, right.
+const ROTATIONS: usize = 3;
+
+/// Number of discrete actions: {none, left, right} x {coast, thrust} x {hold, fire}.
+pub const ACTION_COUNT: usize = ROTATIONS * 2 * 2;
+
+/// Decode a discrete action index into an InputState. Returns None if out of range.
+///
+/// Index layout: `rotation + 3 * thrust + 6 * fire`, where rotation is 0 (none),
+/// 1 (left) or 2 (right). Action 0 is a no-op. Shield and quit are never part of the action
+/// space.
+pub fn action_input(action: usize) -> Option<InputState> {
+    if action >= ACTION_COUNT {
+        return None;
+    }
+    let rotation = action % ROTATIONS;
+    Some(InputState {
+        rotate_left: rotation == 1,
+        rotate_right: rotation == 2,
+        thrust: (action / ROTATIONS) % 2 == 1,
+        fire: action / (ROTATIONS * 2) == 1,
+        shield: false,
+        quit: false,
+    })
+}
+
+/// Ray-cast sensor around the ship.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RaySensor {
+    /// Number of rays, evenly spaced starting at the ship's heading.
+    pub count: usize,
+    /// Maximum distance a ray can see, in world units.
+    pub range: f64,
+}
+
+impl Default for RaySensor {
+    fn default() -> Self {
+        Self {
+            count: 16,
+            range: 300.0,
+        }
+    }
+}
+
+impl RaySensor {
+    /// Cast every ray from the ship. Each reading is the distance to the nearest
+    /// asteroid edge divided by the range: 0.0 means touching, 1.0 means nothing seen.
+    /// Asteroids are tested at their nearest toroidal image.
+    pub fn cast(&self, state: &PlayingState, world_width: f64, world_height: f64) -> Vec<f64> {
+        let origin = state.ship.position;
+        let offsets: Vec<(Vec2, f64)> = state
+            .asteroids
+            .iter()
+            .map(|a| {
+                (
+                    collision::toroidal_direction(origin, a.position, world_width, world_height),
+                    a.size.radius(),
+                )
+            })
+            .collect();
+
+        (0..self.count)
+            .map(|i| {
+                let angle = state.ship.rotation + 2.0 * PI * i as f64 / self.count as f64;
+                let dir = Vec2::from_angle(angle);
+                let nearest = offsets
+                    .iter()
+                    .filter_map(|&(offset, radius)| ray_circle(dir, offset, radius))
+                    .fold(self.range, f64::min);
+                nearest.min(self.range) / self.range
+            })
+            .collect()
+    }
+}
+
+/// Distance along a unit ray from the origin to a circle, if the ray hits it ahead.
+fn ray_circle(dir: Vec2, center: Vec2, radius: f64) -> Option<f64> {
+    let along = center.dot(dir);
+    let closest_sq = center.dot(center) - along * along;
+    let radius_sq = radius * radius;
+    if closest_sq > radius_sq {
+        return None;
+    }
+    let half_chord = (radius_sq - closest_sq).sqrt();
+    if along + half_chord < 0.0 {
+        return None;
+    }
+    Some((along - half_chord).max(0.0))
+}
+
+/// Environment settings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnvConfig {
+    pub world_width: f64,
+    pub world_height: f64,
+    /// Simulation ticks per step; rotation and thrust are held for every tick.
+    pub frame_skip: u32,
+    /// Optional ray-cast sensor added to each observation.
+    pub rays: Option<RaySensor>,
+    /// Subtracted from the reward for each life lost.
+    pub death_penalty: f64,
+    /// Steps after which an episode is truncated.
+    pub max_steps: Option<u64>,
+}
+
+impl Default for EnvConfig {
+    fn default() -> Self {
+        Self {
+            world_width: WORLD_WIDTH,
+            world_height: WORLD_HEIGHT,
+            frame_skip: 4,
+            rays: None,
+            death_penalty: 0.0,
+            max_steps: None,
+        }
+    }
+}
+
+/// What an agent sees after reset or step.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnvObservation {
+    /// Full game snapshot, as sent to protocol bots.
+    pub game: Observation,
+    /// Normalized ray distances, if the sensor is enabled.
+    pub rays: Option<Vec<f64>>,
+}
+
+/// Diagnostics returned with each step.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StepInfo {
+    pub score: u32,
+    pub wave: u32,
+    pub lives: u32,
+    /// Simulation ticks advanced by this step.
+    pub frames: u32,
+    pub game_over: bool,
+    /// The episode hit max_steps without a game over.
+    pub truncated: bool,
+}
+
+/// A reset/step environment over a seeded PlayingState.
+pub struct Env {
+    config: EnvConfig,
+    state: PlayingState,
+    steps: u64,
+    done: bool,
+}
+
+impl Env {
+    /// Create an environment already reset with seed 0.
+    pub fn new(config: EnvConfig) -> Self {
+        let state = PlayingState::new_seeded(config.world_width, config.world_height, 0);
+        Self {
+            config,
+            state,
+            steps: 0,
+            done: false,
+        }
+    }
+
+    pub fn config(&self) -> &EnvConfig {
+        &self.config
+    }
+
+    /// The underlying game, for rendering or inspection.
+    pub fn state(&self) -> &PlayingState {
+        &self.state
+    }
+
+    /// Steps taken in the current episode.
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// Start a new episode. The same seed always produces the same episode for the same actions.
+    pub fn reset(&mut self, seed: u64) -> EnvObservation {
+        self.state =
+            PlayingState::new_seeded(self.config.world_width, self.config.world_height, seed);
+        self.steps = 0;
+        self.done = false;
+        self.observe()
+    }
+
+    /// Apply a discrete action for `frame_skip` ticks.
+    /// Fire only triggers on the first tick, like a single key press.
+    /// Once an episode is done, further steps return zero reward without advancing.
+    ///
+    /// # Panics
+    /// Panics if `action >= ACTION_COUNT`.
+    pub fn step(&mut self, action: usize) -> (EnvObservation, f64, bool, StepInfo) {
+        let input = action_input(action)
+            .unwrap_or_else(|| panic!("action {} out of range 0..{}", action, ACTION_COUNT));
+        if self.done {
+            let truncated = self.state.ship.lives > 0;
+            return (self.observe(), 0.0, true, self.info(0, truncated));
+        }
+
+        let (w, h) = (self.config.world_width, self.config.world_height);
+        let start_score = self.state.score;
+        let mut lives_lost = 0u32;
+        let mut frames = 0;
+        let mut game_over = false;
+        let held = InputState {
+            fire: false,
+            ..input.clone()
+        };
+
+        for frame in 0..self.config.frame_skip.max(1) {
+            let tick_input = if frame == 0 { &input } else { &held };
+            let lives_before = self.state.ship.lives;
+            let result = self.state.update(tick_input, TIMESTEP, w, h);
+            frames += 1;
+            lives_lost += lives_before.saturating_sub(self.state.ship.lives);
+            if result.state == Some(GameState::GameOver) {
+                game_over = true;
+                break;
+            }
+        }
+
+        self.steps += 1;
+        let truncated = !game_over && self.config.max_steps.is_some_and(|m| self.steps >= m);
+        self.done = game_over || truncated;
+        let reward =
+            (self.state.score - start_score) as f64 - self.config.death_penalty * lives_lost as f64;
+        let info = StepInfo {
+            game_over,
+            ..self.info(frames, truncated)
+        };
+        (self.observe(), reward, self.done, info)
+    }
+
+    fn observe(&self) -> EnvObservation {
+        let (w, h) = (self.config.world_width, self.config.world_height);
+        EnvObservation {
+            game: Observation::capture(&self.state, w, h, self.steps, self.done),
+            rays: self.config.rays.map(|r| r.cast(&self.state, w, h)),
+        }
+    }
+
+    fn info(&self, frames: u32, truncated: bool) -> StepInfo {
+        StepInfo {
+            score: self.state.score,
+            wave: self.state.wave,
+            lives: self.state.ship.lives,
+            frames,
+            game_over: self.state.ship.lives == 0,
+            truncated,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asteroids::{Asteroid, AsteroidSize};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    const NOOP: usize = 0;
+    const FIRE: usize = 6;
+
+    fn asteroid_at(x: f64, y: f64, size: AsteroidSize) -> Asteroid {
+        let mut rng = StdRng::seed_from_u64(1);
+        Asteroid::new(Vec2::new(x, y), Vec2::new(0.0, 0.0), size, &mut rng)
+    }
+
+    fn env_with(config: EnvConfig) -> Env {
+        let mut env = Env::new(config);
+        env.reset(42);
+        env
+    }
+
+    // === Requirement: Discrete Action Space ===
+
+    // Scenario: Every action index decodes to a distinct input without Quit
+    #[test]
+    fn test_action_space_distinct() {
+        let inputs: Vec<InputState> = (0..ACTION_COUNT)
+            .map(|a| action_input(a).unwrap())
+            .collect();
+        assert_eq!(ACTION_COUNT, 12);
+        for (i, a) in inputs.iter().enumerate() {
+            assert!(!a.quit);
+            assert!(!(a.rotate_left && a.rotate_right));
+            for b in &inputs[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+        assert_eq!(inputs[NOOP], InputState::default());
+        assert_eq!(
+            inputs[FIRE],
+            InputState {
+                fire: true,
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            action_input(11),
+            Some(InputState {
+                rotate_right: true,
+                thrust: true,
+                fire: true,
+                ..Default::default()
+            })
+        );
+    }
+
+    // Scenario: Out-of-range action is rejected
+    #[test]
+    fn test_action_out_of_range() {
+        assert_eq!(action_input(ACTION_COUNT), None);
+    }
+
+    // Scenario: Stepping with an out-of-range action panics
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn test_step_out_of_range_panics() {
+        env_with(EnvConfig::default()).step(ACTION_COUNT);
+    }
+
+    // === Requirement: Reset ===
+
+    // Scenario: Reset with the same seed reproduces the episode
+    #[test]
+    fn test_reset_deterministic() {
+        let mut env = Env::new(EnvConfig::default());
+        let run = |env: &mut Env| {
+            let first = env.reset(7);
+            let mut trace = vec![first];
+            for a in [1, 3, 6, 8, 0, 11] {
+                trace.push(env.step(a).0);
+            }
+            trace
+        };
+        let a = run(&mut env);
+        let b = run(&mut env);
+        assert_eq!(a, b);
+        let other = env.reset(8);
+        assert_ne!(other.game.asteroids, a[0].game.asteroids);
+    }
+
+    // Scenario: Reset starts a fresh episode
+    #[test]
+    fn test_reset_fresh_episode() {
+        let mut env = env_with(EnvConfig::default());
+        env.step(3);
+        let obs = env.reset(42);
+        assert_eq!(env.steps(), 0);
+        assert_eq!(obs.game.tick, 0);
+        assert_eq!(obs.game.score, 0);
+        assert_eq!(obs.game.ship.lives, 3);
+        assert!(!obs.game.done);
+        assert_eq!(obs.rays, None);
+    }
+
+    // === Requirement: Step ===
+
+    // Scenario: Frame skip advances several ticks per step
+    #[test]
+    fn test_frame_skip() {
+        let mut env = env_with(EnvConfig {
+            frame_skip: 3,
+            ..Default::default()
+        });
+        let (obs, _, done, info) = env.step(NOOP);
+        assert_eq!(info.frames, 3);
+        assert_eq!(env.state().frame_count, 3);
+        assert_eq!(obs.game.tick, 1);
+        assert!(!done);
+    }
+
+    // Scenario: Zero frame skip is treated as one tick
+    #[test]
+    fn test_frame_skip_zero() {
+        let mut env = env_with(EnvConfig {
+            frame_skip: 0,
+            ..Default::default()
+        });
+        assert_eq!(env.step(NOOP).3.frames, 1);
+    }
+
+    // Scenario: Fire triggers once per step
+    #[test]
+    fn test_fire_once_per_step() {
+        let mut env = env_with(EnvConfig {
+            frame_skip: 4,
+            ..Default::default()
+        });
+        env.step(FIRE);
+        assert_eq!(env.state().bullet_pool.active_count(), 1);
+    }
+
+    // Scenario: Reward is the score gained during the step
+    #[test]
+    fn test_reward_is_score_delta() {
+        let mut env = env_with(EnvConfig {
+            frame_skip: 1,
+            ..Default::default()
+        });
+        let ship = env.state.ship.position;
+        let heading = Vec2::from_angle(env.state.ship.rotation);
+        let target = ship + heading.scale(40.0);
+        env.state.asteroids = vec![
+            asteroid_at(target.x, target.y, AsteroidSize::Small),
+            asteroid_at(50.0, 50.0, AsteroidSize::Large),
+        ];
+        env.state.score = 500;
+        let mut total = 0.0;
+        for _ in 0..10 {
+            let (_, reward, _, _) = env.step(if total == 0.0 { FIRE } else { NOOP });
+            total += reward;
+        }
+        assert_eq!(total, 100.0);
+        assert_eq!(env.state().score, 600);
+    }
+
+    // Scenario: Losing a life applies the death penalty
+    #[test]
+    fn test_death_penalty() {
+        let mut env = env_with(EnvConfig {
+            frame_skip: 1,
+            death_penalty: 50.0,
+            ..Default::default()
+        });
+        let ship = env.state.ship.position;
+        env.state.ship.invulnerable = false;
+        env.state.asteroids = vec![asteroid_at(ship.x, ship.y, AsteroidSize::Large)];
+        let (_, reward, done, info) = env.step(NOOP);
+        assert_eq!(reward, -50.0);
+        assert_eq!(info.lives, 2);
+        assert!(!done);
+    }
+
+    // Scenario: Game over ends the episode
+    #[test]
+    fn test_game_over_done() {
+        let mut env = env_with(EnvConfig::default());
+        let ship = env.state.ship.position;
+        env.state.ship.lives = 1;
+        env.state.ship.invulnerable = false;
+        env.state.asteroids = vec![asteroid_at(ship.x, ship.y, AsteroidSize::Large)];
+        let (obs, _, done, info) = env.step(NOOP);
+        assert!(done);
+        assert!(obs.game.done);
+        assert!(info.game_over);
+        assert!(!info.truncated);
+        assert_eq!(info.frames, 1);
+
+        // Further steps do not advance
+        let frames = env.state().frame_count;
+        let (_, reward, done, info) = env.step(3);
+        assert!(done);
+        assert_eq!(reward, 0.0);
+        assert_eq!(info.frames, 0);
+        assert!(info.game_over);
+        assert_eq!(env.state().frame_count, frames);
+    }
+
+    // Scenario: Episodes are truncated at max_steps
+    #[test]
+    fn test_truncation() {
+        let mut env = env_with(EnvConfig {
+            max_steps: Some(2),
+            ..Default::default()
+        });
+        assert!(!env.step(NOOP).2);
+        let (_, _, done, info) = env.step(NOOP);
+        assert!(done);
+        assert!(info.truncated);
+        assert!(!info.game_over);
+        let (_, _, done, info) = env.step(NOOP);
+        assert!(done);
+        assert!(info.truncated);
+    }
+
+    // === Requirement: Ray-Cast Sensor ===
+
+    // Scenario: Ray sees an asteroid straight ahead
+    #[test]
+    fn test_ray_hits_asteroid_ahead() {
+        let mut env = env_with(EnvConfig {
+            rays: Some(RaySensor {
+                count: 4,
+                range: 200.0,
+            }),
+            ..Default::default()
+        });
+        let ship = env.state.ship.position;
+        env.state.ship.rotation = 0.0;
+        // Large radius 40, centre 140 ahead: edge at 100
+        env.state.asteroids = vec![asteroid_at(ship.x + 140.0, ship.y, AsteroidSize::Large)];
+        let rays = env.observe().rays.unwrap();
+        assert_eq!(rays.len(), 4);
+        assert!((rays[0] - 0.5).abs() < 1e-9);
+        assert_eq!(&rays[1..], &[1.0, 1.0, 1.0]);
+    }
+
+    // Scenario: Rays follow the ship's heading
+    #[test]
+    fn test_rays_relative_to_heading() {
+        let mut env = env_with(EnvConfig::default());
+        let ship = env.state.ship.position;
+        env.state.ship.rotation = PI / 2.0;
+        env.state.asteroids = vec![asteroid_at(ship.x + 100.0, ship.y, AsteroidSize::Small)];
+        let sensor = RaySensor {
+            count: 4,
+            range: 200.0,
+        };
+        let rays = sensor.cast(env.state(), 800.0, 600.0);
+        // Heading points down (+y); the asteroid to the right is the fourth ray (270 degrees on)
+        assert_eq!(rays[0], 1.0);
+        assert!(rays[3] < 1.0);
+    }
+
+    // Scenario: Rays see across the world edge
+    #[test]
+    fn test_ray_toroidal() {
+        let mut env = env_with(EnvConfig::default());
+        env.state.ship.position = Vec2::new(790.0, 300.0);
+        env.state.ship.rotation = 0.0;
+        env.state.asteroids = vec![asteroid_at(50.0, 300.0, AsteroidSize::Small)];
+        let sensor = RaySensor {
+            count: 1,
+            range: 100.0,
+        };
+        let rays = sensor.cast(env.state(), 800.0, 600.0);
+        // 60 units away across the edge, radius 10
+        assert!((rays[0] - 0.5).abs() < 1e-9);
+    }
+
+    // Scenario: Ray origin inside an asteroid reads zero, asteroids behind are ignored
+    #[test]
+    fn test_ray_circle_edge_cases() {
+        let dir = Vec2::new(1.0, 0.0);
+        assert_eq!(ray_circle(dir, Vec2::new(5.0, 0.0), 10.0), Some(0.0));
+        assert_eq!(ray_circle(dir, Vec2::new(-50.0, 0.0), 10.0), None);
+        assert_eq!(ray_circle(dir, Vec2::new(50.0, 20.0), 10.0), None);
+    }
+}
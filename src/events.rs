@@ -0,0 +1,214 @@
+// Events capability: what happened during an update, for audio, stats and achievements
+
+use crate::asteroids::AsteroidSize;
+use crate::audio::AudioEvent;
+use crate::game::GameState;
+use crate::physics::Vec2;
+use crate::pickups::PowerUp;
+
+/// What destroyed an asteroid, and which player gets the credit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Destroyer {
+    Bullet { player: usize },
+    SmartBomb { player: usize },
+}
+
+impl Destroyer {
+    pub fn player(self) -> usize {
+        match self {
+            Destroyer::Bullet { player } | Destroyer::SmartBomb { player } => player,
+        }
+    }
+}
+
+/// Something that happened during one update, in the order it happened.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GameEvent {
+    /// A ship in play flew for `dt` seconds at `speed`, maybe thrusting.
+    ShipFlew {
+        player: usize,
+        dt: f64,
+        speed: f64,
+        thrusting: bool,
+    },
+    BulletFired {
+        player: usize,
+        bullets: u32,
+    },
+    AsteroidDestroyed {
+        size: AsteroidSize,
+        position: Vec2,
+        by: Destroyer,
+    },
+    /// A player lost a ship, to a rock or to the other player's bullet.
+    ShipDestroyed {
+        player: usize,
+        by: Option<usize>,
+    },
+    ShieldBounce {
+        player: usize,
+    },
+    PowerUpCollected {
+        player: usize,
+        kind: PowerUp,
+    },
+    /// Points added to a player's score, kills and wave bonuses alike.
+    PointsAwarded {
+        player: usize,
+        points: u32,
+    },
+    ExtraLife {
+        player: usize,
+    },
+    /// The wave's rocks are gone with the player still in.
+    WaveCleared {
+        player: usize,
+    },
+    WaveStarted {
+        wave: u32,
+    },
+}
+
+impl GameEvent {
+    /// The player the event happened to or is credited to, or None if it happened to
+    /// the whole game.
+    pub fn player(&self) -> Option<usize> {
+        match *self {
+            GameEvent::ShipFlew { player, .. }
+            | GameEvent::BulletFired { player, .. }
+            | GameEvent::ShipDestroyed { player, .. }
+            | GameEvent::ShieldBounce { player }
+            | GameEvent::PowerUpCollected { player, .. }
+            | GameEvent::PointsAwarded { player, .. }
+            | GameEvent::ExtraLife { player }
+            | GameEvent::WaveCleared { player } => Some(player),
+            GameEvent::AsteroidDestroyed { by, .. } => Some(by.player()),
+            GameEvent::WaveStarted { .. } => None,
+        }
+    }
+}
+
+/// Result of a PlayingState::update() call: a state transition, if any, and every
+/// event of the update.
+pub struct UpdateResult {
+    pub state: Option<GameState>,
+    pub events: Vec<GameEvent>,
+}
+
+impl UpdateResult {
+    /// An update where nothing happened.
+    pub fn none() -> Self {
+        Self {
+            state: None,
+            events: Vec::new(),
+        }
+    }
+
+    /// The sounds the update's events make.
+    pub fn audio_events(&self) -> Vec<AudioEvent> {
+        self.events
+            .iter()
+            .filter_map(AudioEvent::from_event)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // === Requirement: Game Events ===
+
+    // Scenario: Events name the player they happened to
+    #[test]
+    fn test_event_player() {
+        let kill = GameEvent::AsteroidDestroyed {
+            size: AsteroidSize::Small,
+            position: Vec2::new(0.0, 0.0),
+            by: Destroyer::SmartBomb { player: 1 },
+        };
+        assert_eq!(kill.player(), Some(1));
+        let shot = GameEvent::ShipDestroyed {
+            player: 0,
+            by: Some(1),
+        };
+        assert_eq!(shot.player(), Some(0));
+        assert_eq!(GameEvent::WaveStarted { wave: 3 }.player(), None);
+    }
+
+    // === Requirement: Update Result ===
+
+    // Scenario: Update result with no state change
+    #[test]
+    fn test_update_result_with_no_state_change() {
+        let result = UpdateResult {
+            state: None,
+            events: vec![GameEvent::BulletFired {
+                player: 0,
+                bullets: 1,
+            }],
+        };
+        assert!(result.state.is_none());
+        assert_eq!(result.audio_events(), vec![AudioEvent::Fire]);
+    }
+
+    // Scenario: Update result with a state change
+    #[test]
+    fn test_update_result_with_state_change() {
+        let result = UpdateResult {
+            state: Some(GameState::GameOver),
+            events: vec![GameEvent::ShipDestroyed {
+                player: 0,
+                by: None,
+            }],
+        };
+        assert_eq!(result.state, Some(GameState::GameOver));
+        assert_eq!(result.audio_events()[0], AudioEvent::ShipDestroyed);
+    }
+
+    // Scenario: Update result with no events
+    #[test]
+    fn test_update_result_with_empty_events() {
+        let result = UpdateResult::none();
+        assert!(result.state.is_none());
+        assert!(result.events.is_empty());
+        assert!(result.audio_events().is_empty());
+    }
+
+    // Scenario: Silent events make no sound
+    #[test]
+    fn test_update_result_with_multiple_events() {
+        let result = UpdateResult {
+            state: None,
+            events: vec![
+                GameEvent::BulletFired {
+                    player: 0,
+                    bullets: 3,
+                },
+                GameEvent::AsteroidDestroyed {
+                    size: AsteroidSize::Large,
+                    position: Vec2::new(0.0, 0.0),
+                    by: Destroyer::Bullet { player: 0 },
+                },
+                GameEvent::PointsAwarded {
+                    player: 0,
+                    points: 20,
+                },
+                GameEvent::ShipFlew {
+                    player: 0,
+                    dt: 0.5,
+                    speed: 10.0,
+                    thrusting: true,
+                },
+            ],
+        };
+        assert_eq!(
+            result.audio_events(),
+            vec![
+                AudioEvent::Fire,
+                AudioEvent::AsteroidExplosionLarge,
+                AudioEvent::Thrust,
+            ]
+        );
+    }
+}
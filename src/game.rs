@@ -1,38 +1,59 @@
 // Game loop capability: fixed timestep, state machine, update sequence
 
+use crate::achievements::{self, Achievement, Achievements};
 use crate::asteroids::{self, Asteroid, AsteroidSize};
-use crate::audio::{AudioEvent, UpdateResult};
-use crate::bullets::{self, BulletPool};
+use crate::audio::AudioEvent;
+use crate::bullets::{self, Bullet, BulletPool};
 use crate::collision;
-use crate::demo_ai;
-use crate::input::{self, Action, FireEdgeDetector, InputState};
-use crate::physics;
-use crate::renderer::{self, BrailleBuffer};
-use crate::ship::Ship;
+use crate::controller::{Controller, DemoAiController, KeyboardController, MouseController};
+use crate::difficulty::{self, Difficulty, WaveSettings};
+use crate::editor::{self, Editor, EditorCommand, Pointer};
+use crate::entity::{self, Render, Update};
+use crate::events::{Destroyer, GameEvent, UpdateResult};
+use crate::highscore::SharedHighScores;
+use crate::input::{self, Action, ControlScheme, InputState, MouseAim, PlayerKeys};
+use crate::level::{LevelPack, WinCondition};
+use crate::mode::GameMode;
+use crate::netplay::Lockstep;
+use crate::physics::{self, Vec2};
+use crate::pickups::{self, Pickup, PowerUp};
+use crate::renderer::{self, Playfield, Scene};
+use crate::rewind::{Rewind, REWIND_SPEED};
+use crate::save::{self, SaveGame};
+use crate::scoring::{self, Popup, Scoring, WaveTally};
+use crate::ship::{ExtraLives, Ship, INVULNERABILITY_DURATION};
+use crate::spectate::Broadcaster;
+use crate::stats::{self, LifetimeStats, SessionStats};
+use crate::turns::Turns;
 
 use crossterm::{
     cursor,
-    event::{self, Event, KeyCode, KeyEventKind},
+    event::{self, Event, KeyCode, KeyEventKind, MouseEvent},
     execute,
     terminal::{self},
 };
-use rand::rngs::StdRng;
 use rand::SeedableRng;
+use rand_chacha::ChaCha12Rng;
 use ratatui::backend::CrosstermBackend;
-use ratatui::style::{Color, Style};
-use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Paragraph};
 use ratatui::Terminal;
+use serde::{Deserialize, Serialize};
 use std::io;
+use std::path::PathBuf;
 use std::time::{Duration, Instant};
 
 pub const TIMESTEP: f64 = 1.0 / 60.0; // ~16.67ms
+pub const WORLD_WIDTH: f64 = 800.0;
+pub const WORLD_HEIGHT: f64 = 600.0;
 pub const WAVE_DELAY: f64 = 2.0; // seconds between waves
 pub const DRAG_FACTOR: f64 = 0.99;
 pub const MIN_SPAWN_DISTANCE: f64 = 150.0;
+pub const QUICKSAVE_KEY: KeyCode = KeyCode::F(5);
+pub const REWIND_KEY: KeyCode = KeyCode::Backspace;
+pub const NOTICE_TIME: f64 = 1.5; // seconds a save or load message stays up
 
 /// Map asteroid size to its corresponding audio explosion event.
-fn asteroid_explosion_event(size: AsteroidSize) -> AudioEvent {
+pub(crate) fn asteroid_explosion_event(size: AsteroidSize) -> AudioEvent {
     match size {
         AsteroidSize::Large => AudioEvent::AsteroidExplosionLarge,
         AsteroidSize::Medium => AudioEvent::AsteroidExplosionMedium,
@@ -46,9 +67,13 @@ pub enum GameState {
     Menu,
     Playing,
     GameOver,
+    Editing,
+    /// Lifetime stats, opened from the menu.
+    Stats,
 }
 
 /// Accumulator for fixed timestep loop.
+#[derive(Debug)]
 pub struct TimeAccumulator {
     pub accumulated: f64,
     pub timestep: f64,
@@ -71,7 +96,53 @@ impl TimeAccumulator {
     }
 }
 
-/// All playing-state data.
+/// How two simultaneous ships share the asteroid field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Multiplayer {
+    /// Players clear the field together. With friendly fire their bullets also hit each other.
+    CoOp { friendly_fire: bool },
+    /// Players shoot each other for frags as well as asteroids for points.
+    Versus,
+}
+
+impl Multiplayer {
+    /// True if bullets hit the other player's ship.
+    pub fn bullets_hit_ships(self) -> bool {
+        matches!(
+            self,
+            Multiplayer::Versus
+                | Multiplayer::CoOp {
+                    friendly_fire: true
+                }
+        )
+    }
+}
+
+/// Player 2 in a simultaneous two-ship game: their own ship, bullets and score,
+/// plus the versus frag scoreboard for both players.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PlayerTwo {
+    pub mode: Multiplayer,
+    pub ship: Ship,
+    pub bullet_pool: BulletPool,
+    pub score: u32,
+    /// Enemy ships destroyed by player 1 and player 2 (versus only).
+    pub frags: [u32; 2],
+    /// Player 2's combo and wave bonuses.
+    #[serde(default)]
+    pub scoring: Scoring,
+    /// Player 2's statistics for the game.
+    #[serde(default)]
+    pub stats: SessionStats,
+}
+
+/// The game's random number generator. The same ChaCha12 generator as `StdRng`, but
+/// its state can be saved and restored.
+pub type GameRng = ChaCha12Rng;
+
+/// All playing-state data. Serializes completely, RNG included, so a restored game
+/// continues exactly as the original would have.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct PlayingState {
     pub ship: Ship,
     pub asteroids: Vec<Asteroid>,
@@ -79,13 +150,72 @@ pub struct PlayingState {
     pub score: u32,
     pub wave: u32,
     pub wave_delay_timer: f64,
-    pub rng: StdRng,
+    pub rng: GameRng,
     pub frame_count: u64,
+    /// Second ship for simultaneous co-op and versus; `ship`, `bullet_pool` and `score` are player 1's.
+    pub player_two: Option<PlayerTwo>,
+    /// Rules the game is played under.
+    #[serde(default)]
+    pub mode: GameMode,
+    /// Seconds played, for timed modes.
+    #[serde(default)]
+    pub clock: f64,
+    /// Ships lost in survival, where lives never run out.
+    #[serde(default)]
+    pub deaths: u32,
+    /// Preset for wave size, rock speed and bullet limits.
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    /// Ease or harden waves by how the last one went.
+    #[serde(default)]
+    pub adaptive: bool,
+    /// Adaptive steps applied to the preset, from -2 (easier) to 2 (harder).
+    #[serde(default)]
+    pub adaptive_level: i32,
+    /// Ships lost since the current wave started.
+    #[serde(default)]
+    pub wave_deaths: u32,
+    /// Scripted waves replacing the generated ones, if a level pack is being played.
+    #[serde(default)]
+    pub level: Option<LevelPack>,
+    /// Seconds since the current level wave started.
+    #[serde(default)]
+    pub wave_clock: f64,
+    /// The last level wave was won.
+    #[serde(default)]
+    pub level_complete: bool,
+    /// Power-ups dropped by destroyed asteroids, waiting to be collected.
+    #[serde(default)]
+    pub pickups: Vec<Pickup>,
+    /// Player 1's combo and wave bonuses.
+    #[serde(default)]
+    pub scoring: Scoring,
+    /// Points floating up from destroyed rocks.
+    #[serde(default)]
+    pub popups: Vec<Popup>,
+    /// Each player's bonuses for the wave just cleared, shown until the next starts.
+    #[serde(default)]
+    pub tally: Vec<WaveTally>,
+    /// Points per extra life and the lives cap.
+    #[serde(default)]
+    pub extra_lives: ExtraLives,
+    /// Player 1's statistics for the game.
+    #[serde(default)]
+    pub stats: SessionStats,
 }
 
 impl PlayingState {
     pub fn new(world_width: f64, world_height: f64) -> Self {
-        let mut rng = StdRng::from_entropy();
+        Self::with_rng(world_width, world_height, GameRng::from_entropy())
+    }
+
+    /// Create with a seeded RNG for deterministic testing.
+    pub fn new_seeded(world_width: f64, world_height: f64, seed: u64) -> Self {
+        Self::with_rng(world_width, world_height, GameRng::seed_from_u64(seed))
+    }
+
+    /// A first wave around a ship in the middle of the world, drawing from `rng`.
+    fn with_rng(world_width: f64, world_height: f64, mut rng: GameRng) -> Self {
         let ship = Ship::new(world_width / 2.0, world_height / 2.0);
         let asteroids = asteroids::spawn_wave(
             1,
@@ -105,35 +235,151 @@ impl PlayingState {
             wave_delay_timer: 0.0,
             rng,
             frame_count: 0,
+            player_two: None,
+            mode: GameMode::Classic,
+            clock: 0.0,
+            deaths: 0,
+            difficulty: Difficulty::Normal,
+            adaptive: false,
+            adaptive_level: 0,
+            wave_deaths: 0,
+            level: None,
+            wave_clock: 0.0,
+            level_complete: false,
+            pickups: Vec::new(),
+            scoring: Scoring::default(),
+            popups: Vec::new(),
+            tally: Vec::new(),
+            extra_lives: ExtraLives::default(),
+            stats: SessionStats::default(),
         }
     }
 
-    /// Create with a seeded RNG for deterministic testing.
-    pub fn new_seeded(world_width: f64, world_height: f64, seed: u64) -> Self {
-        let mut rng = StdRng::seed_from_u64(seed);
-        let ship = Ship::new(world_width / 2.0, world_height / 2.0);
-        let asteroids = asteroids::spawn_wave(
-            1,
-            ship.position,
+    /// Play under `mode`'s rules, starting with its number of lives.
+    pub fn set_mode(&mut self, mode: GameMode) {
+        self.mode = mode;
+        self.ship.lives = mode.starting_lives();
+    }
+
+    /// Play at `difficulty`, optionally adapting to the player: respawns the first wave
+    /// to suit it and sets both players' bullet limits.
+    pub fn set_difficulty(
+        &mut self,
+        difficulty: Difficulty,
+        adaptive: bool,
+        world_width: f64,
+        world_height: f64,
+    ) {
+        self.difficulty = difficulty;
+        self.adaptive = adaptive;
+        self.adaptive_level = 0;
+        let settings = self.wave_settings();
+        self.bullet_pool.limit = settings.max_bullets;
+        if let Some(ref mut two) = self.player_two {
+            two.bullet_pool.limit = settings.max_bullets;
+        }
+        self.asteroids = asteroids::spawn_wave_with(
+            &settings,
+            self.wave,
+            self.ship.position,
             MIN_SPAWN_DISTANCE,
             world_width,
             world_height,
-            &mut rng,
+            &mut self.rng,
+        );
+    }
+
+    /// Play a level pack: its waves replace the generated ones, from the first.
+    pub fn set_level(&mut self, level: LevelPack, world_width: f64, world_height: f64) {
+        self.wave = 1;
+        self.wave_clock = 0.0;
+        self.level_complete = false;
+        self.asteroids = level.spawn(
+            1,
+            self.ship.position,
+            world_width,
+            world_height,
+            &mut self.rng,
         );
+        self.level = Some(level);
+    }
 
-        Self {
+    /// Seconds left to win the current level wave, if it is timed.
+    pub fn wave_time_left(&self) -> Option<f64> {
+        let def = self.level.as_ref()?.wave_def(self.wave)?;
+        def.time_limit
+            .map(|limit| (limit - self.wave_clock).max(0.0))
+    }
+
+    /// Wave settings in force: the preset, adjusted by the adaptive level if adaptive.
+    pub fn wave_settings(&self) -> WaveSettings {
+        let level = if self.adaptive {
+            self.adaptive_level
+        } else {
+            0
+        };
+        self.difficulty.settings().adjusted(level)
+    }
+
+    /// Seconds left in a timed mode.
+    pub fn time_left(&self) -> Option<f64> {
+        self.mode
+            .time_limit()
+            .map(|limit| (limit - self.clock).max(0.0))
+    }
+
+    /// Add player 2's ship for a simultaneous two-ship game. The two ships start on
+    /// either side of the centre with spawn invulnerability.
+    pub fn add_player_two(&mut self, mode: Multiplayer, world_width: f64, world_height: f64) {
+        let y = world_height / 2.0;
+        self.ship.position = Vec2::new(world_width / 3.0, y);
+        let mut ship = Ship::new(world_width * 2.0 / 3.0, y);
+        for ship in [&mut self.ship, &mut ship] {
+            ship.invulnerable = true;
+            ship.invulnerable_timer = INVULNERABILITY_DURATION;
+        }
+        self.player_two = Some(PlayerTwo {
+            mode,
             ship,
-            asteroids,
             bullet_pool: BulletPool::new(),
             score: 0,
-            wave: 1,
-            wave_delay_timer: 0.0,
-            rng,
-            frame_count: 0,
+            frags: [0, 0],
+            scoring: Scoring::default(),
+            stats: SessionStats::default(),
+        });
+    }
+
+    /// Number of ships in play (1, or 2 in simultaneous modes).
+    pub fn pilot_count(&self) -> usize {
+        if self.player_two.is_some() {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// Ship, bullets and score of player `p` (0 or 1).
+    fn pilot_mut(&mut self, p: usize) -> PilotMut<'_> {
+        match (p, self.player_two.as_mut()) {
+            (1, Some(two)) => PilotMut {
+                ship: &mut two.ship,
+                bullet_pool: &mut two.bullet_pool,
+                score: &mut two.score,
+                scoring: &mut two.scoring,
+                stats: &mut two.stats,
+            },
+            _ => PilotMut {
+                ship: &mut self.ship,
+                bullet_pool: &mut self.bullet_pool,
+                score: &mut self.score,
+                scoring: &mut self.scoring,
+                stats: &mut self.stats,
+            },
         }
     }
 
-    /// Perform one fixed-timestep update. Returns UpdateResult with state transition and audio events.
+    /// Perform one fixed-timestep update. Returns UpdateResult with the state transition
+    /// and the update's events.
     pub fn update(
         &mut self,
         input: &InputState,
@@ -141,163 +387,490 @@ impl PlayingState {
         world_width: f64,
         world_height: f64,
     ) -> UpdateResult {
-        let mut audio_events: Vec<AudioEvent> = Vec::new();
+        self.update_players(
+            [input, &InputState::default()],
+            dt,
+            world_width,
+            world_height,
+        )
+    }
 
-        // 1. Process input
-        if input.is_active(Action::Quit) {
-            return UpdateResult {
-                state: Some(GameState::Menu),
-                audio_events,
-            };
+    /// Add points to each player's score, awarding the extra lives the mode allows,
+    /// with an event for each.
+    fn award_points(&mut self, gained: [u32; 2], events: &mut Vec<GameEvent>) {
+        let rule = self.mode.awards_extra_lives().then_some(self.extra_lives);
+        for (p, &points) in gained.iter().enumerate().take(self.pilot_count()) {
+            if points == 0 {
+                continue;
+            }
+            let pilot = self.pilot_mut(p);
+            let from = *pilot.score;
+            *pilot.score += points;
+            events.push(GameEvent::PointsAwarded { player: p, points });
+            if let Some(rule) = rule {
+                let awarded = pilot.ship.check_extra_life(from, *pilot.score, rule);
+                events.extend((0..awarded).map(|_| GameEvent::ExtraLife { player: p }));
+            }
         }
+    }
 
-        // 2. Update ship
-        self.ship.rotate(
-            input.is_active(Action::RotateLeft),
-            input.is_active(Action::RotateRight),
-            dt,
-        );
-        if input.is_active(Action::Thrust) {
-            self.ship.thrust(dt);
-            audio_events.push(AudioEvent::Thrust);
+    /// Tally and award each player's wave bonuses once the wave's rocks are gone.
+    /// Ships out of a two-ship game get nothing, and the wave does not count for them.
+    fn finish_wave(&mut self, events: &mut Vec<GameEvent>) {
+        let multiplayer = self.pilot_count() > 1;
+        let mut bonus = [0, 0];
+        self.tally = (0..self.pilot_count())
+            .map(|p| {
+                let wave = self.wave;
+                let pilot = self.pilot_mut(p);
+                let tally = pilot.scoring.finish_wave(wave);
+                if multiplayer && pilot.ship.lives == 0 {
+                    return WaveTally {
+                        accuracy_bonus: 0,
+                        no_death_bonus: 0,
+                        speed_bonus: 0,
+                        ..tally
+                    };
+                }
+                events.push(GameEvent::WaveCleared { player: p });
+                bonus[p] = tally.total();
+                tally
+            })
+            .collect();
+        self.award_points(bonus, events);
+    }
+
+    /// Perform one fixed-timestep update with input for each player. Player 2's input
+    /// only matters when a second ship is in play. Each player's bullets score for that
+    /// player. In simultaneous modes a ship with no lives left sits out, and the game is
+    /// over once both ships are out.
+    pub fn update_players(
+        &mut self,
+        inputs: [&InputState; 2],
+        dt: f64,
+        world_width: f64,
+        world_height: f64,
+    ) -> UpdateResult {
+        let mut events: Vec<GameEvent> = Vec::new();
+        let pilots = self.pilot_count();
+        let multiplayer = pilots > 1;
+
+        // 1. Process input
+        if inputs.iter().any(|input| input.is_active(Action::Quit)) {
+            return self.report(Some(GameState::Menu), events);
         }
-        self.ship.velocity = physics::apply_drag(self.ship.velocity, DRAG_FACTOR);
-        self.ship.update(dt, world_width, world_height);
 
-        // 3. Update bullets
-        self.bullet_pool.update(dt, world_width, world_height);
+        for (p, input) in inputs.iter().enumerate().take(pilots) {
+            let pilot = self.pilot_mut(p);
+            let out = multiplayer && pilot.ship.lives == 0;
 
-        // Fire if requested
-        if input.is_active(Action::Fire) {
-            let nose = self.ship.nose_position();
-            self.bullet_pool.fire(nose, self.ship.rotation);
-            audio_events.push(AudioEvent::Fire);
+            // 2. Update ship
+            if !out {
+                pilot.ship.rotate(
+                    input.is_active(Action::RotateLeft),
+                    input.is_active(Action::RotateRight),
+                    dt,
+                );
+                let thrusting = input.is_active(Action::Thrust);
+                if thrusting {
+                    pilot.ship.thrust(dt);
+                }
+                pilot.ship.velocity = physics::apply_drag(pilot.ship.velocity, DRAG_FACTOR);
+                pilot
+                    .ship
+                    .update_shield(input.is_active(Action::Shield), dt);
+                pilot.ship.update(dt, world_width, world_height);
+                events.push(GameEvent::ShipFlew {
+                    player: p,
+                    dt,
+                    speed: pilot.ship.velocity.magnitude(),
+                    thrusting,
+                });
+            }
+
+            // 3. Update bullets
+            pilot.bullet_pool.update(dt, world_width, world_height);
+            pilot.scoring.update(dt);
+
+            // Fire if requested, counting bullets for accuracy
+            if !out && input.is_active(Action::Fire) {
+                let nose = pilot.ship.nose_position();
+                let before = pilot.bullet_pool.bullets.len();
+                pilot.bullet_pool.fire(nose, pilot.ship.rotation);
+                let fired = pilot.bullet_pool.bullets.len() - before;
+                pilot.scoring.shot(fired);
+                if fired > 0 {
+                    events.push(GameEvent::BulletFired {
+                        player: p,
+                        bullets: fired as u32,
+                    });
+                }
+            }
         }
 
         // 4. Update asteroids
-        for asteroid in &mut self.asteroids {
-            asteroid.update(dt, world_width, world_height);
-        }
+        let rock_dt = dt * self.mode.rock_speed();
+        entity::update_all(&mut self.asteroids, rock_dt, world_width, world_height);
+        entity::update_all(&mut self.pickups, dt, world_width, world_height);
+        entity::update_all(&mut self.popups, dt, world_width, world_height);
 
-        // 5. Check collisions
-        // Bullet-asteroid
-        let mut new_asteroids: Vec<Asteroid> = Vec::new();
-        let mut bullets_to_remove: Vec<usize> = Vec::new();
-        let mut asteroids_to_remove: Vec<usize> = Vec::new();
-        let mut score_gained: u32 = 0;
+        // 5. Check collisions, with the rocks and pickups held apart from the players
+        // so each pass can reach a player's ship and bullets through `pilot_mut`
+        let mut asteroids = std::mem::take(&mut self.asteroids);
+        let mut pickups = std::mem::take(&mut self.pickups);
 
-        for (bi, bullet) in self.bullet_pool.bullets.iter().enumerate() {
-            for (ai, asteroid) in self.asteroids.iter().enumerate() {
-                if asteroids_to_remove.contains(&ai) {
-                    continue;
-                }
-                let result = collision::check_bullet_asteroid_collision(
-                    bullet.position,
-                    bullets::BULLET_RADIUS,
-                    asteroid.position,
-                    asteroid.size.radius(),
-                    match asteroid.size {
-                        AsteroidSize::Large => collision::AsteroidSize::Large,
-                        AsteroidSize::Medium => collision::AsteroidSize::Medium,
-                        AsteroidSize::Small => collision::AsteroidSize::Small,
-                    },
-                    world_width,
-                    world_height,
-                );
-                match result {
-                    collision::BulletAsteroidResult::AsteroidSplit { .. }
-                    | collision::BulletAsteroidResult::AsteroidDestroyed => {
-                        bullets_to_remove.push(bi);
-                        asteroids_to_remove.push(ai);
-                        score_gained += asteroid.size.points();
-                        audio_events.push(asteroid_explosion_event(asteroid.size));
-                        if let Some(children) = asteroid.split(&mut self.rng) {
-                            new_asteroids.extend(children);
-                        }
-                        break;
-                    }
-                    collision::BulletAsteroidResult::NoCollision => {}
-                }
+        // Bullet-asteroid, credited to the player who fired and scored through their
+        // combo. Piercing bullets carry on; some destroyed asteroids drop a pickup.
+        let mut taken = vec![false; asteroids.len()];
+        let mut hits: Vec<(usize, usize, usize)> = Vec::new();
+        for p in 0..pilots {
+            let pool = self.pilot_mut(p).bullet_pool;
+            let contacts = entity::first_contacts(
+                &pool.bullets,
+                Bullet::can_hit,
+                &asteroids,
+                &mut taken,
+                world_width,
+                world_height,
+            );
+            hits.extend(contacts.into_iter().map(|(bi, ai)| (p, bi, ai)));
+        }
+        let mut new_asteroids: Vec<Asteroid> = Vec::new();
+        let mut score_gained: [u32; 2] = [0, 0];
+        let split_speed = self.wave_settings().split_speed;
+        for (p, bi, ai) in hits {
+            let asteroid = &asteroids[ai];
+            pickups.extend(pickups::maybe_drop(asteroid.position, &mut self.rng));
+            if let Some(new_size) = asteroid.size.split_into() {
+                new_asteroids.extend(asteroid.break_into(new_size, split_speed, &mut self.rng));
+            }
+            events.push(GameEvent::AsteroidDestroyed {
+                size: asteroid.size,
+                position: asteroid.position,
+                by: Destroyer::Bullet { player: p },
+            });
+            let pilot = self.pilot_mut(p);
+            let bullet = &mut pilot.bullet_pool.bullets[bi];
+            if bullet.piercing {
+                bullet.pierce_cooldown = bullets::PIERCE_COOLDOWN;
+            } else {
+                bullet.alive = false;
             }
+            let (scored, multiplier) = pilot.scoring.kill(asteroid.size.points());
+            score_gained[p] += scored;
+            self.popups
+                .push(Popup::new(asteroid.position, scored, multiplier));
         }
+        let mut taken = taken.into_iter();
+        asteroids.retain(|_| !taken.next().unwrap_or(false));
+        asteroids.extend(new_asteroids);
 
-        // Remove destroyed bullets and asteroids (reverse order to keep indices valid)
-        for &bi in bullets_to_remove.iter().rev() {
-            if bi < self.bullet_pool.bullets.len() {
-                self.bullet_pool.bullets[bi].alive = false;
+        // Ship-pickup: timed power-ups go to the ship that flies into them, and a
+        // smart bomb destroys every rock, scoring them for that player
+        let mut bombs: Vec<usize> = Vec::new();
+        for p in 0..pilots {
+            let pilot = self.pilot_mut(p);
+            if multiplayer && pilot.ship.lives == 0 {
+                continue;
+            }
+            for pickup in
+                entity::take_touching(&*pilot.ship, &mut pickups, world_width, world_height)
+            {
+                pickup.kind.apply(pilot.ship, pilot.bullet_pool);
+                events.push(GameEvent::PowerUpCollected {
+                    player: p,
+                    kind: pickup.kind,
+                });
+                if pickup.kind == PowerUp::SmartBomb {
+                    bombs.push(p);
+                }
             }
         }
-        asteroids_to_remove.sort_unstable();
-        asteroids_to_remove.dedup();
-        for &ai in asteroids_to_remove.iter().rev() {
-            if ai < self.asteroids.len() {
-                self.asteroids.remove(ai);
+        self.pickups = pickups;
+        for p in bombs {
+            for asteroid in asteroids.drain(..) {
+                events.push(GameEvent::AsteroidDestroyed {
+                    size: asteroid.size,
+                    position: asteroid.position,
+                    by: Destroyer::SmartBomb { player: p },
+                });
+                let points = asteroid.size.points();
+                score_gained[p] += points;
+                self.popups.push(Popup::new(asteroid.position, points, 1));
             }
         }
-        self.asteroids.extend(new_asteroids);
 
         // 6. Process scoring
-        let prev_lives = self.ship.lives;
-        self.score += score_gained;
-        self.ship.check_extra_life(self.score);
-        if self.ship.lives > prev_lives {
-            audio_events.push(AudioEvent::ExtraLife);
+        self.award_points(score_gained, &mut events);
+
+        // Bullet-ship, when the mode lets players shoot each other
+        if let Some(two) = self
+            .player_two
+            .as_mut()
+            .filter(|two| two.mode.bullets_hit_ships())
+        {
+            let hits = [
+                shoot_ship(
+                    &mut self.bullet_pool,
+                    &mut two.ship,
+                    world_width,
+                    world_height,
+                ),
+                shoot_ship(
+                    &mut two.bullet_pool,
+                    &mut self.ship,
+                    world_width,
+                    world_height,
+                ),
+            ];
+            if two.mode == Multiplayer::Versus {
+                for (frags, hit) in two.frags.iter_mut().zip(hits) {
+                    *frags += u32::from(hit);
+                }
+            }
+            for shooter in (0..2).filter(|&shooter| hits[shooter]) {
+                let target = self.pilot_mut(1 - shooter);
+                target.bullet_pool.clear_power_ups();
+                target.scoring.death();
+                events.push(GameEvent::ShipDestroyed {
+                    player: 1 - shooter,
+                    by: Some(shooter),
+                });
+            }
+        }
+
+        // Shield bounce: a ship with its shield up bounces off rocks, trading momentum
+        for p in 0..pilots {
+            let ship = self.pilot_mut(p).ship;
+            if !ship.shield_up {
+                continue;
+            }
+            let bounces = entity::bounce_all(
+                &ship.shield(),
+                &mut ship.velocity,
+                crate::ship::SHIP_MASS,
+                &mut asteroids,
+                world_width,
+                world_height,
+            );
+            events.extend((0..bounces).map(|_| GameEvent::ShieldBounce { player: p }));
         }
 
-        // Ship-asteroid collision
-        for asteroid in &self.asteroids {
+        // Ship-asteroid collision. Shielded ships pass through rocks; destroyed ships
+        // lose their power-ups.
+        let ship_can_die = self.mode.ship_can_die();
+        let infinite_lives = self.mode.infinite_lives();
+        let mut game_over = false;
+        for p in (0..pilots).filter(|_| ship_can_die) {
+            let pilot = self.pilot_mut(p);
+            if multiplayer && pilot.ship.lives == 0 {
+                continue;
+            }
+            let Some(ai) =
+                entity::first_touching(&*pilot.ship, &asteroids, world_width, world_height)
+            else {
+                continue;
+            };
             let result = collision::check_ship_asteroid_collision(
-                self.ship.position,
-                crate::ship::SHIP_RADIUS,
-                self.ship.lives,
-                self.ship.invulnerable,
-                asteroid.position,
-                asteroid.size.radius(),
+                pilot.ship,
+                &asteroids[ai],
                 world_width,
                 world_height,
             );
             match result {
+                collision::ShipCollisionResult::ShipDestroyed { .. }
+                | collision::ShipCollisionResult::GameOver
+                    if infinite_lives =>
+                {
+                    pilot.ship.respawn(world_width, world_height);
+                    pilot.bullet_pool.clear_power_ups();
+                    pilot.scoring.death();
+                    self.deaths += 1;
+                    self.wave_deaths += 1;
+                }
                 collision::ShipCollisionResult::ShipDestroyed { .. } => {
-                    self.ship.destroy(world_width, world_height);
-                    audio_events.push(AudioEvent::ShipDestroyed);
-                    break;
+                    pilot.ship.destroy(world_width, world_height);
+                    pilot.bullet_pool.clear_power_ups();
+                    pilot.scoring.death();
+                    self.wave_deaths += 1;
                 }
                 collision::ShipCollisionResult::GameOver => {
-                    self.ship.lives = 0;
-                    audio_events.push(AudioEvent::ShipDestroyed);
-                    return UpdateResult {
-                        state: Some(GameState::GameOver),
-                        audio_events,
-                    };
+                    pilot.ship.lives = 0;
+                    game_over = !multiplayer;
                 }
-                collision::ShipCollisionResult::NoCollision => {}
+                collision::ShipCollisionResult::NoCollision => continue,
+            }
+            events.push(GameEvent::ShipDestroyed {
+                player: p,
+                by: None,
+            });
+            if game_over {
+                break;
             }
         }
+        if game_over {
+            self.asteroids = asteroids;
+            return self.report(Some(GameState::GameOver), events);
+        }
+
+        // Near misses: rocks passing close to a ship that could have been hit all along.
+        // A ship lost this tick was hit, not missed.
+        for p in 0..pilots {
+            let lost = events.iter().any(
+                |event| matches!(event, GameEvent::ShipDestroyed { player, .. } if *player == p),
+            );
+            if lost {
+                continue;
+            }
+            let pilot = self.pilot_mut(p);
+            let ship = &*pilot.ship;
+            let exposed = ship.lives > 0 && !ship.invulnerable && !ship.shielded();
+            if !exposed {
+                pilot.stats.covered();
+                continue;
+            }
+            let close = asteroids.iter().any(|asteroid| {
+                collision::collide_with_margin(
+                    ship,
+                    asteroid,
+                    stats::NEAR_MISS_MARGIN,
+                    world_width,
+                    world_height,
+                )
+            });
+            pilot.stats.near(close);
+        }
+        self.asteroids = asteroids;
+
+        let all_out = self.ship.lives == 0
+            && self
+                .player_two
+                .as_ref()
+                .is_some_and(|two| two.ship.lives == 0);
+        if all_out {
+            return self.report(Some(GameState::GameOver), events);
+        }
 
-        // 7. Check wave completion
+        // 7. Check wave completion. Level waves can also be won by surviving or
+        // scoring, and are lost if their time runs out first; the last one ends the game.
+        let level_wave = self.level.as_ref().and_then(|level| {
+            let def = level.wave_def(self.wave)?;
+            Some((
+                def.win,
+                def.time_limit,
+                self.wave as usize >= level.waves.len(),
+            ))
+        });
+        if let Some((win, time_limit, last)) = level_wave {
+            let in_progress = self.wave_delay_timer == 0.0;
+            if in_progress {
+                self.wave_clock += dt;
+                let out_of_time = time_limit.is_some_and(|limit| self.wave_clock >= limit);
+                let won = match win {
+                    WinCondition::Clear => self.asteroids.is_empty(),
+                    WinCondition::Survive => out_of_time,
+                    WinCondition::Score(target) => self.score >= target,
+                };
+                if won {
+                    self.asteroids.clear();
+                    self.level_complete = last;
+                }
+                if self.level_complete || (out_of_time && !won) {
+                    return self.report(Some(GameState::GameOver), events);
+                }
+            }
+        }
         if self.asteroids.is_empty() {
+            if self.wave_delay_timer == 0.0 {
+                self.finish_wave(&mut events);
+            }
             self.wave_delay_timer += dt;
             if self.wave_delay_timer >= WAVE_DELAY {
                 self.wave += 1;
                 self.wave_delay_timer = 0.0;
-                self.asteroids = asteroids::spawn_wave(
-                    self.wave,
-                    self.ship.position,
-                    MIN_SPAWN_DISTANCE,
-                    world_width,
-                    world_height,
-                    &mut self.rng,
-                );
-                audio_events.push(AudioEvent::NewWave);
+                if self.adaptive {
+                    self.adaptive_level = difficulty::adapt(self.adaptive_level, self.wave_deaths);
+                }
+                self.wave_deaths = 0;
+                self.wave_clock = 0.0;
+                self.tally.clear();
+                for p in 0..pilots {
+                    self.pilot_mut(p).scoring.start_wave();
+                }
+                self.asteroids = match self.level {
+                    Some(ref level) => level.spawn(
+                        self.wave,
+                        self.ship.position,
+                        world_width,
+                        world_height,
+                        &mut self.rng,
+                    ),
+                    None => asteroids::spawn_wave_with(
+                        &self.wave_settings(),
+                        self.wave,
+                        self.ship.position,
+                        MIN_SPAWN_DISTANCE,
+                        world_width,
+                        world_height,
+                        &mut self.rng,
+                    ),
+                };
+                events.push(GameEvent::WaveStarted { wave: self.wave });
             }
         }
 
+        // 8. Run the clock in timed modes
+        self.clock += dt;
+        let state = self
+            .time_left()
+            .filter(|&left| left <= 0.0)
+            .map(|_| GameState::GameOver);
+
         self.frame_count += 1;
-        UpdateResult {
-            state: None,
-            audio_events,
+        self.report(state, events)
+    }
+
+    /// End an update: each player's stats follow the update's events, which are then
+    /// handed back with the state change.
+    fn report(&mut self, state: Option<GameState>, events: Vec<GameEvent>) -> UpdateResult {
+        for event in &events {
+            if let Some(p) = event.player().filter(|&p| p < self.pilot_count()) {
+                self.pilot_mut(p).stats.observe(event);
+            }
         }
+        UpdateResult { state, events }
+    }
+}
+
+/// Mutable view of one player's ship, bullets, score, scoring and stats.
+struct PilotMut<'a> {
+    ship: &'a mut Ship,
+    bullet_pool: &'a mut BulletPool,
+    score: &'a mut u32,
+    scoring: &'a mut Scoring,
+    stats: &'a mut SessionStats,
+}
+
+/// Resolve one player's bullets against the other player's ship. At most one bullet
+/// hits per tick; the ship loses a life as if it hit an asteroid. Returns true on a hit.
+fn shoot_ship(
+    pool: &mut BulletPool,
+    target: &mut Ship,
+    world_width: f64,
+    world_height: f64,
+) -> bool {
+    if target.lives == 0 || target.invulnerable || target.shielded() {
+        return false;
     }
+    let hit = pool.bullets.iter_mut().find(|bullet| {
+        bullet.alive && collision::collide(&**bullet, &*target, world_width, world_height)
+    });
+    let Some(bullet) = hit else {
+        return false;
+    };
+    bullet.alive = false;
+    target.destroy(world_width, world_height);
+    true
 }
 
 /// The top-level game that manages state transitions and the main loop.
@@ -308,6 +881,78 @@ pub struct Game {
     pub final_score: u32,
     pub world_width: f64,
     pub world_height: f64,
+    /// Drives the player's ship during Playing.
+    pub controller: Box<dyn Controller>,
+    /// Drives the attract-mode demo ship during Menu.
+    pub demo_controller: Box<dyn Controller>,
+    /// Drives player 2's ship in simultaneous two-ship modes.
+    pub controller_two: Box<dyn Controller>,
+    /// Input applied on the most recent Playing tick (drives the thrust flame).
+    pub last_input: InputState,
+    /// Player 2's input on the most recent Playing tick.
+    pub last_input_two: InputState,
+    /// Two-player alternating turns, when that mode is being played.
+    pub turns: Option<Turns>,
+    /// Whether co-op games started from the menu let players shoot each other.
+    pub friendly_fire: bool,
+    /// Both players' scores from the last two-player game.
+    pub final_scores: Option<[u32; 2]>,
+    /// Both players' frags from the last versus game.
+    pub final_frags: Option<[u32; 2]>,
+    /// Network session when playing against a remote player.
+    pub lockstep: Option<Lockstep>,
+    /// Why the last network game ended early, if it did.
+    pub net_error: Option<String>,
+    /// Board that finished games are entered on, if any.
+    pub high_scores: Option<SharedHighScores>,
+    /// Name entered on the high score board.
+    pub player_name: String,
+    /// Where quicksaves are written and continued from; None disables both.
+    pub save_path: Option<PathBuf>,
+    /// Short message about saving or loading, shown until its timer runs out.
+    pub notice: Option<String>,
+    pub notice_timer: f64,
+    /// History for rewinding; None in modes where rewind is not allowed.
+    pub rewind: Option<Rewind>,
+    /// True while the rewind key is held.
+    pub rewinding: bool,
+    /// The current or last game was rewound, so its score does not count.
+    pub assisted: bool,
+    /// Mode for one-player games started from the menu.
+    pub mode: GameMode,
+    /// Mode of the last game, whose high score board is shown after it.
+    pub final_mode: GameMode,
+    /// Ships lost in the last survival game.
+    pub final_deaths: Option<u32>,
+    /// Difficulty for one-player games started from the menu.
+    pub difficulty: Difficulty,
+    /// Whether one-player games adapt their difficulty to the player.
+    pub adaptive: bool,
+    /// How player 1 steers; change it with `set_controls` so the controller follows.
+    pub controls: ControlScheme,
+    /// Points per extra life and the lives cap in games started here.
+    pub extra_lives: ExtraLives,
+    /// Level pack one-player games play instead of generated waves.
+    pub level: Option<LevelPack>,
+    /// How far the last level game got, for the results.
+    pub final_level: Option<String>,
+    /// Level file the editor opens and saves; None disables the editor.
+    pub level_path: Option<PathBuf>,
+    /// Level being edited, kept while it is test-played.
+    pub editor: Option<Editor>,
+    /// Where lifetime stats are kept; None keeps none and hides them from the menu.
+    pub stats_path: Option<PathBuf>,
+    /// Lifetime stats as last read or written.
+    pub lifetime: LifetimeStats,
+    /// Each player's stats from the last game, for the summary.
+    pub final_stats: Vec<SessionStats>,
+    /// Where unlocked achievements are kept; None watches for none.
+    pub achievements_path: Option<PathBuf>,
+    /// Unlocked achievements and progress toward the rest in the current game.
+    pub achievements: Achievements,
+    /// Unlocks waiting to be shown, the first on screen until its timer runs out.
+    pub toasts: Vec<String>,
+    pub toast_timer: f64,
 }
 
 impl Game {
@@ -319,6 +964,229 @@ impl Game {
             final_score: 0,
             world_width,
             world_height,
+            controller: Box::new(KeyboardController::new()),
+            demo_controller: Box::new(DemoAiController::default()),
+            controller_two: Box::new(KeyboardController::new()),
+            last_input: InputState::default(),
+            last_input_two: InputState::default(),
+            turns: None,
+            friendly_fire: false,
+            final_scores: None,
+            final_frags: None,
+            lockstep: None,
+            net_error: None,
+            high_scores: None,
+            player_name: "PLAYER".to_string(),
+            save_path: None,
+            notice: None,
+            notice_timer: 0.0,
+            rewind: None,
+            rewinding: false,
+            assisted: false,
+            mode: GameMode::Classic,
+            final_mode: GameMode::Classic,
+            final_deaths: None,
+            difficulty: Difficulty::Normal,
+            adaptive: false,
+            controls: ControlScheme::Keyboard,
+            extra_lives: ExtraLives::default(),
+            level: None,
+            final_level: None,
+            level_path: None,
+            editor: None,
+            stats_path: None,
+            lifetime: LifetimeStats::default(),
+            final_stats: Vec::new(),
+            achievements_path: None,
+            achievements: Achievements::default(),
+            toasts: Vec::new(),
+            toast_timer: 0.0,
+        }
+    }
+
+    /// A game that keeps its quicksave, stats, achievements and edited level in the data
+    /// directory.
+    pub fn with_default_paths(world_width: f64, world_height: f64) -> Self {
+        let mut game = Self::new(world_width, world_height);
+        game.save_path = Some(save::default_path());
+        game.stats_path = Some(stats::default_path());
+        game.achievements_path = Some(achievements::default_path());
+        game.level_path = Some(editor::default_path());
+        game
+    }
+
+    /// Replace the controller that drives the player's ship.
+    pub fn set_controller(&mut self, controller: Box<dyn Controller>) {
+        self.controller = controller;
+    }
+
+    /// Forward the keyboard state polled by the run loop to the player's controller.
+    pub fn feed_keyboard(&mut self, keys: &InputState) {
+        self.controller.feed_keyboard(keys);
+    }
+
+    /// Steer player 1 with the keyboard alone or with the mouse alongside it.
+    pub fn set_controls(&mut self, controls: ControlScheme) {
+        self.controls = controls;
+        self.controller = match controls {
+            ControlScheme::Keyboard => Box::new(KeyboardController::new()),
+            ControlScheme::Mouse => Box::new(MouseController::new()),
+        };
+    }
+
+    /// Forward the mouse state tracked by the run loop to the player's controller.
+    pub fn feed_mouse(&mut self, mouse: &MouseAim) {
+        self.controller.feed_mouse(mouse);
+    }
+
+    /// Whether the run loop should capture the mouse: in the editor, and while
+    /// playing with mouse controls. Otherwise the terminal keeps it for selecting text.
+    pub fn wants_mouse(&self) -> bool {
+        match self.state {
+            GameState::Editing => true,
+            GameState::Playing => self.controls == ControlScheme::Mouse,
+            _ => false,
+        }
+    }
+
+    /// Forward player 2's keyboard state to their controller.
+    pub fn feed_keyboard_two(&mut self, keys: &InputState) {
+        self.controller_two.feed_keyboard(keys);
+    }
+
+    /// Advance one fixed timestep. Playing asks the player's controller for input;
+    /// Menu ticks the attract-mode demo, which is silent and restarts when it ends.
+    /// In two-player turns, play pauses while the turn banner shows, and a lost life
+    /// or game over hands the controls to the other player if they are still in.
+    /// In a network game the tick waits for the remote player's input, and a network
+    /// failure ends the game. A `state` in the result means the game changed state and
+    /// the caller should stop ticking for this frame.
+    pub fn tick(&mut self, dt: f64) -> UpdateResult {
+        let (w, h) = (self.world_width, self.world_height);
+        if self.notice.is_some() {
+            self.notice_timer -= dt;
+            if self.notice_timer <= 0.0 {
+                self.notice = None;
+            }
+        }
+        if !self.toasts.is_empty() {
+            self.toast_timer -= dt;
+            if self.toast_timer <= 0.0 {
+                self.toasts.remove(0);
+                self.toast_timer = achievements::TOAST_TIME;
+            }
+        }
+        match self.state {
+            GameState::Playing => {
+                let Some(ref mut playing) = self.playing else {
+                    return UpdateResult::none();
+                };
+                if let Some(turns) = self.turns.as_mut().filter(|t| t.is_paused()) {
+                    turns.tick_banner(dt);
+                    return UpdateResult::none();
+                }
+                if let Some(rewind) = self.rewind.as_mut().filter(|_| self.rewinding) {
+                    // Scrub back through the history instead of playing
+                    if let Some(past) = rewind.step_back(REWIND_SPEED, w, h) {
+                        *playing = past;
+                        self.assisted = true;
+                    }
+                    self.last_input = InputState::default();
+                    self.last_input_two = InputState::default();
+                    return UpdateResult::none();
+                }
+                let lives_before = playing.ship.lives;
+                let mut result = if let Some(ref mut lockstep) = self.lockstep {
+                    let local = self.controller.next_input(playing, w, h);
+                    match lockstep.step(playing, &local) {
+                        Ok(result) => {
+                            [self.last_input, self.last_input_two] = lockstep.last_inputs().clone();
+                            result
+                        }
+                        Err(e) => {
+                            self.net_error = Some(e.to_string());
+                            self.game_over();
+                            return UpdateResult {
+                                state: Some(GameState::GameOver),
+                                events: Vec::new(),
+                            };
+                        }
+                    }
+                } else {
+                    let input = self.controller.next_input(playing, w, h);
+                    let input_two = if playing.player_two.is_some() {
+                        self.controller_two.next_input(playing, w, h)
+                    } else {
+                        InputState::default()
+                    };
+                    if let Some(ref mut rewind) = self.rewind {
+                        rewind.record(playing, [&input, &input_two], dt);
+                    }
+                    let result = playing.update_players([&input, &input_two], dt, w, h);
+                    self.last_input = input;
+                    self.last_input_two = input_two;
+                    result
+                };
+                // Achievements watch the local player in games that count
+                let counts =
+                    self.achievements_path.is_some() && !self.assisted && self.editor.is_none();
+                let local = self.lockstep.as_ref().map(Lockstep::local_player);
+                let unlocked: Vec<&Achievement> = result
+                    .events
+                    .iter()
+                    .filter(|_| counts)
+                    .filter(|event| event.player().is_none_or(|p| local.is_none_or(|l| l == p)))
+                    .flat_map(|event| self.achievements.observe(event))
+                    .collect();
+                match result.state {
+                    Some(GameState::GameOver | GameState::Menu) if self.editor.is_some() => {
+                        result.state = Some(GameState::Editing);
+                        self.end_test_play();
+                    }
+                    Some(GameState::GameOver) => {
+                        let handed_over = match self.turns.as_mut() {
+                            Some(turns) => turns.player_out(playing),
+                            None => false,
+                        };
+                        if handed_over {
+                            result.state = None;
+                        } else {
+                            self.game_over();
+                        }
+                    }
+                    Some(GameState::Menu) if playing.mode.endless() => {
+                        // Endless modes have no other way to end, so quitting ends the game
+                        result.state = Some(GameState::GameOver);
+                        self.game_over();
+                    }
+                    Some(GameState::Menu) => {
+                        self.state = GameState::Menu;
+                        self.playing = None;
+                        self.turns = None;
+                        self.end_netplay();
+                        self.start_demo();
+                    }
+                    _ => {
+                        if playing.ship.lives < lives_before {
+                            if let Some(turns) = self.turns.as_mut() {
+                                turns.life_lost(playing);
+                            }
+                        }
+                    }
+                }
+                self.celebrate(&unlocked);
+                result
+            }
+            GameState::Menu => {
+                if let Some(ref mut demo) = self.demo {
+                    let input = self.demo_controller.next_input(demo, w, h);
+                    if demo.update(&input, dt, w, h).state.is_some() {
+                        self.start_demo();
+                    }
+                }
+                UpdateResult::none()
+            }
+            GameState::GameOver | GameState::Editing | GameState::Stats => UpdateResult::none(),
         }
     }
 
@@ -329,10 +1197,45 @@ impl Game {
                 if code == KeyCode::Char('q') || code == KeyCode::Char('Q') {
                     return true; // quit
                 }
-                // Any other key starts the game
-                self.state = GameState::Playing;
-                self.playing = Some(PlayingState::new(self.world_width, self.world_height));
-                self.demo = None;
+                // 2 starts alternating turns, C co-op, V versus, F toggles friendly fire,
+                // M picks the one-player mode, D its difficulty and A toggles adaptive
+                // difficulty, K switches between keyboard and mouse controls, R continues
+                // a saved game, E opens the level editor, S shows lifetime stats; any
+                // other key starts one player
+                match code {
+                    KeyCode::Char('r') | KeyCode::Char('R') if self.has_saved_game() => {
+                        self.continue_saved();
+                    }
+                    KeyCode::Char('e') | KeyCode::Char('E') if self.level_path.is_some() => {
+                        if let Err(e) = self.open_editor() {
+                            self.show_notice(format!("CANNOT EDIT: {}", e));
+                        }
+                    }
+                    KeyCode::Char('s') | KeyCode::Char('S') if self.stats_path.is_some() => {
+                        self.open_stats()
+                    }
+                    KeyCode::Char('2') => self.start_two_player(),
+                    KeyCode::Char('c') | KeyCode::Char('C') => {
+                        self.start_simultaneous(Multiplayer::CoOp {
+                            friendly_fire: self.friendly_fire,
+                        })
+                    }
+                    KeyCode::Char('v') | KeyCode::Char('V') => {
+                        self.start_simultaneous(Multiplayer::Versus)
+                    }
+                    KeyCode::Char('f') | KeyCode::Char('F') => {
+                        self.friendly_fire = !self.friendly_fire
+                    }
+                    KeyCode::Char('m') | KeyCode::Char('M') => self.mode = self.mode.next(),
+                    KeyCode::Char('d') | KeyCode::Char('D') => {
+                        self.difficulty = self.difficulty.next()
+                    }
+                    KeyCode::Char('a') | KeyCode::Char('A') => self.adaptive = !self.adaptive,
+                    KeyCode::Char('k') | KeyCode::Char('K') => {
+                        self.set_controls(self.controls.next())
+                    }
+                    _ => self.start_game(),
+                }
                 false
             }
             GameState::GameOver => {
@@ -343,513 +1246,960 @@ impl Game {
                 self.demo = Some(PlayingState::new(self.world_width, self.world_height));
                 false
             }
+            GameState::Editing => {
+                let Some(ref mut editor) = self.editor else {
+                    return false;
+                };
+                match editor.handle_key(code) {
+                    EditorCommand::None => {}
+                    EditorCommand::TestPlay => self.test_play(),
+                    EditorCommand::Save => self.save_level(),
+                    EditorCommand::Exit => {
+                        self.state = GameState::Menu;
+                        self.editor = None;
+                        self.start_demo();
+                    }
+                }
+                false
+            }
+            GameState::Stats => {
+                self.state = GameState::Menu;
+                false
+            }
             GameState::Playing => false, // handled in update loop
         }
     }
 
-    /// Transition to game over.
-    pub fn game_over(&mut self) {
-        if let Some(ref playing) = self.playing {
-            self.final_score = playing.score;
+    /// Handle a mouse action; only the level editor uses the mouse.
+    pub fn pointer(&mut self, pointer: Pointer) {
+        if self.state == GameState::Editing {
+            if let Some(ref mut editor) = self.editor {
+                editor.pointer(pointer);
+            }
         }
-        self.state = GameState::GameOver;
-        self.playing = None;
     }
 
-    /// Start a new demo (attract mode) game.
-    pub fn start_demo(&mut self) {
-        self.demo = Some(PlayingState::new(self.world_width, self.world_height));
+    /// Open the level editor on the level file, or on a new level if there is no file
+    /// yet. Fails if the file cannot be read as a level pack.
+    pub fn open_editor(&mut self) -> io::Result<()> {
+        let Some(ref path) = self.level_path else {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "no level file to edit",
+            ));
+        };
+        let editor = match LevelPack::load(path) {
+            Ok(pack) => Editor::from_level(&pack),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Editor::new(),
+            Err(e) => return Err(e),
+        };
+        self.editor = Some(editor);
+        self.state = GameState::Editing;
+        self.demo = None;
+        Ok(())
     }
-}
 
-/// Calculate how long to sleep to maintain frame rate.
-pub fn frame_sleep_duration(frame_start: Instant, target_frame_time: Duration) -> Option<Duration> {
-    let elapsed = frame_start.elapsed();
-    if elapsed < target_frame_time {
-        Some(target_frame_time - elapsed)
-    } else {
-        None
+    /// Play the edited level from the wave on screen. The game comes back to the
+    /// editor when it ends.
+    fn test_play(&mut self) {
+        match self.editor.as_ref().and_then(Editor::test_level) {
+            Some(level) => self.start_one_player(Some(level)),
+            None => self.show_notice("NO ROCKS TO PLAY".to_string()),
+        }
     }
-}
 
-/// Run the main game loop (real terminal I/O).
-#[cfg(not(tarpaulin_include))]
-pub fn run() -> io::Result<()> {
-    // Setup terminal
-    terminal::enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
-
-    // Enable Kitty keyboard protocol for press/release tracking.
-    // Without this, terminals only send key-repeat for one key at a time,
-    // so pressing spacebar interrupts arrow key repeat.
-    let enhanced_keyboard = terminal::supports_keyboard_enhancement().unwrap_or(false);
-    if enhanced_keyboard {
-        execute!(
-            stdout,
-            event::PushKeyboardEnhancementFlags(
-                event::KeyboardEnhancementFlags::REPORT_EVENT_TYPES
-            )
-        )?;
+    /// Leave a test game for the editor, saying how it went.
+    fn end_test_play(&mut self) {
+        let text = match self.playing.take() {
+            Some(playing) if playing.level_complete => "LEVEL COMPLETE".to_string(),
+            Some(playing) => format!("TEST ENDED ON WAVE {}", playing.wave),
+            None => "TEST ENDED".to_string(),
+        };
+        self.state = GameState::Editing;
+        self.rewind = None;
+        self.rewinding = false;
+        self.show_notice(text);
     }
 
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
-
-    let _size = terminal.size()?;
-    let world_width = 800.0_f64;
-    let world_height = 600.0_f64;
-
-    let mut game = Game::new(world_width, world_height);
-    let audio_engine = crate::audio::AudioEngine::try_new();
-    let mut input_state = InputState::default();
-    let mut fire_detector = FireEdgeDetector::new();
-    let mut accumulator = TimeAccumulator::new(TIMESTEP);
-    let target_frame_time = Duration::from_secs_f64(TIMESTEP);
+    /// Write the edited level to the level file. A level being played from the menu
+    /// is replaced by the saved one.
+    fn save_level(&mut self) {
+        let (Some(path), Some(editor)) = (self.level_path.clone(), self.editor.as_ref()) else {
+            return;
+        };
+        let Some(pack) = editor.level() else {
+            self.show_notice("NOTHING TO SAVE".to_string());
+            return;
+        };
+        match pack.save(&path) {
+            Ok(()) => {
+                self.show_notice(format!("SAVED {}", path.display()));
+                if self.level.is_some() {
+                    self.level = Some(pack);
+                }
+            }
+            Err(e) => self.show_notice(format!("SAVE FAILED: {}", e)),
+        }
+    }
 
-    let mut last_time = Instant::now();
+    /// Show a short message to the player.
+    fn show_notice(&mut self, text: String) {
+        self.notice = Some(text);
+        self.notice_timer = NOTICE_TIME;
+    }
 
-    // Hold counters for fallback input (terminals without keyboard enhancement).
-    // When a key press arrives, the counter resets to HOLD_FRAMES. Counters only
-    // decay when no key events are received (terminal is quiet), and freeze while
-    // other keys are generating events. This bridges the gap when spacebar steals
-    // the terminal's key repeat from arrow keys.
-    const HOLD_FRAMES: u8 = 20; // ~333ms at 60fps
-    let mut hold_left: u8 = 0;
-    let mut hold_right: u8 = 0;
-    let mut hold_thrust: u8 = 0;
+    /// Capture the game in progress for saving. Network games cannot be saved, since
+    /// the other player's game would not come back with it.
+    pub fn snapshot(&self) -> Option<SaveGame> {
+        let test_game = self.editor.is_some();
+        if self.state != GameState::Playing || self.lockstep.is_some() || test_game {
+            return None;
+        }
+        let playing = self.playing.clone()?;
+        let mut save = SaveGame::new(
+            self.world_width,
+            self.world_height,
+            playing,
+            self.turns.clone(),
+        );
+        save.assisted = self.assisted;
+        Some(save)
+    }
 
-    loop {
-        let frame_start = Instant::now();
-        let elapsed = last_time.elapsed().as_secs_f64();
-        last_time = frame_start;
+    /// Resume a saved game in the saved world size.
+    pub fn restore(&mut self, save: SaveGame) {
+        self.begin(save.playing.mode);
+        self.world_width = save.world_width;
+        self.world_height = save.world_height;
+        self.playing = Some(save.playing);
+        self.turns = save.turns;
+        self.last_input = InputState::default();
+        self.last_input_two = InputState::default();
+        self.assisted = save.assisted;
+        self.reset_rewind();
+    }
 
-        // Poll input
-        let mut raw_fire_pressed = false;
-        let mut any_key_event = false;
-        while event::poll(Duration::ZERO)? {
-            if let Event::Key(key) = event::read()? {
-                any_key_event = true;
-                match key.kind {
-                    KeyEventKind::Press | KeyEventKind::Repeat => {
-                        match game.state {
-                            GameState::Menu | GameState::GameOver => {
-                                if key.kind == KeyEventKind::Press && game.handle_key(key.code) {
-                                    // Quit — cleanup
-                                    if enhanced_keyboard {
-                                        let _ = execute!(
-                                            terminal.backend_mut(),
-                                            event::PopKeyboardEnhancementFlags
-                                        );
-                                    }
-                                    terminal::disable_raw_mode()?;
-                                    execute!(
-                                        terminal.backend_mut(),
-                                        terminal::LeaveAlternateScreen,
-                                        cursor::Show
-                                    )?;
-                                    return Ok(());
-                                }
-                            }
-                            GameState::Playing => {
-                                if let Some(action) = input::map_key(key.code) {
-                                    match action {
-                                        Action::RotateLeft => {
-                                            input_state.rotate_left = true;
-                                            hold_left = HOLD_FRAMES;
-                                        }
-                                        Action::RotateRight => {
-                                            input_state.rotate_right = true;
-                                            hold_right = HOLD_FRAMES;
-                                        }
-                                        Action::Thrust => {
-                                            input_state.thrust = true;
-                                            hold_thrust = HOLD_FRAMES;
-                                        }
-                                        Action::Fire => {
-                                            if key.kind == KeyEventKind::Press {
-                                                raw_fire_pressed = true;
-                                            }
-                                        }
-                                        Action::Quit => input_state.quit = true,
-                                    }
-                                }
-                            }
-                        }
-                    }
-                    KeyEventKind::Release => {
-                        // With enhanced keyboard, clear key state on release
-                        if game.state == GameState::Playing {
-                            if let Some(action) = input::map_key(key.code) {
-                                match action {
-                                    Action::RotateLeft => {
-                                        input_state.rotate_left = false;
-                                        hold_left = 0;
-                                    }
-                                    Action::RotateRight => {
-                                        input_state.rotate_right = false;
-                                        hold_right = 0;
-                                    }
-                                    Action::Thrust => {
-                                        input_state.thrust = false;
-                                        hold_thrust = 0;
-                                    }
-                                    Action::Quit => input_state.quit = false,
-                                    Action::Fire => {} // handled by edge detector
-                                }
-                            }
-                        }
-                    }
-                }
+    /// Save the game in progress to the save path. Returns true if it was saved.
+    pub fn quicksave(&mut self) -> bool {
+        let (Some(path), Some(save)) = (self.save_path.clone(), self.snapshot()) else {
+            return false;
+        };
+        match save.write(&path) {
+            Ok(()) => {
+                self.show_notice("GAME SAVED".to_string());
+                true
+            }
+            Err(e) => {
+                self.show_notice(format!("SAVE FAILED: {}", e));
+                false
             }
         }
+    }
 
-        // Handle fire edge detection
-        input_state.fire = fire_detector.update(raw_fire_pressed);
+    /// True if there is a quicksave to continue.
+    pub fn has_saved_game(&self) -> bool {
+        self.save_path.as_ref().is_some_and(|path| path.exists())
+    }
 
-        // Fixed timestep updates
-        if game.state == GameState::Playing {
-            let updates = accumulator.accumulate(elapsed);
-            for _ in 0..updates {
-                if let Some(ref mut playing) = game.playing {
-                    let result = playing.update(&input_state, TIMESTEP, world_width, world_height);
-                    for event in &result.audio_events {
-                        audio_engine.play(event);
-                    }
-                    if let Some(new_state) = result.state {
-                        match new_state {
-                            GameState::GameOver => game.game_over(),
-                            GameState::Menu => {
-                                game.state = GameState::Menu;
-                                game.playing = None;
-                                game.start_demo();
-                            }
-                            _ => {}
-                        }
-                        break;
-                    }
-                }
-            }
-        } else if game.state == GameState::Menu {
-            // Tick attract mode demo
-            let updates = accumulator.accumulate(elapsed);
-            let mut demo_over = false;
-            for _ in 0..updates {
-                if let Some(ref mut demo) = game.demo {
-                    let ai_input = demo_ai::generate_demo_input(
-                        &demo.ship,
-                        &demo.asteroids,
-                        world_width,
-                        world_height,
-                    );
-                    let demo_result = demo.update(&ai_input, TIMESTEP, world_width, world_height);
-                    if let Some(new_state) = demo_result.state {
-                        if new_state == GameState::GameOver || new_state == GameState::Menu {
-                            demo_over = true;
-                        }
-                        break;
-                    }
-                }
+    /// Continue from the quicksave. Returns true if the saved game was resumed; an
+    /// unreadable save leaves the menu up with a notice.
+    pub fn continue_saved(&mut self) -> bool {
+        let Some(path) = self.save_path.clone() else {
+            return false;
+        };
+        match SaveGame::read(&path) {
+            Ok(save) => {
+                self.restore(save);
+                true
             }
-            if demo_over {
-                game.start_demo();
+            Err(e) => {
+                self.show_notice(format!("CANNOT CONTINUE: {}", e));
+                false
             }
         }
+    }
+
+    /// Start a one-player game in the selected mode and difficulty.
+    pub fn start_game(&mut self) {
+        self.start_one_player(self.level.clone());
+    }
 
-        // Without keyboard enhancement, use hold counters to keep keys active
-        // across frames even when another key steals the terminal's key repeat.
-        // Counters only decay when the terminal is quiet (no events at all).
-        // While any key generates events, counters freeze — this bridges the gap
-        // when spacebar temporarily steals the repeat from arrow keys.
-        // With enhancement, Release events already handle clearing.
-        if !enhanced_keyboard {
-            if !any_key_event {
-                hold_left = hold_left.saturating_sub(1);
-                hold_right = hold_right.saturating_sub(1);
-                hold_thrust = hold_thrust.saturating_sub(1);
+    /// Start a one-player game in the selected mode and difficulty, playing `level`'s
+    /// waves if there is one.
+    fn start_one_player(&mut self, level: Option<LevelPack>) {
+        self.begin(self.mode);
+        let (w, h) = (self.world_width, self.world_height);
+        if let Some(ref mut playing) = self.playing {
+            playing.set_difficulty(self.difficulty, self.adaptive, w, h);
+            if let Some(level) = level {
+                playing.set_level(level, w, h);
             }
-            input_state.rotate_left = hold_left > 0;
-            input_state.rotate_right = hold_right > 0;
-            input_state.thrust = hold_thrust > 0;
-            input_state.quit = false;
         }
+    }
 
-        // Render
-        terminal.draw(|frame| {
-            let area = frame.area();
+    /// Read which achievements are unlocked from the achievements file, if there is one.
+    fn load_achievements(&mut self) {
+        let Some(ref path) = self.achievements_path else {
+            return;
+        };
+        match Achievements::load(path) {
+            Ok(achievements) => self.achievements = achievements,
+            Err(e) => self.show_notice(format!("CANNOT READ ACHIEVEMENTS: {}", e)),
+        }
+    }
 
-            match game.state {
-                GameState::Menu => {
-                    let cols = area.width as usize;
-                    let rows = area.height as usize;
-
-                    // Render demo game as background (attract mode)
-                    let mut lines: Vec<Line> = Vec::new();
-                    if let Some(ref demo) = game.demo {
-                        let mut buf = BrailleBuffer::new(cols, rows);
-                        // Draw asteroids
-                        for asteroid in &demo.asteroids {
-                            let verts = asteroid.world_vertices();
-                            buf.draw_polygon(&verts, world_width, world_height);
-                        }
-                        // Draw bullets
-                        for bullet in &demo.bullet_pool.bullets {
-                            if bullet.alive {
-                                let dot_x = (bullet.position.x / world_width
-                                    * buf.dot_width() as f64)
-                                    as i32;
-                                let dot_y = (bullet.position.y / world_height
-                                    * buf.dot_height() as f64)
-                                    as i32;
-                                buf.set_dot(dot_x, dot_y);
-                                buf.set_dot(dot_x + 1, dot_y);
-                                buf.set_dot(dot_x, dot_y + 1);
-                                buf.set_dot(dot_x + 1, dot_y + 1);
-                            }
-                        }
-                        // Draw ship
-                        let draw_ship = if demo.ship.invulnerable {
-                            renderer::ship_blink_visible(demo.frame_count)
-                        } else {
-                            true
-                        };
-                        if draw_ship && demo.ship.lives > 0 {
-                            let ship_verts = demo.ship.vertices();
-                            buf.draw_polygon(&ship_verts, world_width, world_height);
-                        }
-                        // Convert buffer to lines (no HUD for attract mode)
-                        for row in 0..buf.rows {
-                            let mut spans = String::new();
-                            for col in 0..buf.cols {
-                                spans.push(buf.get_char(col, row));
-                            }
-                            lines.push(Line::from(spans));
-                        }
-                    } else {
-                        // No demo — fill with empty lines
-                        for _ in 0..rows {
-                            lines.push(Line::from(""));
-                        }
-                    }
+    /// Start a fresh game under `mode`'s rules at normal difficulty. Two-player games
+    /// are always classic and normal.
+    fn begin(&mut self, mode: GameMode) {
+        self.state = GameState::Playing;
+        self.playing = Some(self.new_playing(mode));
+        self.demo = None;
+        self.turns = None;
+        self.final_scores = None;
+        self.final_frags = None;
+        self.net_error = None;
+        self.end_netplay();
+        self.assisted = false;
+        self.reset_rewind();
+        self.load_achievements();
+        self.achievements.new_game();
+    }
 
-                    // Overlay menu text at vertical center, preserving braille background
-                    let center = rows / 2;
-                    if center >= 2 && center + 2 < lines.len() {
-                        let overlay = |line: &Line, text: &str, style: Style| -> Line {
-                            let existing: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
-                            let text_chars: usize = text.chars().count();
-                            let remaining: String = existing.chars().skip(text_chars).collect();
-                            Line::from(vec![
-                                Span::styled(text.to_string(), style),
-                                Span::raw(remaining),
-                            ])
-                        };
-                        let white = Style::default().fg(Color::White);
-                        lines[center - 2] = overlay(&lines[center - 2], "    TUISTEROIDS", white);
-                        // center-1 left unchanged so braille background shows through
-                        lines[center] = overlay(&lines[center], "    Press any key to start", Style::default());
-                        lines[center + 1] = overlay(&lines[center + 1], "    Press Q to quit", Style::default());
-                    }
+    /// A fresh player's game under `mode` and the extra life rule set here.
+    fn new_playing(&self, mode: GameMode) -> PlayingState {
+        let mut playing = PlayingState::new(self.world_width, self.world_height);
+        playing.set_mode(mode);
+        playing.extra_lives = self.extra_lives;
+        playing
+    }
 
-                    let paragraph = Paragraph::new(lines).block(Block::default());
-                    frame.render_widget(paragraph, area);
-                }
-                GameState::GameOver => {
-                    let text = vec![
-                        Line::from(""),
-                        Line::from(""),
-                        Line::from(Span::styled(
-                            "    GAME OVER",
-                            Style::default().fg(Color::Red),
-                        )),
-                        Line::from(""),
-                        Line::from(format!("    Score: {}", game.final_score)),
-                        Line::from(""),
-                        Line::from("    Press any key to restart or Q to quit"),
-                    ];
-                    let paragraph = Paragraph::new(text).block(Block::default());
-                    frame.render_widget(paragraph, area);
-                }
-                GameState::Playing => {
-                    if let Some(ref playing) = game.playing {
-                        let cols = area.width as usize;
-                        let rows = area.height as usize;
-                        let mut buf = BrailleBuffer::new(cols, rows.saturating_sub(1));
-
-                        // Draw asteroids
-                        for asteroid in &playing.asteroids {
-                            let verts = asteroid.world_vertices();
-                            buf.draw_polygon(&verts, world_width, world_height);
-                        }
+    /// Start a fresh rewind history if the mode allows it: one-player and co-op games
+    /// on this machine. Versus, alternating turns and network games are competitive.
+    fn reset_rewind(&mut self) {
+        let allowed = self.lockstep.is_none()
+            && self.turns.is_none()
+            && self.playing.as_ref().is_some_and(|playing| {
+                playing
+                    .player_two
+                    .as_ref()
+                    .is_none_or(|two| two.mode != Multiplayer::Versus)
+            });
+        self.rewind = allowed.then(Rewind::default);
+        self.rewinding = false;
+    }
 
-                        // Draw bullets
-                        for bullet in &playing.bullet_pool.bullets {
-                            if bullet.alive {
-                                let dot_x = (bullet.position.x / world_width
-                                    * buf.dot_width() as f64)
-                                    as i32;
-                                let dot_y = (bullet.position.y / world_height
-                                    * buf.dot_height() as f64)
-                                    as i32;
-                                buf.set_dot(dot_x, dot_y);
-                                buf.set_dot(dot_x + 1, dot_y);
-                                buf.set_dot(dot_x, dot_y + 1);
-                                buf.set_dot(dot_x + 1, dot_y + 1);
-                            }
-                        }
+    /// Start a simultaneous two-ship game: player 1 on the arrow keys, player 2 on WASD.
+    pub fn start_simultaneous(&mut self, mode: Multiplayer) {
+        self.begin(GameMode::Classic);
+        if let Some(ref mut playing) = self.playing {
+            playing.add_player_two(mode, self.world_width, self.world_height);
+        }
+        self.reset_rewind();
+    }
 
-                        // Draw ship
-                        let draw_ship = if playing.ship.invulnerable {
-                            renderer::ship_blink_visible(playing.frame_count)
-                        } else {
-                            true
-                        };
-                        if draw_ship && playing.ship.lives > 0 {
-                            let ship_verts = playing.ship.vertices();
-                            buf.draw_polygon(&ship_verts, world_width, world_height);
-
-                            // Draw thrust flame if thrusting
-                            if input_state.thrust {
-                                let flame = renderer::thrust_flame_vertices(
-                                    playing.ship.position,
-                                    playing.ship.rotation,
-                                );
-                                buf.draw_polygon(&flame, world_width, world_height);
-                            }
-                        }
+    /// Start a network game. Both peers simulate the same seeded two-ship game; the local
+    /// controller flies this peer's ship and the other ship follows the remote inputs.
+    pub fn start_netplay(&mut self, lockstep: Lockstep) {
+        self.begin(GameMode::Classic);
+        self.playing = Some(lockstep.initial_state());
+        self.lockstep = Some(lockstep);
+        self.reset_rewind();
+    }
 
-                        // Convert buffer to lines
-                        let mut lines: Vec<Line> = Vec::new();
-                        for row in 0..buf.rows {
-                            let mut spans = String::new();
-                            for col in 0..buf.cols {
-                                spans.push(buf.get_char(col, row));
-                            }
-                            lines.push(Line::from(spans));
-                        }
+    /// Say goodbye to the remote player, if there is one.
+    fn end_netplay(&mut self) {
+        if let Some(mut lockstep) = self.lockstep.take() {
+            lockstep.close();
+        }
+    }
 
-                        // HUD line
-                        let lives_str = "▲ ".repeat(playing.ship.lives as usize);
-                        let hud_line = format!("Score: {}  {}", playing.score, lives_str);
-                        lines.push(Line::from(Span::styled(
-                            hud_line,
-                            Style::default().fg(Color::White),
-                        )));
+    /// Start a two-player game with alternating turns. Each player gets their own
+    /// PlayingState; player 1 goes first.
+    pub fn start_two_player(&mut self) {
+        self.begin(GameMode::Classic);
+        self.turns = Some(Turns::new(self.new_playing(GameMode::Classic)));
+        self.reset_rewind();
+    }
 
-                        let paragraph = Paragraph::new(lines);
-                        frame.render_widget(paragraph, area);
+    /// Transition to game over, keeping the results and adding them to the high scores
+    /// and lifetime stats.
+    pub fn game_over(&mut self) {
+        if let Some(ref playing) = self.playing {
+            self.final_score = playing.score;
+            self.final_mode = playing.mode;
+            self.final_deaths = playing.mode.infinite_lives().then_some(playing.deaths);
+            self.final_level = playing.level.as_ref().map(|level| {
+                if playing.level_complete {
+                    format!("{}: complete!", level.name)
+                } else {
+                    format!(
+                        "{}: wave {} of {}",
+                        level.name,
+                        playing.wave,
+                        level.waves.len()
+                    )
+                }
+            });
+            self.final_stats = vec![playing.stats.clone()];
+            if let Some(ref turns) = self.turns {
+                self.final_stats = turns.stats(playing).to_vec();
+            }
+            self.final_scores = self.turns.take().map(|turns| turns.scores(playing));
+            if let Some(ref two) = playing.player_two {
+                self.final_scores = Some([playing.score, two.score]);
+                self.final_frags = (two.mode == Multiplayer::Versus).then_some(two.frags);
+                self.final_stats.push(two.stats.clone());
+            }
+            // Rewound and level games are not comparable with normal play
+            let ranked = !self.assisted && playing.level.is_none();
+            if let Some(board) = self.high_scores.as_ref().filter(|_| ranked) {
+                let mut board = board.lock().unwrap();
+                match self.final_scores {
+                    Some(scores) => {
+                        for (i, score) in scores.into_iter().enumerate() {
+                            let name = format!("{} P{}", self.player_name, i + 1);
+                            board.submit(playing.mode, &name, score);
+                        }
+                    }
+                    None => {
+                        board.submit(playing.mode, &self.player_name, self.final_score);
                     }
                 }
             }
-        })?;
-
-        // Frame rate limiting
-        if let Some(sleep_time) = frame_sleep_duration(frame_start, target_frame_time) {
-            std::thread::sleep(sleep_time);
         }
+        if self.playing.is_some() {
+            self.record_stats();
+        }
+        self.state = GameState::GameOver;
+        self.playing = None;
+        self.turns = None;
+        self.end_netplay();
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::physics::Vec2;
-    use std::time::{Duration, Instant};
 
-    // === Requirement: Fixed Timestep Game Loop ===
-
-    // Scenario: Single update per frame at 60 FPS
-    #[test]
-    fn test_single_update_at_60fps() {
-        let mut acc = TimeAccumulator::new(TIMESTEP);
-        let updates = acc.accumulate(TIMESTEP);
-        assert_eq!(updates, 1);
+    /// Add the last game to the lifetime stats file: every player on this machine, or
+    /// just the local one in a network game.
+    fn record_stats(&mut self) {
+        let Some(ref path) = self.stats_path else {
+            return;
+        };
+        let result = LifetimeStats::load(path).and_then(|mut lifetime| {
+            let local = self.lockstep.as_ref().map(Lockstep::local_player);
+            for (p, stats) in self.final_stats.iter().enumerate() {
+                if local.is_none_or(|local| local == p) {
+                    lifetime.record(stats);
+                }
+            }
+            lifetime.write(path)?;
+            self.lifetime = lifetime;
+            Ok(())
+        });
+        if let Err(e) = result {
+            self.show_notice(format!("CANNOT SAVE STATS: {}", e));
+        }
     }
 
-    // Scenario: Multiple updates when frame is slow
-    #[test]
-    fn test_multiple_updates_slow_frame() {
-        let mut acc = TimeAccumulator::new(TIMESTEP);
-        let updates = acc.accumulate(0.050); // 50ms ~ 3 timesteps
-        assert_eq!(updates, 3);
+    /// Toast each newly unlocked achievement and add it to the achievements file.
+    fn celebrate(&mut self, unlocked: &[&Achievement]) {
+        let Some(ref path) = self.achievements_path else {
+            return;
+        };
+        if unlocked.is_empty() {
+            return;
+        }
+        let result = Achievements::load(path).and_then(|mut saved| {
+            saved.unlocked.extend(
+                unlocked
+                    .iter()
+                    .map(|achievement| achievement.id.to_string()),
+            );
+            saved.write(path)
+        });
+        if self.toasts.is_empty() {
+            self.toast_timer = achievements::TOAST_TIME;
+        }
+        self.toasts.extend(
+            unlocked
+                .iter()
+                .map(|achievement| achievements::toast(achievement)),
+        );
+        if let Err(e) = result {
+            self.show_notice(format!("CANNOT SAVE ACHIEVEMENTS: {}", e));
+        }
     }
 
-    // Scenario: No update when insufficient time elapsed
-    #[test]
-    fn test_no_update_insufficient_time() {
-        let mut acc = TimeAccumulator::new(TIMESTEP);
-        let updates = acc.accumulate(0.005); // 5ms < 16.67ms
-        assert_eq!(updates, 0);
+    /// Show the lifetime stats from the stats file.
+    fn open_stats(&mut self) {
+        let Some(ref path) = self.stats_path else {
+            return;
+        };
+        match LifetimeStats::load(path) {
+            Ok(lifetime) => {
+                self.lifetime = lifetime;
+                self.state = GameState::Stats;
+            }
+            Err(e) => self.show_notice(format!("CANNOT READ STATS: {}", e)),
+        }
+        self.load_achievements();
     }
 
-    // Scenario: Accumulator retains remainder
-    #[test]
-    fn test_accumulator_retains_remainder() {
-        let mut acc = TimeAccumulator::new(TIMESTEP);
-        let updates = acc.accumulate(0.040); // 40ms / 16.67ms = 2.4
-        assert_eq!(updates, 2);
-        assert!(acc.accumulated > 0.0);
-        assert!(acc.accumulated < TIMESTEP);
+    /// Capture what the screen should show this frame.
+    pub fn scene(&self) -> Scene {
+        let (w, h) = (self.world_width, self.world_height);
+        match self.state {
+            GameState::Menu => Scene::Menu {
+                demo: self.demo.as_ref().map(|demo| {
+                    let idle = InputState::default();
+                    playfield(demo, [&idle, &idle], w, h)
+                }),
+                friendly_fire: self.friendly_fire,
+                mode: self.mode,
+                difficulty: self.difficulty,
+                adaptive: self.adaptive,
+                controls: self.controls,
+                saved_game: self.has_saved_game(),
+                editor: self.level_path.is_some(),
+                stats: self.stats_path.is_some(),
+                notice: self.notice.clone(),
+            },
+            GameState::Playing => {
+                let Some(ref playing) = self.playing else {
+                    return Scene::Playing {
+                        playfield: Playfield::new(w, h),
+                        banner: None,
+                        hud: String::new(),
+                        tally: Vec::new(),
+                        toast: None,
+                    };
+                };
+                let hud = match self.turns {
+                    Some(ref turns) => {
+                        let players = turns
+                            .players(playing)
+                            .map(|(score, lives)| renderer::HudInfo { score, lives });
+                        renderer::two_player_hud(Some(turns.current()), players, None)
+                    }
+                    None => match playing.player_two {
+                        Some(ref two) => {
+                            let players = [(playing.score, &playing.ship), (two.score, &two.ship)]
+                                .map(|(score, ship)| renderer::HudInfo {
+                                    score,
+                                    lives: ship.lives,
+                                });
+                            let frags = (two.mode == Multiplayer::Versus).then_some(two.frags);
+                            renderer::two_player_hud(None, players, frags)
+                        }
+                        None => one_player_hud(playing),
+                    },
+                };
+                let hud = if self.assisted {
+                    format!("{} ASSISTED", hud)
+                } else {
+                    hud
+                };
+                let rewinding = self.rewinding && self.rewind.is_some();
+                Scene::Playing {
+                    playfield: playfield(playing, [&self.last_input, &self.last_input_two], w, h),
+                    banner: self
+                        .turns
+                        .as_ref()
+                        .and_then(|t| t.banner())
+                        .or_else(|| rewinding.then(|| "<< REWIND".to_string()))
+                        .or_else(|| self.notice.clone()),
+                    hud,
+                    tally: scoring::tally_lines(&playing.tally),
+                    toast: self.toasts.first().cloned(),
+                }
+            }
+            GameState::GameOver => Scene::GameOver {
+                results: match (self.final_frags, self.final_scores) {
+                    (Some(frags), Some(scores)) => renderer::versus_results(frags, scores),
+                    (None, Some(scores)) => renderer::two_player_results(scores),
+                    _ => std::iter::once(format!("Score: {}", self.final_score))
+                        .chain(
+                            self.final_deaths
+                                .map(|deaths| format!("Deaths: {}", deaths)),
+                        )
+                        .chain(self.final_level.clone())
+                        .collect(),
+                },
+                note: self
+                    .net_error
+                    .as_ref()
+                    .map(|error| format!("Network game ended: {}", error))
+                    .or_else(|| {
+                        self.assisted.then(|| {
+                            "Assisted: rewind was used, so the score is not ranked".to_string()
+                        })
+                    }),
+                high_scores: self
+                    .high_scores
+                    .as_ref()
+                    .map(|board| board.lock().unwrap().lines(self.final_mode))
+                    .unwrap_or_default(),
+                mode: self.final_mode,
+                summary: stats::summary_lines(&self.final_stats),
+            },
+            GameState::Stats => {
+                let mut lines = self.lifetime.lines();
+                if self.achievements_path.is_some() {
+                    lines.push(String::new());
+                    lines.extend(self.achievements.lines());
+                }
+                Scene::Stats { lines }
+            }
+            GameState::Editing => {
+                let Some(ref editor) = self.editor else {
+                    return Scene::Editor {
+                        playfield: Playfield::new(WORLD_WIDTH, WORLD_HEIGHT),
+                        help: editor::HELP.to_string(),
+                        status: String::new(),
+                    };
+                };
+                let status = match self.notice {
+                    Some(ref notice) => format!("{}  {}", editor.status(), notice),
+                    None => editor.status(),
+                };
+                Scene::Editor {
+                    playfield: editor.playfield(),
+                    help: editor::HELP.to_string(),
+                    status,
+                }
+            }
+        }
     }
 
-    // === Requirement: Update-Render Separation ===
-
-    // Scenario: Render happens after updates
-    #[test]
-    fn test_update_then_render_order() {
-        // The accumulator controls how many updates happen, then render is called once.
-        let mut acc = TimeAccumulator::new(TIMESTEP);
-        let updates = acc.accumulate(0.040);
-        assert_eq!(updates, 2);
-        // After consuming updates, render would be called once (game loop structure)
-        // This test verifies the accumulator correctly returns update count.
+    /// Start a new demo (attract mode) game.
+    pub fn start_demo(&mut self) {
+        self.demo = Some(PlayingState::new(self.world_width, self.world_height));
     }
+}
 
-    // Scenario: Render happens even with zero updates
-    #[test]
-    fn test_render_with_zero_updates() {
-        let mut acc = TimeAccumulator::new(TIMESTEP);
-        let updates = acc.accumulate(0.005);
-        assert_eq!(updates, 0);
-        // Game loop still renders once after this — verified by structure
+/// HUD line for one player: score, then lives, survival deaths or the time attack
+/// countdown. Zen ships cannot be lost, so only the score shows. Level games add the
+/// wave and its time left. The shield's energy bar shows while it is up or recharging,
+/// and running power-ups show with their seconds left.
+fn one_player_hud(playing: &PlayingState) -> String {
+    let status = match playing.mode {
+        GameMode::Survival => Some(format!("Deaths: {}", playing.deaths)),
+        GameMode::Zen => None,
+        _ => Some("▲ ".repeat(playing.ship.lives as usize)),
+    };
+    let mut hud = match (status, playing.time_left()) {
+        (None, _) => format!("Score: {}", playing.score),
+        (Some(status), Some(left)) => {
+            format!("Score: {}  {} {}", playing.score, status, countdown(left))
+        }
+        (Some(status), None) => format!("Score: {}  {}", playing.score, status),
+    };
+    if let Some(ref level) = playing.level {
+        hud.push_str(&format!("  Wave {}/{}", playing.wave, level.waves.len()));
+        if let Some(left) = playing.wave_time_left() {
+            hud.push_str(&format!(" {}", countdown(left)));
+        }
     }
+    let ship = &playing.ship;
+    if ship.shield_up || ship.shield_energy < crate::ship::SHIELD_MAX_ENERGY {
+        let fraction = ship.shield_energy / crate::ship::SHIELD_MAX_ENERGY;
+        hud.push_str(&format!("  Shield {}", renderer::energy_bar(fraction)));
+    }
+    let power_ups = pickups::active_label(&playing.ship, &playing.bullet_pool);
+    if !power_ups.is_empty() {
+        hud.push_str(&format!("  {}", power_ups));
+    }
+    hud
+}
 
-    // === Requirement: Game State Machine ===
+/// Seconds left as m:ss, rounded up.
+fn countdown(left: f64) -> String {
+    let seconds = left.ceil() as u32;
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
 
-    // Scenario: Game starts in Menu state
-    #[test]
-    fn test_game_starts_in_menu() {
-        let game = Game::new(800.0, 600.0);
-        assert_eq!(game.state, GameState::Menu);
+/// Outlines and bullets of a game, as drawn on screen. Invulnerable ships blink, ships
+/// with no lives are hidden, and a thrust flame shows behind each thrusting ship.
+/// Raised shields show as a bubble. Pickups about to expire blink, as do shield
+/// power-ups about to run out. Score popups float up where rocks were destroyed.
+fn playfield(state: &PlayingState, inputs: [&InputState; 2], w: f64, h: f64) -> Playfield {
+    let mut playfield = Playfield::new(w, h);
+    let blink = renderer::ship_blink_visible(state.frame_count);
+    entity::render_all(&state.asteroids, &mut playfield, blink);
+    entity::render_all(&state.pickups, &mut playfield, blink);
+    entity::render_all(&state.popups, &mut playfield, blink);
+    let mut pilots = vec![(&state.ship, &state.bullet_pool, inputs[0])];
+    if let Some(ref two) = state.player_two {
+        pilots.push((&two.ship, &two.bullet_pool, inputs[1]));
+    }
+    for (ship, bullet_pool, input) in pilots {
+        entity::render_all(&bullet_pool.bullets, &mut playfield, blink);
+        ship.render(&mut playfield, blink);
+        if input.thrust && ship.visible(blink) {
+            playfield.add_polygon(&renderer::thrust_flame_vertices(
+                ship.position,
+                ship.rotation,
+            ));
+        }
     }
+    playfield
+}
 
-    // Scenario: Menu transitions to Playing on key press
-    #[test]
-    fn test_menu_to_playing() {
-        let mut game = Game::new(800.0, 600.0);
-        let quit = game.handle_key(KeyCode::Enter);
-        assert!(!quit);
-        assert_eq!(game.state, GameState::Playing);
-        assert!(game.playing.is_some());
+/// Turns key events into each player's input and advances a game frame by frame.
+/// Shared by the terminal run loop and server sessions.
+#[derive(Debug)]
+pub struct FrameDriver {
+    keys: PlayerKeys,
+    keys_two: PlayerKeys,
+    accumulator: TimeAccumulator,
+    any_key_event: bool,
+    /// Frames the rewind key stays held without enhancement, like `PlayerKeys`.
+    hold_rewind: u8,
+    /// Player 1's mouse while playing with mouse controls.
+    mouse: MouseAim,
+}
+
+impl Default for FrameDriver {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    // Scenario: Playing transitions to GameOver on last life lost
-    #[test]
-    fn test_playing_to_game_over() {
-        let mut game = Game::new(800.0, 600.0);
-        game.state = GameState::Playing;
-        game.playing = Some(PlayingState::new_seeded(800.0, 600.0, 42));
-        if let Some(ref mut playing) = game.playing {
-            playing.score = 1234;
+impl FrameDriver {
+    pub fn new() -> Self {
+        Self {
+            keys: PlayerKeys::new(),
+            keys_two: PlayerKeys::new(),
+            accumulator: TimeAccumulator::new(TIMESTEP),
+            any_key_event: false,
+            hold_rewind: 0,
+            mouse: MouseAim::default(),
         }
-        game.game_over();
-        assert_eq!(game.state, GameState::GameOver);
-        assert_eq!(game.final_score, 1234);
-        assert!(game.playing.is_none());
     }
 
-    // Scenario: Playing transitions to Menu on quit
-    #[test]
+    /// Handle a key press or repeat. Menu screens and the editor only react to initial
+    /// presses; during play arrows go to player 1, WASD to player 2, Backspace rewinds
+    /// while held and F5 quicksaves. Returns true if the game should quit.
+    pub fn press(&mut self, game: &mut Game, code: KeyCode, initial_press: bool) -> bool {
+        self.any_key_event = true;
+        match game.state {
+            GameState::Menu | GameState::GameOver | GameState::Editing | GameState::Stats => {
+                initial_press && game.handle_key(code)
+            }
+            GameState::Playing => {
+                if code == QUICKSAVE_KEY {
+                    if initial_press {
+                        game.quicksave();
+                    }
+                } else if code == REWIND_KEY {
+                    self.hold_rewind = input::HOLD_FRAMES;
+                } else if let Some(action) = input::map_key(code) {
+                    self.keys.press(action, initial_press);
+                } else if let Some(action) = input::map_key_player_two(code) {
+                    self.keys_two.press(action, initial_press);
+                }
+                false
+            }
+        }
+    }
+
+    /// Handle a key release (only sent with keyboard enhancement).
+    pub fn release(&mut self, game: &Game, code: KeyCode) {
+        self.any_key_event = true;
+        if game.state == GameState::Playing {
+            if code == REWIND_KEY {
+                self.hold_rewind = 0;
+            } else if let Some(action) = input::map_key(code) {
+                self.keys.release(action);
+            } else if let Some(action) = input::map_key_player_two(code) {
+                self.keys_two.release(action);
+            }
+        }
+    }
+
+    /// Handle a mouse event on a `cols` by `rows` terminal. The editor takes presses and
+    /// drags; playing with mouse controls, the cursor aims player 1's ship, a left-click
+    /// fires like the fire key and the right button thrusts while held.
+    pub fn mouse(&mut self, game: &mut Game, event: &MouseEvent, cols: u16, rows: u16) {
+        match game.state {
+            GameState::Editing => {
+                if let Some(pointer) = editor::pointer_from_mouse(event, cols, rows) {
+                    game.pointer(pointer);
+                }
+            }
+            GameState::Playing if game.controls == ControlScheme::Mouse => {
+                let (w, h) = (game.world_width, game.world_height);
+                if self.mouse.handle(event, cols, rows, w, h) {
+                    self.keys.press(Action::Fire, true);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Run the fixed timesteps due after `elapsed` seconds and return their audio events.
+    pub fn frame(
+        &mut self,
+        game: &mut Game,
+        elapsed: f64,
+        enhanced_keyboard: bool,
+    ) -> Vec<AudioEvent> {
+        // Handle fire edge detection
+        self.keys.latch_fire();
+        self.keys_two.latch_fire();
+
+        // Fixed timestep updates — the game's controllers decide where input comes from
+        game.feed_keyboard(&self.keys.state);
+        game.feed_keyboard_two(&self.keys_two.state);
+        if game.state != GameState::Playing {
+            // Buttons released off the playing screen go unseen
+            self.mouse = MouseAim::default();
+        }
+        game.feed_mouse(&self.mouse);
+        game.rewinding = self.hold_rewind > 0;
+        let mut audio_events = Vec::new();
+        if game.state != GameState::GameOver {
+            let updates = self.accumulator.accumulate(elapsed);
+            for _ in 0..updates {
+                let result = game.tick(TIMESTEP);
+                audio_events.extend(result.audio_events());
+                if result.state.is_some() {
+                    break;
+                }
+            }
+        }
+
+        // Without keyboard enhancement, hold counters keep keys active across frames
+        // even when another key steals the terminal's key repeat.
+        self.keys.decay(enhanced_keyboard, self.any_key_event);
+        self.keys_two.decay(enhanced_keyboard, self.any_key_event);
+        if !enhanced_keyboard && !self.any_key_event {
+            self.hold_rewind = self.hold_rewind.saturating_sub(1);
+        }
+        self.any_key_event = false;
+        audio_events
+    }
+}
+
+/// Calculate how long to sleep to maintain frame rate.
+pub fn frame_sleep_duration(frame_start: Instant, target_frame_time: Duration) -> Option<Duration> {
+    let elapsed = frame_start.elapsed();
+    if elapsed < target_frame_time {
+        Some(target_frame_time - elapsed)
+    } else {
+        None
+    }
+}
+
+/// Run the main game loop (real terminal I/O) for an already set-up game, optionally
+/// streaming every frame to spectators.
+#[cfg(not(tarpaulin_include))]
+pub fn run_game(mut game: Game, broadcaster: Option<Broadcaster>) -> io::Result<()> {
+    // Setup terminal
+    terminal::enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+
+    // Enable Kitty keyboard protocol for press/release tracking.
+    // Without this, terminals only send key-repeat for one key at a time,
+    // so pressing spacebar interrupts arrow key repeat.
+    let enhanced_keyboard = terminal::supports_keyboard_enhancement().unwrap_or(false);
+    if enhanced_keyboard {
+        execute!(
+            stdout,
+            event::PushKeyboardEnhancementFlags(
+                event::KeyboardEnhancementFlags::REPORT_EVENT_TYPES
+            )
+        )?;
+    }
+
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let _size = terminal.size()?;
+
+    let audio_engine = crate::audio::AudioEngine::try_new();
+    let mut driver = FrameDriver::new();
+    let target_frame_time = Duration::from_secs_f64(TIMESTEP);
+
+    let mut last_time = Instant::now();
+    let mut mouse_captured = false;
+
+    loop {
+        let frame_start = Instant::now();
+        let elapsed = last_time.elapsed().as_secs_f64();
+        last_time = frame_start;
+
+        // Only the editor and mouse controls take the mouse, so the terminal can
+        // select text otherwise
+        let wants_mouse = game.wants_mouse();
+        if wants_mouse != mouse_captured {
+            if wants_mouse {
+                execute!(terminal.backend_mut(), event::EnableMouseCapture)?;
+            } else {
+                execute!(terminal.backend_mut(), event::DisableMouseCapture)?;
+            }
+            mouse_captured = wants_mouse;
+        }
+
+        // Poll input
+        while event::poll(Duration::ZERO)? {
+            let key = match event::read()? {
+                Event::Key(key) => key,
+                Event::Mouse(mouse) => {
+                    let size = terminal.size()?;
+                    driver.mouse(&mut game, &mouse, size.width, size.height);
+                    continue;
+                }
+                _ => continue,
+            };
+            let quit = match key.kind {
+                KeyEventKind::Press | KeyEventKind::Repeat => {
+                    driver.press(&mut game, key.code, key.kind == KeyEventKind::Press)
+                }
+                KeyEventKind::Release => {
+                    driver.release(&game, key.code);
+                    false
+                }
+            };
+            if quit {
+                // Quit — cleanup
+                if enhanced_keyboard {
+                    let _ = execute!(terminal.backend_mut(), event::PopKeyboardEnhancementFlags);
+                }
+                if mouse_captured {
+                    execute!(terminal.backend_mut(), event::DisableMouseCapture)?;
+                }
+                terminal::disable_raw_mode()?;
+                execute!(
+                    terminal.backend_mut(),
+                    terminal::LeaveAlternateScreen,
+                    cursor::Show
+                )?;
+                return Ok(());
+            }
+        }
+
+        for event in driver.frame(&mut game, elapsed, enhanced_keyboard) {
+            audio_engine.play(&event);
+        }
+
+        // Render, and stream the same frame to any spectators
+        let scene = game.scene();
+        if let Some(ref broadcaster) = broadcaster {
+            broadcaster.publish(&scene);
+        }
+        terminal.draw(|frame| {
+            let area = frame.area();
+            let lines = renderer::scene_lines(&scene, area.width as usize, area.height as usize);
+            frame.render_widget(Paragraph::new(lines).block(Block::default()), area);
+        })?;
+
+        // Frame rate limiting
+        if let Some(sleep_time) = frame_sleep_duration(frame_start, target_frame_time) {
+            std::thread::sleep(sleep_time);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::highscore::ModeHighScores;
+    use crate::physics::Vec2;
+    use std::time::{Duration, Instant};
+
+    // === Requirement: Fixed Timestep Game Loop ===
+
+    // Scenario: Single update per frame at 60 FPS
+    #[test]
+    fn test_single_update_at_60fps() {
+        let mut acc = TimeAccumulator::new(TIMESTEP);
+        let updates = acc.accumulate(TIMESTEP);
+        assert_eq!(updates, 1);
+    }
+
+    // Scenario: Multiple updates when frame is slow
+    #[test]
+    fn test_multiple_updates_slow_frame() {
+        let mut acc = TimeAccumulator::new(TIMESTEP);
+        let updates = acc.accumulate(0.050); // 50ms ~ 3 timesteps
+        assert_eq!(updates, 3);
+    }
+
+    // Scenario: No update when insufficient time elapsed
+    #[test]
+    fn test_no_update_insufficient_time() {
+        let mut acc = TimeAccumulator::new(TIMESTEP);
+        let updates = acc.accumulate(0.005); // 5ms < 16.67ms
+        assert_eq!(updates, 0);
+    }
+
+    // Scenario: Accumulator retains remainder
+    #[test]
+    fn test_accumulator_retains_remainder() {
+        let mut acc = TimeAccumulator::new(TIMESTEP);
+        let updates = acc.accumulate(0.040); // 40ms / 16.67ms = 2.4
+        assert_eq!(updates, 2);
+        assert!(acc.accumulated > 0.0);
+        assert!(acc.accumulated < TIMESTEP);
+    }
+
+    // === Requirement: Update-Render Separation ===
+
+    // Scenario: Render happens after updates
+    #[test]
+    fn test_update_then_render_order() {
+        // The accumulator controls how many updates happen, then render is called once.
+        let mut acc = TimeAccumulator::new(TIMESTEP);
+        let updates = acc.accumulate(0.040);
+        assert_eq!(updates, 2);
+        // After consuming updates, render would be called once (game loop structure)
+        // This test verifies the accumulator correctly returns update count.
+    }
+
+    // Scenario: Render happens even with zero updates
+    #[test]
+    fn test_render_with_zero_updates() {
+        let mut acc = TimeAccumulator::new(TIMESTEP);
+        let updates = acc.accumulate(0.005);
+        assert_eq!(updates, 0);
+        // Game loop still renders once after this — verified by structure
+    }
+
+    // === Requirement: Game State Machine ===
+
+    // Scenario: Game starts in Menu state
+    #[test]
+    fn test_game_starts_in_menu() {
+        let game = Game::new(800.0, 600.0);
+        assert_eq!(game.state, GameState::Menu);
+    }
+
+    // Scenario: Menu transitions to Playing on key press
+    #[test]
+    fn test_menu_to_playing() {
+        let mut game = Game::new(800.0, 600.0);
+        let quit = game.handle_key(KeyCode::Enter);
+        assert!(!quit);
+        assert_eq!(game.state, GameState::Playing);
+        assert!(game.playing.is_some());
+    }
+
+    // Scenario: Playing transitions to GameOver on last life lost
+    #[test]
+    fn test_playing_to_game_over() {
+        let mut game = Game::new(800.0, 600.0);
+        game.state = GameState::Playing;
+        game.playing = Some(PlayingState::new_seeded(800.0, 600.0, 42));
+        if let Some(ref mut playing) = game.playing {
+            playing.score = 1234;
+        }
+        game.game_over();
+        assert_eq!(game.state, GameState::GameOver);
+        assert_eq!(game.final_score, 1234);
+        assert!(game.playing.is_none());
+    }
+
+    // Scenario: Playing transitions to Menu on quit
+    #[test]
     fn test_playing_quit_transitions() {
         let mut playing = PlayingState::new_seeded(800.0, 600.0, 42);
         let input = InputState {
@@ -908,6 +2258,8 @@ mod tests {
                 velocity: Vec2::new(0.0, 0.0),
                 distance_traveled: 0.0,
                 alive: true,
+                piercing: false,
+                pierce_cooldown: 0.0,
             });
         }
         let initial_score = playing.score;
@@ -942,7 +2294,7 @@ mod tests {
     // Scenario: Terminal restored on exit
     #[test]
     fn test_terminal_restore_concept() {
-        // Terminal restore happens in the run() function with enable/disable raw mode.
+        // Terminal restore happens in run_game() with enable/disable raw mode.
         // We verify the frame_sleep_duration helper works correctly as part of the loop.
         let start = Instant::now();
         let target = Duration::from_millis(16);
@@ -1006,6 +2358,8 @@ mod tests {
             velocity: Vec2::new(0.0, 0.0),
             distance_traveled: 0.0,
             alive: true,
+            piercing: false,
+            pierce_cooldown: 0.0,
         });
         let input = InputState::default();
         playing.update(&input, TIMESTEP, 800.0, 600.0);
@@ -1029,10 +2383,12 @@ mod tests {
             velocity: Vec2::new(0.0, 0.0),
             distance_traveled: 0.0,
             alive: true,
+            piercing: false,
+            pierce_cooldown: 0.0,
         });
         let input = InputState::default();
         playing.update(&input, TIMESTEP, 800.0, 600.0);
-        assert_eq!(playing.score, 100);
+        assert_eq!(playing.score - playing.tally[0].total(), 100);
         assert!(playing.asteroids.is_empty());
     }
 
@@ -1127,8 +2483,13 @@ mod tests {
         let mut game = Game::new(800.0, 600.0);
         let demo = game.demo.as_mut().unwrap();
         let initial_frame = demo.frame_count;
-        let ai_input =
-            crate::demo_ai::generate_demo_input(&demo.ship, &demo.asteroids, 800.0, 600.0);
+        let ai_input = crate::demo_ai::generate_demo_input(
+            &demo.ship,
+            &demo.asteroids,
+            &demo.bullet_pool,
+            800.0,
+            600.0,
+        );
         demo.update(&ai_input, TIMESTEP, 800.0, 600.0);
         assert_eq!(demo.frame_count, initial_frame + 1);
     }
@@ -1218,7 +2579,7 @@ mod tests {
         };
         let result = playing.update(&input, TIMESTEP, 800.0, 600.0);
         assert!(result
-            .audio_events
+            .audio_events()
             .contains(&crate::audio::AudioEvent::Fire));
     }
 
@@ -1232,7 +2593,7 @@ mod tests {
         };
         let result = playing.update(&input, TIMESTEP, 800.0, 600.0);
         assert!(result
-            .audio_events
+            .audio_events()
             .contains(&crate::audio::AudioEvent::Thrust));
     }
 
@@ -1253,11 +2614,13 @@ mod tests {
             velocity: Vec2::new(0.0, 0.0),
             distance_traveled: 0.0,
             alive: true,
+            piercing: false,
+            pierce_cooldown: 0.0,
         });
         let input = InputState::default();
         let result = playing.update(&input, TIMESTEP, 800.0, 600.0);
         assert!(result
-            .audio_events
+            .audio_events()
             .contains(&crate::audio::AudioEvent::AsteroidExplosionLarge));
     }
 
@@ -1278,11 +2641,13 @@ mod tests {
             velocity: Vec2::new(0.0, 0.0),
             distance_traveled: 0.0,
             alive: true,
+            piercing: false,
+            pierce_cooldown: 0.0,
         });
         let input = InputState::default();
         let result = playing.update(&input, TIMESTEP, 800.0, 600.0);
         assert!(result
-            .audio_events
+            .audio_events()
             .contains(&crate::audio::AudioEvent::AsteroidExplosionMedium));
     }
 
@@ -1303,11 +2668,13 @@ mod tests {
             velocity: Vec2::new(0.0, 0.0),
             distance_traveled: 0.0,
             alive: true,
+            piercing: false,
+            pierce_cooldown: 0.0,
         });
         let input = InputState::default();
         let result = playing.update(&input, TIMESTEP, 800.0, 600.0);
         assert!(result
-            .audio_events
+            .audio_events()
             .contains(&crate::audio::AudioEvent::AsteroidExplosionSmall));
     }
 
@@ -1327,7 +2694,7 @@ mod tests {
         let input = InputState::default();
         let result = playing.update(&input, TIMESTEP, 800.0, 600.0);
         assert!(result
-            .audio_events
+            .audio_events()
             .contains(&crate::audio::AudioEvent::ShipDestroyed));
     }
 
@@ -1349,7 +2716,7 @@ mod tests {
         let result = playing.update(&input, TIMESTEP, 800.0, 600.0);
         assert_eq!(result.state, Some(GameState::GameOver));
         assert!(result
-            .audio_events
+            .audio_events()
             .contains(&crate::audio::AudioEvent::ShipDestroyed));
     }
 
@@ -1359,7 +2726,6 @@ mod tests {
         let mut playing = PlayingState::new_seeded(800.0, 600.0, 42);
         // Set score just below extra life threshold
         playing.score = 9_980;
-        playing.ship.extra_life_awarded = false;
         // Place a bullet on a small asteroid (100 points) to push past 10,000
         playing.asteroids.clear();
         let mut rng = rand::rngs::StdRng::seed_from_u64(1);
@@ -1374,14 +2740,94 @@ mod tests {
             velocity: Vec2::new(0.0, 0.0),
             distance_traveled: 0.0,
             alive: true,
+            piercing: false,
+            pierce_cooldown: 0.0,
         });
         let input = InputState::default();
         let result = playing.update(&input, TIMESTEP, 800.0, 600.0);
         assert!(result
-            .audio_events
+            .audio_events()
             .contains(&crate::audio::AudioEvent::ExtraLife));
     }
 
+    // Scenario: One extra life cue for each life a kill awards, up to the cap
+    #[test]
+    fn test_extra_lives_per_threshold() {
+        let mut playing = PlayingState::new_seeded(800.0, 600.0, 42);
+        playing.extra_lives = ExtraLives {
+            every: 40,
+            max_lives: None,
+        };
+        playing.asteroids = vec![
+            small_asteroid_at(Vec2::new(100.0, 100.0)),
+            small_asteroid_at(Vec2::new(700.0, 500.0)),
+        ];
+        let mut capped = playing.clone();
+        capped.extra_lives.max_lives = Some(4);
+        for state in [&mut playing, &mut capped] {
+            state
+                .bullet_pool
+                .bullets
+                .push(bullet_at(Vec2::new(100.0, 100.0)));
+        }
+        let cues = |result: UpdateResult| {
+            result
+                .audio_events()
+                .iter()
+                .filter(|&event| *event == AudioEvent::ExtraLife)
+                .count()
+        };
+        assert_eq!(
+            cues(playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0)),
+            2
+        );
+        assert_eq!(playing.ship.lives, 5);
+        assert_eq!(
+            cues(capped.update(&InputState::default(), TIMESTEP, 800.0, 600.0)),
+            1
+        );
+        assert_eq!(capped.ship.lives, 4);
+    }
+
+    // Scenario: Games started from the menu use the configured extra lives
+    #[test]
+    fn test_game_extra_lives_setting() {
+        let mut game = Game::new(800.0, 600.0);
+        let rule = ExtraLives {
+            every: 5_000,
+            max_lives: Some(6),
+        };
+        game.extra_lives = rule;
+        game.start_game();
+        assert_eq!(game.playing.unwrap().extra_lives, rule);
+    }
+
+    // Scenario: Local games keep their files in the data directory
+    #[test]
+    fn test_game_with_default_paths() {
+        let game = Game::with_default_paths(800.0, 600.0);
+        assert_eq!(game.save_path, Some(save::default_path()));
+        assert_eq!(game.stats_path, Some(stats::default_path()));
+        assert_eq!(game.achievements_path, Some(achievements::default_path()));
+        assert_eq!(game.level_path, Some(editor::default_path()));
+        assert!(Game::new(800.0, 600.0).save_path.is_none());
+    }
+
+    // Scenario: Both players of a turns game use the configured extra lives
+    #[test]
+    fn test_turns_extra_lives_setting() {
+        let mut game = Game::new(800.0, 600.0);
+        let rule = ExtraLives {
+            every: 0,
+            max_lives: Some(4),
+        };
+        game.extra_lives = rule;
+        game.start_two_player();
+        assert_eq!(game.playing.as_ref().unwrap().extra_lives, rule);
+        let turns = game.turns.as_ref().unwrap();
+        assert_eq!(turns.benched().unwrap().extra_lives, rule);
+    }
+
     // Scenario: Update returns audio events for new wave
     #[test]
     fn test_update_emits_new_wave_audio_event() {
@@ -1392,7 +2838,7 @@ mod tests {
         let input = InputState::default();
         let result = playing.update(&input, TIMESTEP, 800.0, 600.0);
         assert!(result
-            .audio_events
+            .audio_events()
             .contains(&crate::audio::AudioEvent::NewWave));
     }
 
@@ -1404,7 +2850,7 @@ mod tests {
         playing.ship.invulnerable = true;
         let input = InputState::default();
         let result = playing.update(&input, TIMESTEP, 800.0, 600.0);
-        assert!(result.audio_events.is_empty());
+        assert!(result.audio_events().is_empty());
     }
 
     // Scenario: asteroid_explosion_event maps all sizes correctly
@@ -1454,7 +2900,7 @@ mod tests {
         let result = playing.update(&input, TIMESTEP, 800.0, 600.0);
         // Dead bullet should not destroy the asteroid
         assert_eq!(playing.asteroids.len(), 1);
-        assert!(result.audio_events.is_empty());
+        assert!(result.audio_events().is_empty());
     }
 
     // Scenario: Already-removed asteroid is skipped for subsequent bullets
@@ -1481,10 +2927,2463 @@ mod tests {
         let result = playing.update(&input, TIMESTEP, 800.0, 600.0);
         // Only one explosion event (second bullet should skip the already-removed asteroid)
         let explosion_count = result
-            .audio_events
+            .audio_events()
             .iter()
             .filter(|e| matches!(e, AudioEvent::AsteroidExplosionSmall))
             .count();
         assert_eq!(explosion_count, 1);
     }
+
+    // === Requirement: Controller Selection ===
+
+    // Scenario: Game defaults to keyboard control for the player
+    #[test]
+    fn test_game_defaults_to_keyboard_controller() {
+        let mut game = Game::new(800.0, 600.0);
+        game.handle_key(KeyCode::Enter);
+        game.playing = Some(PlayingState::new_seeded(800.0, 600.0, 42));
+        let keys = InputState {
+            thrust: true,
+            ..Default::default()
+        };
+        game.feed_keyboard(&keys);
+        game.tick(TIMESTEP);
+        assert_eq!(game.last_input, keys);
+        assert!(game.playing.as_ref().unwrap().ship.velocity.magnitude() > 0.0);
+    }
+
+    // Scenario: Plugged-in controller drives the player's ship
+    #[test]
+    fn test_set_controller_drives_ship() {
+        let mut game = Game::new(800.0, 600.0);
+        game.handle_key(KeyCode::Enter);
+        game.playing = Some(PlayingState::new_seeded(800.0, 600.0, 42));
+        game.set_controller(Box::new(crate::controller::ScriptedController::new().then(
+            1,
+            InputState {
+                fire: true,
+                ..Default::default()
+            },
+        )));
+        let result = game.tick(TIMESTEP);
+        assert!(result.audio_events().contains(&AudioEvent::Fire));
+        assert_eq!(game.playing.as_ref().unwrap().bullet_pool.active_count(), 1);
+        assert!(game.last_input.fire);
+    }
+
+    // Scenario: Keyboard state does not reach a non-keyboard controller
+    #[test]
+    fn test_keyboard_ignored_by_replay_controller() {
+        let mut game = Game::new(800.0, 600.0);
+        game.handle_key(KeyCode::Enter);
+        game.set_controller(Box::new(crate::controller::ReplayController::new(vec![])));
+        game.feed_keyboard(&InputState {
+            quit: true,
+            ..Default::default()
+        });
+        let result = game.tick(TIMESTEP);
+        assert_eq!(result.state, None);
+        assert_eq!(game.state, GameState::Playing);
+    }
+
+    // === Requirement: Game Tick ===
+
+    // Scenario: Tick transitions to Menu when the controller quits
+    #[test]
+    fn test_tick_quit_returns_to_menu() {
+        let mut game = Game::new(800.0, 600.0);
+        game.handle_key(KeyCode::Enter);
+        game.feed_keyboard(&InputState {
+            quit: true,
+            ..Default::default()
+        });
+        let result = game.tick(TIMESTEP);
+        assert_eq!(result.state, Some(GameState::Menu));
+        assert_eq!(game.state, GameState::Menu);
+        assert!(game.playing.is_none());
+        assert!(game.demo.is_some());
+    }
+
+    // Scenario: Tick transitions to GameOver on last life lost
+    #[test]
+    fn test_tick_game_over() {
+        let mut game = Game::new(800.0, 600.0);
+        game.handle_key(KeyCode::Enter);
+        let mut playing = PlayingState::new_seeded(800.0, 600.0, 42);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+        playing.asteroids = vec![crate::asteroids::Asteroid::new(
+            playing.ship.position,
+            Vec2::new(0.0, 0.0),
+            AsteroidSize::Large,
+            &mut rng,
+        )];
+        playing.ship.lives = 1;
+        playing.score = 777;
+        game.playing = Some(playing);
+        let result = game.tick(TIMESTEP);
+        assert_eq!(result.state, Some(GameState::GameOver));
+        assert_eq!(game.state, GameState::GameOver);
+        assert_eq!(game.final_score, 777);
+    }
+
+    // Scenario: Tick in Menu advances the demo with the demo controller, silently
+    #[test]
+    fn test_tick_menu_advances_demo() {
+        let mut game = Game::new(800.0, 600.0);
+        game.demo = Some(PlayingState::new_seeded(800.0, 600.0, 42));
+        game.demo_controller = Box::new(crate::controller::ScriptedController::new().then(
+            1,
+            InputState {
+                fire: true,
+                ..Default::default()
+            },
+        ));
+        let result = game.tick(TIMESTEP);
+        assert!(result.audio_events().is_empty());
+        assert_eq!(result.state, None);
+        let demo = game.demo.as_ref().unwrap();
+        assert_eq!(demo.frame_count, 1);
+        assert_eq!(demo.bullet_pool.active_count(), 1);
+    }
+
+    // Scenario: Tick restarts the demo when it ends
+    #[test]
+    fn test_tick_restarts_finished_demo() {
+        let mut game = Game::new(800.0, 600.0);
+        let mut demo = PlayingState::new_seeded(800.0, 600.0, 42);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+        demo.asteroids = vec![crate::asteroids::Asteroid::new(
+            demo.ship.position,
+            Vec2::new(0.0, 0.0),
+            AsteroidSize::Large,
+            &mut rng,
+        )];
+        demo.ship.lives = 1;
+        demo.frame_count = 500;
+        game.demo = Some(demo);
+        game.demo_controller = Box::new(crate::controller::ScriptedController::new());
+        game.tick(TIMESTEP);
+        let demo = game.demo.as_ref().unwrap();
+        assert_eq!(demo.frame_count, 0);
+        assert_eq!(demo.ship.lives, crate::ship::INITIAL_LIVES);
+    }
+
+    // Scenario: Tick does nothing on the game over screen
+    #[test]
+    fn test_tick_game_over_noop() {
+        let mut game = Game::new(800.0, 600.0);
+        game.state = GameState::GameOver;
+        let result = game.tick(TIMESTEP);
+        assert_eq!(result.state, None);
+        assert!(result.audio_events().is_empty());
+        assert_eq!(game.state, GameState::GameOver);
+    }
+
+    // Scenario: Tick in Playing without a PlayingState is a no-op
+    #[test]
+    fn test_tick_playing_without_state() {
+        let mut game = Game::new(800.0, 600.0);
+        game.state = GameState::Playing;
+        game.playing = None;
+        let result = game.tick(TIMESTEP);
+        assert_eq!(result.state, None);
+        assert!(result.audio_events().is_empty());
+    }
+
+    // === Requirement: Two-Player Alternating Turns ===
+
+    /// Park a large asteroid on the ship so the next update costs a life.
+    fn doom_ship(playing: &mut PlayingState) {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+        playing.ship.invulnerable = false;
+        playing.asteroids = vec![crate::asteroids::Asteroid::new(
+            playing.ship.position,
+            Vec2::new(0.0, 0.0),
+            AsteroidSize::Large,
+            &mut rng,
+        )];
+    }
+
+    fn two_player_game() -> Game {
+        let mut game = Game::new(800.0, 600.0);
+        game.handle_key(KeyCode::Char('2'));
+        game.turns
+            .as_mut()
+            .unwrap()
+            .tick_banner(crate::turns::TURN_BANNER_TIME);
+        game
+    }
+
+    // Scenario: Pressing 2 on the menu starts a two-player game
+    #[test]
+    fn test_menu_two_starts_two_player() {
+        let mut game = Game::new(800.0, 600.0);
+        assert!(!game.handle_key(KeyCode::Char('2')));
+        assert_eq!(game.state, GameState::Playing);
+        let turns = game.turns.as_ref().unwrap();
+        assert_eq!(turns.current(), 0);
+        assert_eq!(turns.banner(), Some("PLAYER 1".to_string()));
+        assert_eq!(turns.benched().unwrap().ship.lives, 3);
+    }
+
+    // Scenario: Any other key starts a one-player game
+    #[test]
+    fn test_menu_other_key_starts_one_player() {
+        let mut game = Game::new(800.0, 600.0);
+        game.final_scores = Some([1, 2]);
+        game.handle_key(KeyCode::Enter);
+        assert!(game.turns.is_none());
+        assert!(game.final_scores.is_none());
+    }
+
+    // Scenario: Play pauses while the turn banner shows
+    #[test]
+    fn test_turn_banner_pauses_play() {
+        let mut game = Game::new(800.0, 600.0);
+        game.handle_key(KeyCode::Char('2'));
+        let result = game.tick(TIMESTEP);
+        assert_eq!(result.state, None);
+        assert_eq!(game.playing.as_ref().unwrap().frame_count, 0);
+    }
+
+    // Scenario: Losing a life switches to player 2
+    #[test]
+    fn test_life_lost_switches_player() {
+        let mut game = two_player_game();
+        let playing = game.playing.as_mut().unwrap();
+        playing.score = 120;
+        doom_ship(playing);
+        let result = game.tick(TIMESTEP);
+        assert_eq!(result.state, None);
+        let turns = game.turns.as_ref().unwrap();
+        assert_eq!(turns.current(), 1);
+        assert_eq!(turns.banner(), Some("PLAYER 2".to_string()));
+        assert_eq!(game.playing.as_ref().unwrap().score, 0);
+        let benched = turns.benched().unwrap();
+        assert_eq!((benched.score, benched.ship.lives), (120, 2));
+    }
+
+    // Scenario: Game over for one player hands over to the other
+    #[test]
+    fn test_player_out_continues_game() {
+        let mut game = two_player_game();
+        let playing = game.playing.as_mut().unwrap();
+        playing.ship.lives = 1;
+        playing.score = 300;
+        doom_ship(playing);
+        let result = game.tick(TIMESTEP);
+        assert_eq!(result.state, None);
+        assert_eq!(game.state, GameState::Playing);
+        let turns = game.turns.as_ref().unwrap();
+        assert_eq!(turns.current(), 1);
+        assert!(turns.benched().is_none());
+        assert_eq!(turns.players(game.playing.as_ref().unwrap())[0], (300, 0));
+    }
+
+    // Scenario: Game over reports both players
+    #[test]
+    fn test_two_player_game_over_scores() {
+        let mut game = two_player_game();
+        let playing = game.playing.as_mut().unwrap();
+        playing.ship.lives = 1;
+        playing.score = 300;
+        doom_ship(playing);
+        game.tick(TIMESTEP);
+        game.turns
+            .as_mut()
+            .unwrap()
+            .tick_banner(crate::turns::TURN_BANNER_TIME);
+        let playing = game.playing.as_mut().unwrap();
+        playing.ship.lives = 1;
+        playing.score = 450;
+        doom_ship(playing);
+        let result = game.tick(TIMESTEP);
+        assert_eq!(result.state, Some(GameState::GameOver));
+        assert_eq!(game.state, GameState::GameOver);
+        assert_eq!(game.final_scores, Some([300, 450]));
+        assert!(game.turns.is_none());
+    }
+
+    // Scenario: Quitting a two-player game returns to the menu
+    #[test]
+    fn test_two_player_quit() {
+        let mut game = two_player_game();
+        game.feed_keyboard(&InputState {
+            quit: true,
+            ..Default::default()
+        });
+        game.tick(TIMESTEP);
+        assert_eq!(game.state, GameState::Menu);
+        assert!(game.turns.is_none());
+    }
+
+    // === Requirement: Simultaneous Two-Ship Play ===
+
+    fn two_ship_state(mode: Multiplayer) -> PlayingState {
+        let mut playing = PlayingState::new_seeded(800.0, 600.0, 42);
+        playing.add_player_two(mode, 800.0, 600.0);
+        playing.asteroids.clear();
+        playing.wave_delay_timer = f64::NEG_INFINITY; // keep the field empty
+        for ship in [
+            &mut playing.ship,
+            &mut playing.player_two.as_mut().unwrap().ship,
+        ] {
+            ship.invulnerable = false;
+        }
+        playing
+    }
+
+    fn bullet_at(position: Vec2) -> crate::bullets::Bullet {
+        crate::bullets::Bullet {
+            position,
+            velocity: Vec2::new(0.0, 0.0),
+            distance_traveled: 0.0,
+            alive: true,
+            piercing: false,
+            pierce_cooldown: 0.0,
+        }
+    }
+
+    fn small_asteroid_at(position: Vec2) -> crate::asteroids::Asteroid {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+        crate::asteroids::Asteroid::new(
+            position,
+            Vec2::new(0.0, 0.0),
+            AsteroidSize::Small,
+            &mut rng,
+        )
+    }
+
+    // Scenario: Second ship joins on the other side of the field
+    #[test]
+    fn test_add_player_two() {
+        let mut playing = PlayingState::new_seeded(800.0, 600.0, 42);
+        assert_eq!(playing.pilot_count(), 1);
+        playing.add_player_two(Multiplayer::Versus, 800.0, 600.0);
+        assert_eq!(playing.pilot_count(), 2);
+        let two = playing.player_two.as_ref().unwrap();
+        assert!(playing.ship.position.x < 400.0);
+        assert!(two.ship.position.x > 400.0);
+        assert!(playing.ship.invulnerable && two.ship.invulnerable);
+        assert_eq!(two.ship.lives, crate::ship::INITIAL_LIVES);
+        assert_eq!((two.score, two.frags), (0, [0, 0]));
+    }
+
+    // Scenario: Each player's input drives only their own ship
+    #[test]
+    fn test_each_player_drives_own_ship() {
+        let mut playing = two_ship_state(Multiplayer::CoOp {
+            friendly_fire: false,
+        });
+        let thrust = InputState {
+            thrust: true,
+            ..Default::default()
+        };
+        let fire = InputState {
+            fire: true,
+            ..Default::default()
+        };
+        playing.update_players([&fire, &thrust], TIMESTEP, 800.0, 600.0);
+        let two = playing.player_two.as_ref().unwrap();
+        assert_eq!(playing.ship.velocity.magnitude(), 0.0);
+        assert!(two.ship.velocity.magnitude() > 0.0);
+        assert_eq!(playing.bullet_pool.active_count(), 1);
+        assert_eq!(two.bullet_pool.active_count(), 0);
+    }
+
+    // Scenario: Asteroid hits are credited to the firing player
+    #[test]
+    fn test_hits_credited_to_shooter() {
+        let mut playing = two_ship_state(Multiplayer::CoOp {
+            friendly_fire: false,
+        });
+        let target = Vec2::new(400.0, 100.0);
+        playing.asteroids = vec![small_asteroid_at(target)];
+        playing
+            .player_two
+            .as_mut()
+            .unwrap()
+            .bullet_pool
+            .bullets
+            .push(bullet_at(target));
+        let result = playing.update_players(
+            [&InputState::default(), &InputState::default()],
+            TIMESTEP,
+            800.0,
+            600.0,
+        );
+        assert!(result
+            .audio_events()
+            .contains(&AudioEvent::AsteroidExplosionSmall));
+        assert_eq!(playing.score, 0);
+        assert_eq!(playing.player_two.as_ref().unwrap().score, 100);
+    }
+
+    // Scenario: Player 2 earns their own extra life
+    #[test]
+    fn test_player_two_extra_life() {
+        let mut playing = two_ship_state(Multiplayer::CoOp {
+            friendly_fire: false,
+        });
+        let target = Vec2::new(400.0, 100.0);
+        playing.asteroids = vec![small_asteroid_at(target)];
+        let two = playing.player_two.as_mut().unwrap();
+        two.score = crate::ship::EXTRA_LIFE_SCORE - 50;
+        two.bullet_pool.bullets.push(bullet_at(target));
+        let result = playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        assert!(result.audio_events().contains(&AudioEvent::ExtraLife));
+        assert_eq!(playing.player_two.as_ref().unwrap().ship.lives, 4);
+        assert_eq!(playing.ship.lives, 3);
+    }
+
+    // Scenario: Versus bullets destroy the other ship and score a frag
+    #[test]
+    fn test_versus_frag() {
+        let mut playing = two_ship_state(Multiplayer::Versus);
+        let target = playing.player_two.as_ref().unwrap().ship.position;
+        playing.bullet_pool.bullets.push(bullet_at(target));
+        let result = playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        assert_eq!(result.state, None);
+        assert!(result.audio_events().contains(&AudioEvent::ShipDestroyed));
+        let two = playing.player_two.as_ref().unwrap();
+        assert_eq!(two.ship.lives, 2);
+        assert!(two.ship.invulnerable);
+        assert_eq!(two.frags, [1, 0]);
+        assert_eq!(playing.bullet_pool.active_count(), 0);
+    }
+
+    // Scenario: Co-op bullets pass through the other ship without friendly fire
+    #[test]
+    fn test_coop_no_friendly_fire() {
+        let mut playing = two_ship_state(Multiplayer::CoOp {
+            friendly_fire: false,
+        });
+        let target = playing.player_two.as_ref().unwrap().ship.position;
+        playing.bullet_pool.bullets.push(bullet_at(target));
+        playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        assert_eq!(playing.player_two.as_ref().unwrap().ship.lives, 3);
+        assert_eq!(playing.bullet_pool.active_count(), 1);
+    }
+
+    // Scenario: Co-op friendly fire costs a life but scores no frag
+    #[test]
+    fn test_coop_friendly_fire() {
+        let mut playing = two_ship_state(Multiplayer::CoOp {
+            friendly_fire: true,
+        });
+        let target = playing.ship.position;
+        playing
+            .player_two
+            .as_mut()
+            .unwrap()
+            .bullet_pool
+            .bullets
+            .push(bullet_at(target));
+        playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        assert_eq!(playing.ship.lives, 2);
+        assert_eq!(playing.player_two.as_ref().unwrap().frags, [0, 0]);
+    }
+
+    // Scenario: Invulnerable ships cannot be shot
+    #[test]
+    fn test_invulnerable_ship_not_shot() {
+        let mut playing = two_ship_state(Multiplayer::Versus);
+        let two = playing.player_two.as_mut().unwrap();
+        two.ship.invulnerable = true;
+        let target = two.ship.position;
+        playing.bullet_pool.bullets.push(bullet_at(target));
+        playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        assert_eq!(playing.player_two.as_ref().unwrap().ship.lives, 3);
+    }
+
+    // Scenario: A ship with no lives sits out while the other plays on
+    #[test]
+    fn test_ship_out_sits_out() {
+        let mut playing = two_ship_state(Multiplayer::Versus);
+        playing.ship.lives = 1;
+        let position = playing.ship.position;
+        playing.asteroids = vec![small_asteroid_at(position)];
+        let result = playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        assert_eq!(result.state, None);
+        assert_eq!(playing.ship.lives, 0);
+
+        // Player 1 is out: no movement, no firing, no further collisions
+        let act = InputState {
+            thrust: true,
+            fire: true,
+            ..Default::default()
+        };
+        let result = playing.update_players([&act, &act], TIMESTEP, 800.0, 600.0);
+        assert_eq!(result.state, None);
+        assert_eq!(playing.ship.velocity.magnitude(), 0.0);
+        assert_eq!(playing.bullet_pool.active_count(), 0);
+        assert_eq!(
+            playing
+                .player_two
+                .as_ref()
+                .unwrap()
+                .bullet_pool
+                .active_count(),
+            1
+        );
+
+        // Shots at a ship that is out do nothing
+        let two = playing.player_two.as_mut().unwrap();
+        two.bullet_pool.bullets.push(bullet_at(position));
+        playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        assert_eq!(playing.player_two.as_ref().unwrap().frags, [0, 0]);
+    }
+
+    // Scenario: Game over once both ships are out
+    #[test]
+    fn test_two_ships_game_over() {
+        let mut playing = two_ship_state(Multiplayer::CoOp {
+            friendly_fire: false,
+        });
+        playing.ship.lives = 0;
+        let two = playing.player_two.as_mut().unwrap();
+        two.ship.lives = 1;
+        let position = two.ship.position;
+        playing.asteroids = vec![small_asteroid_at(position)];
+        let result = playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        assert_eq!(result.state, Some(GameState::GameOver));
+    }
+
+    // Scenario: Either player can quit
+    #[test]
+    fn test_player_two_quit() {
+        let mut playing = two_ship_state(Multiplayer::Versus);
+        let quit = InputState {
+            quit: true,
+            ..Default::default()
+        };
+        let result =
+            playing.update_players([&InputState::default(), &quit], TIMESTEP, 800.0, 600.0);
+        assert_eq!(result.state, Some(GameState::Menu));
+    }
+
+    // Scenario: Menu keys start co-op and versus and toggle friendly fire
+    #[test]
+    fn test_menu_simultaneous_modes() {
+        let mut game = Game::new(800.0, 600.0);
+        assert!(!game.handle_key(KeyCode::Char('f')));
+        assert_eq!(game.state, GameState::Menu);
+        assert!(game.friendly_fire);
+        game.handle_key(KeyCode::Char('c'));
+        let two = game.playing.as_ref().unwrap().player_two.as_ref().unwrap();
+        assert_eq!(
+            two.mode,
+            Multiplayer::CoOp {
+                friendly_fire: true
+            }
+        );
+
+        let mut game = Game::new(800.0, 600.0);
+        game.handle_key(KeyCode::Char('V'));
+        let two = game.playing.as_ref().unwrap().player_two.as_ref().unwrap();
+        assert_eq!(two.mode, Multiplayer::Versus);
+        assert!(game.turns.is_none());
+    }
+
+    // Scenario: Player 2's controller drives the second ship
+    #[test]
+    fn test_tick_uses_player_two_controller() {
+        let mut game = Game::new(800.0, 600.0);
+        game.start_simultaneous(Multiplayer::Versus);
+        game.feed_keyboard_two(&InputState {
+            fire: true,
+            ..Default::default()
+        });
+        game.tick(TIMESTEP);
+        assert!(game.last_input_two.fire);
+        let playing = game.playing.as_ref().unwrap();
+        assert_eq!(playing.bullet_pool.active_count(), 0);
+        assert_eq!(
+            playing
+                .player_two
+                .as_ref()
+                .unwrap()
+                .bullet_pool
+                .active_count(),
+            1
+        );
+    }
+
+    // Scenario: Versus game over records scores and frags
+    #[test]
+    fn test_versus_game_over_results() {
+        let mut game = Game::new(800.0, 600.0);
+        game.start_simultaneous(Multiplayer::Versus);
+        let playing = game.playing.as_mut().unwrap();
+        playing.score = 40;
+        let two = playing.player_two.as_mut().unwrap();
+        two.score = 70;
+        two.frags = [2, 1];
+        game.game_over();
+        assert_eq!(game.final_scores, Some([40, 70]));
+        assert_eq!(game.final_frags, Some([2, 1]));
+
+        game.start_simultaneous(Multiplayer::CoOp {
+            friendly_fire: false,
+        });
+        assert_eq!(game.final_frags, None);
+        game.game_over();
+        assert_eq!(game.final_scores, Some([0, 0]));
+        assert_eq!(game.final_frags, None);
+    }
+
+    // Scenario: Replayed input reproduces a seeded game
+    #[test]
+    fn test_replay_reproduces_seeded_game() {
+        let script = || {
+            crate::controller::ScriptedController::new()
+                .then(
+                    20,
+                    InputState {
+                        rotate_left: true,
+                        thrust: true,
+                        ..Default::default()
+                    },
+                )
+                .then(
+                    1,
+                    InputState {
+                        fire: true,
+                        ..Default::default()
+                    },
+                )
+                .wait(40)
+        };
+        let mut recorded = Vec::new();
+        let mut original = Game::new(800.0, 600.0);
+        original.handle_key(KeyCode::Enter);
+        original.playing = Some(PlayingState::new_seeded(800.0, 600.0, 7));
+        original.set_controller(Box::new(script()));
+        for _ in 0..61 {
+            original.tick(TIMESTEP);
+            recorded.push(original.last_input.clone());
+        }
+
+        let mut replayed = Game::new(800.0, 600.0);
+        replayed.handle_key(KeyCode::Enter);
+        replayed.playing = Some(PlayingState::new_seeded(800.0, 600.0, 7));
+        replayed.set_controller(Box::new(crate::controller::ReplayController::new(recorded)));
+        for _ in 0..61 {
+            replayed.tick(TIMESTEP);
+        }
+        let a = original.playing.as_ref().unwrap();
+        let b = replayed.playing.as_ref().unwrap();
+        assert_eq!(a.ship.position, b.ship.position);
+        assert_eq!(a.score, b.score);
+        assert_eq!(a.asteroids.len(), b.asteroids.len());
+        assert!(replayed.controller.is_finished());
+    }
+
+    // === Requirement: Network Play ===
+
+    fn net_pair() -> (
+        crate::netplay::Lockstep,
+        std::thread::JoinHandle<crate::netplay::Lockstep>,
+    ) {
+        use std::io::BufReader;
+        use std::os::unix::net::UnixStream;
+        let (a, b) = UnixStream::pair().unwrap();
+        let joiner = std::thread::spawn(move || {
+            crate::netplay::Lockstep::join(BufReader::new(b.try_clone().unwrap()), b).unwrap()
+        });
+        let config = crate::netplay::NetConfig {
+            seed: 4,
+            input_delay: 2,
+            ..Default::default()
+        };
+        let host =
+            crate::netplay::Lockstep::host(BufReader::new(a.try_clone().unwrap()), a, config)
+                .unwrap();
+        (host, joiner)
+    }
+
+    // Scenario: Network game flies both ships from both peers' inputs
+    #[test]
+    fn test_netplay_ticks_in_lockstep() {
+        let (host, joiner) = net_pair();
+        let remote = std::thread::spawn(move || {
+            let mut session = joiner.join().unwrap();
+            let mut state = session.initial_state();
+            let fire = InputState {
+                fire: true,
+                ..Default::default()
+            };
+            for _ in 0..4 {
+                session.step(&mut state, &fire).unwrap();
+            }
+            session.close();
+        });
+        let mut game = Game::new(WORLD_WIDTH, WORLD_HEIGHT);
+        game.start_netplay(host);
+        assert_eq!(game.state, GameState::Playing);
+        assert!(game.playing.as_ref().unwrap().player_two.is_some());
+        game.feed_keyboard(&InputState {
+            thrust: true,
+            ..Default::default()
+        });
+        let mut applied = Vec::new();
+        for _ in 0..4 {
+            game.tick(TIMESTEP);
+            applied.push((game.last_input.thrust, game.last_input_two.fire));
+        }
+        remote.join().unwrap();
+        assert_eq!(
+            applied,
+            vec![(false, false), (false, false), (true, true), (true, true)]
+        );
+    }
+
+    // Scenario: Remote player leaving ends the game with a message
+    #[test]
+    fn test_netplay_disconnect_ends_game() {
+        let (host, joiner) = net_pair();
+        drop(joiner.join().unwrap());
+        let mut game = Game::new(WORLD_WIDTH, WORLD_HEIGHT);
+        game.start_netplay(host);
+        let mut result = game.tick(TIMESTEP);
+        for _ in 0..5 {
+            if result.state.is_some() {
+                break;
+            }
+            result = game.tick(TIMESTEP);
+        }
+        assert_eq!(result.state, Some(GameState::GameOver));
+        assert_eq!(game.state, GameState::GameOver);
+        assert!(game.lockstep.is_none());
+        assert_eq!(game.net_error.as_deref(), Some("other player disconnected"));
+
+        // A new local game clears the message
+        game.handle_key(KeyCode::Enter);
+        game.handle_key(KeyCode::Enter);
+        assert_eq!(game.state, GameState::Playing);
+        assert!(game.net_error.is_none());
+    }
+
+    // === Requirement: Scene Capture ===
+
+    // Scenario: Menu scene shows the demo and the friendly fire setting
+    #[test]
+    fn test_scene_menu() {
+        let mut game = Game::new(800.0, 600.0);
+        game.friendly_fire = true;
+        match game.scene() {
+            Scene::Menu {
+                demo: Some(demo),
+                friendly_fire: true,
+                mode: GameMode::Classic,
+                difficulty: Difficulty::Normal,
+                adaptive: false,
+                controls: ControlScheme::Keyboard,
+                saved_game: false,
+                editor: false,
+                stats: false,
+                notice: None,
+            } => assert!(!demo.polygons.is_empty()),
+            other => panic!("expected menu, got {:?}", other),
+        }
+    }
+
+    // Scenario: Playing scene draws ships, bullets and flames with the HUD
+    #[test]
+    fn test_scene_playing() {
+        let mut game = Game::new(800.0, 600.0);
+        game.start_simultaneous(Multiplayer::Versus);
+        let playing = game.playing.as_mut().unwrap();
+        let asteroids = playing.asteroids.len();
+        playing.ship.invulnerable = false;
+        playing.player_two.as_mut().unwrap().ship.invulnerable = false;
+        playing.bullet_pool.fire(Vec2::new(10.0, 10.0), 0.0);
+        game.last_input.thrust = true;
+        match game.scene() {
+            Scene::Playing {
+                playfield,
+                banner: None,
+                hud,
+                ..
+            } => {
+                // Asteroids, two ships and one flame
+                assert_eq!(playfield.polygons.len(), asteroids + 3);
+                assert_eq!(playfield.dots, vec![[10.0, 10.0]]);
+                assert!(hud.contains("FRAGS 0-0"));
+            }
+            other => panic!("expected playing, got {:?}", other),
+        }
+
+        // Blinking and out-of-lives ships are hidden
+        let playing = game.playing.as_mut().unwrap();
+        playing.ship.lives = 0;
+        playing.player_two.as_mut().unwrap().ship.invulnerable = true;
+        playing.frame_count = 6;
+        let Scene::Playing { playfield, .. } = game.scene() else {
+            panic!("expected playing");
+        };
+        assert_eq!(playfield.polygons.len(), asteroids);
+    }
+
+    // Scenario: Playing scene shows the turn banner and one-player HUD
+    #[test]
+    fn test_scene_banner_and_hud() {
+        let mut game = Game::new(800.0, 600.0);
+        game.start_two_player();
+        let Scene::Playing { banner, hud, .. } = game.scene() else {
+            panic!("expected playing");
+        };
+        assert_eq!(banner.as_deref(), Some("PLAYER 1"));
+        assert!(hud.starts_with(">1UP 0"));
+
+        game.start_game();
+        let Scene::Playing { hud, .. } = game.scene() else {
+            panic!("expected playing");
+        };
+        assert_eq!(hud, "Score: 0  ▲ ▲ ▲ ");
+
+        game.playing = None;
+        assert!(matches!(game.scene(), Scene::Playing { hud, .. } if hud.is_empty()));
+    }
+
+    // Scenario: Game over scene lists results and network errors
+    #[test]
+    fn test_scene_game_over() {
+        let mut game = Game::new(800.0, 600.0);
+        game.start_game();
+        game.playing.as_mut().unwrap().score = 1250;
+        game.game_over();
+        assert_eq!(
+            game.scene(),
+            Scene::GameOver {
+                results: vec!["Score: 1250".to_string()],
+                note: None,
+                high_scores: Vec::new(),
+                mode: GameMode::Classic,
+                summary: stats::summary_lines(&[SessionStats::default()]),
+            }
+        );
+
+        game.final_scores = Some([10, 20]);
+        game.net_error = Some("other player disconnected".to_string());
+        let Scene::GameOver { results, note, .. } = game.scene() else {
+            panic!("expected game over");
+        };
+        assert_eq!(results[2], "Player 2 wins");
+        assert_eq!(
+            note.as_deref(),
+            Some("Network game ended: other player disconnected")
+        );
+
+        game.final_frags = Some([3, 1]);
+        let Scene::GameOver { results, .. } = game.scene() else {
+            panic!("expected game over");
+        };
+        assert!(results[0].contains("3 frags"));
+    }
+
+    // === Requirement: Shared High Scores ===
+
+    // Scenario: Finished games post to the shared board
+    #[test]
+    fn test_game_over_submits_high_scores() {
+        let board = ModeHighScores::new().shared();
+        let mut game = Game::new(800.0, 600.0);
+        game.high_scores = Some(SharedHighScores::clone(&board));
+        game.player_name = "PLAYER3".to_string();
+        game.start_game();
+        game.playing.as_mut().unwrap().score = 900;
+        game.game_over();
+
+        let mut other = Game::new(800.0, 600.0);
+        other.high_scores = Some(SharedHighScores::clone(&board));
+        other.player_name = "PLAYER4".to_string();
+        other.start_simultaneous(Multiplayer::Versus);
+        let playing = other.playing.as_mut().unwrap();
+        playing.score = 1200;
+        playing.player_two.as_mut().unwrap().score = 300;
+        other.game_over();
+
+        let names: Vec<String> = board
+            .lock()
+            .unwrap()
+            .board(GameMode::Classic)
+            .unwrap()
+            .entries()
+            .iter()
+            .map(|entry| entry.name.clone())
+            .collect();
+        assert_eq!(names, vec!["PLAYER4 P1", "PLAYER3", "PLAYER4 P2"]);
+        let Scene::GameOver { high_scores, .. } = game.scene() else {
+            panic!("expected game over");
+        };
+        assert_eq!(high_scores, board.lock().unwrap().lines(GameMode::Classic));
+    }
+
+    // Scenario: Games without a board record nothing
+    #[test]
+    fn test_game_over_without_board() {
+        let mut game = Game::new(800.0, 600.0);
+        game.start_game();
+        game.playing.as_mut().unwrap().score = 900;
+        game.game_over();
+        let Scene::GameOver { high_scores, .. } = game.scene() else {
+            panic!("expected game over");
+        };
+        assert!(high_scores.is_empty());
+    }
+
+    // === Requirement: Game Modes ===
+
+    // Scenario: M cycles the mode and one-player games start in it
+    #[test]
+    fn test_menu_selects_mode() {
+        let mut game = Game::new(800.0, 600.0);
+        game.handle_key(KeyCode::Char('m'));
+        game.handle_key(KeyCode::Char('M'));
+        assert_eq!(game.mode, GameMode::Survival);
+        assert_eq!(game.state, GameState::Menu);
+        let Scene::Menu { mode, .. } = game.scene() else {
+            panic!("expected menu");
+        };
+        assert_eq!(mode, GameMode::Survival);
+
+        game.handle_key(KeyCode::Enter);
+        assert_eq!(game.playing.as_ref().unwrap().mode, GameMode::Survival);
+
+        // Two-player games are always classic
+        game.mode = GameMode::Hardcore;
+        game.start_simultaneous(Multiplayer::Versus);
+        assert_eq!(game.playing.as_ref().unwrap().mode, GameMode::Classic);
+        game.start_two_player();
+        assert_eq!(game.playing.as_ref().unwrap().mode, GameMode::Classic);
+        game.start_game();
+        assert_eq!(game.playing.as_ref().unwrap().ship.lives, 1);
+    }
+
+    /// A game in `mode` with one large rock parked on the ship.
+    fn rock_on_ship(mode: GameMode) -> PlayingState {
+        let mut state = PlayingState::new_seeded(800.0, 600.0, 37);
+        state.set_mode(mode);
+        let mut rock = state.asteroids[0].clone();
+        rock.position = state.ship.position;
+        rock.velocity = Vec2::new(0.0, 0.0);
+        state.asteroids = vec![rock];
+        state
+    }
+
+    // Scenario: Time attack ends when the clock runs out
+    #[test]
+    fn test_time_attack_clock() {
+        let mut state = PlayingState::new_seeded(800.0, 600.0, 37);
+        state.set_mode(GameMode::TimeAttack);
+        assert_eq!(one_player_hud(&state), "Score: 0  ▲ ▲ ▲  3:00");
+        let idle = InputState::default();
+        let ticks = (crate::mode::TIME_ATTACK_SECONDS / TIMESTEP).round() as u64;
+        for tick in 1..ticks {
+            state.ship.invulnerable = true;
+            state.ship.invulnerable_timer = 1.0;
+            let result = state.update(&idle, TIMESTEP, 800.0, 600.0);
+            assert_eq!(result.state, None, "ended early at tick {}", tick);
+        }
+        assert_eq!(one_player_hud(&state), "Score: 0  ▲ ▲ ▲  0:01");
+        let result = state.update(&idle, TIMESTEP, 800.0, 600.0);
+        assert_eq!(result.state, Some(GameState::GameOver));
+        assert_eq!(state.time_left(), Some(0.0));
+
+        // Untimed modes never run out
+        let classic = PlayingState::new_seeded(800.0, 600.0, 37);
+        assert_eq!(classic.time_left(), None);
+    }
+
+    // Scenario: Survival counts deaths instead of taking lives
+    #[test]
+    fn test_survival_counts_deaths() {
+        let mut state = rock_on_ship(GameMode::Survival);
+        state.ship.lives = 1;
+        let idle = InputState::default();
+        let result = state.update(&idle, TIMESTEP, 800.0, 600.0);
+        assert_eq!(result.state, None);
+        assert!(result.audio_events().contains(&AudioEvent::ShipDestroyed));
+        assert_eq!((state.deaths, state.ship.lives), (1, 1));
+        assert!(state.ship.invulnerable);
+        assert_eq!(one_player_hud(&state), "Score: 0  Deaths: 1");
+
+        // Quitting ends the game with the death count
+        let mut game = Game::new(800.0, 600.0);
+        game.playing = Some(state);
+        game.state = GameState::Playing;
+        game.feed_keyboard(&InputState {
+            quit: true,
+            ..Default::default()
+        });
+        let result = game.tick(TIMESTEP);
+        assert_eq!(result.state, Some(GameState::GameOver));
+        assert_eq!(game.state, GameState::GameOver);
+        let Scene::GameOver { results, mode, .. } = game.scene() else {
+            panic!("expected game over");
+        };
+        assert_eq!(results, vec!["Score: 0", "Deaths: 1"]);
+        assert_eq!(mode, GameMode::Survival);
+    }
+
+    // Scenario: Zen ships cannot be destroyed
+    #[test]
+    fn test_zen_ship_survives() {
+        let mut state = rock_on_ship(GameMode::Zen);
+        let idle = InputState::default();
+        for _ in 0..10 {
+            let result = state.update(&idle, TIMESTEP, 800.0, 600.0);
+            assert!(!result.audio_events().contains(&AudioEvent::ShipDestroyed));
+        }
+        assert_eq!(state.ship.lives, crate::ship::INITIAL_LIVES);
+        assert_eq!(one_player_hud(&state), "Score: 0");
+    }
+
+    // Scenario: Hardcore has one life, no extra lives and faster rocks
+    #[test]
+    fn test_hardcore_rules() {
+        let mut state = rock_on_ship(GameMode::Hardcore);
+        let idle = InputState::default();
+        let result = state.update(&idle, TIMESTEP, 800.0, 600.0);
+        assert_eq!(result.state, Some(GameState::GameOver));
+
+        let mut state = PlayingState::new_seeded(800.0, 600.0, 37);
+        state.set_mode(GameMode::Hardcore);
+        state.score = crate::ship::EXTRA_LIFE_SCORE - 50;
+        state.ship.position = Vec2::new(-1000.0, -1000.0);
+        let target = Vec2::new(100.0, 100.0);
+        state.asteroids.push(small_asteroid_at(target));
+        state.bullet_pool.bullets.push(bullet_at(target));
+        let mut classic = state.clone();
+        classic.mode = GameMode::Classic;
+        let (start, velocity) = (state.asteroids[0].position, state.asteroids[0].velocity);
+        state.update(&idle, TIMESTEP, 800.0, 600.0);
+        classic.update(&idle, TIMESTEP, 800.0, 600.0);
+        assert_eq!(state.ship.lives, 1);
+        assert_eq!(classic.ship.lives, 2);
+
+        let moved = state.asteroids[0].position - start;
+        let expected = velocity.scale(TIMESTEP * crate::mode::HARDCORE_ROCK_SPEED);
+        assert!((moved.x - expected.x).abs() < 1e-9 && (moved.y - expected.y).abs() < 1e-9);
+    }
+
+    // Scenario: Each mode posts to its own board
+    #[test]
+    fn test_high_scores_per_mode() {
+        let board = ModeHighScores::new().shared();
+        let mut game = Game::new(800.0, 600.0);
+        game.high_scores = Some(SharedHighScores::clone(&board));
+        game.mode = GameMode::TimeAttack;
+        game.start_game();
+        game.playing.as_mut().unwrap().score = 700;
+        game.game_over();
+        game.mode = GameMode::Classic;
+        game.start_game();
+        game.playing.as_mut().unwrap().score = 100;
+        game.game_over();
+        let Scene::GameOver { high_scores, .. } = game.scene() else {
+            panic!("expected game over");
+        };
+
+        let boards = board.lock().unwrap();
+        assert_eq!(
+            boards.board(GameMode::TimeAttack).unwrap().entries()[0].score,
+            700
+        );
+        assert_eq!(
+            boards.board(GameMode::Classic).unwrap().entries()[0].score,
+            100
+        );
+        assert_eq!(high_scores, boards.lines(GameMode::Classic));
+    }
+
+    // Scenario: The mode survives a save
+    #[test]
+    fn test_mode_saved() {
+        let mut game = Game::new(800.0, 600.0);
+        game.mode = GameMode::TimeAttack;
+        game.start_game();
+        game.playing.as_mut().unwrap().clock = 42.0;
+        let save = game.snapshot().unwrap();
+        let mut other = Game::new(800.0, 600.0);
+        other.restore(SaveGame::from_json(&save.to_json()).unwrap());
+        let playing = other.playing.as_ref().unwrap();
+        assert_eq!((playing.mode, playing.clock), (GameMode::TimeAttack, 42.0));
+    }
+
+    // === Requirement: Difficulty ===
+
+    // Scenario: D and A pick the difficulty for one-player games
+    #[test]
+    fn test_menu_selects_difficulty() {
+        let mut game = Game::new(800.0, 600.0);
+        game.handle_key(KeyCode::Char('d'));
+        game.handle_key(KeyCode::Char('D'));
+        game.handle_key(KeyCode::Char('a'));
+        assert_eq!(game.state, GameState::Menu);
+        let Scene::Menu {
+            difficulty,
+            adaptive,
+            ..
+        } = game.scene()
+        else {
+            panic!("expected menu");
+        };
+        assert_eq!((difficulty, adaptive), (Difficulty::Insane, true));
+
+        game.handle_key(KeyCode::Enter);
+        let playing = game.playing.as_ref().unwrap();
+        assert_eq!(playing.difficulty, Difficulty::Insane);
+        assert!(playing.adaptive);
+        assert_eq!(playing.asteroids.len(), 6);
+        assert_eq!(playing.bullet_pool.limit, 3);
+
+        // Two-player games stay normal
+        game.start_simultaneous(Multiplayer::Versus);
+        let playing = game.playing.as_ref().unwrap();
+        assert_eq!(playing.difficulty, Difficulty::Normal);
+        assert_eq!(playing.asteroids.len(), 4);
+        assert_eq!(playing.player_two.as_ref().unwrap().bullet_pool.limit, 4);
+    }
+
+    // Scenario: Later waves follow the difficulty's curve
+    #[test]
+    fn test_wave_follows_difficulty() {
+        let mut state = PlayingState::new_seeded(800.0, 600.0, 38);
+        state.set_difficulty(Difficulty::Arcade, false, 800.0, 600.0);
+        assert_eq!(state.asteroids.len(), 4);
+        state.asteroids.clear();
+        let idle = InputState::default();
+        while state.wave == 1 {
+            state.update(&idle, TIMESTEP, 800.0, 600.0);
+        }
+        assert_eq!(state.asteroids.len(), 6);
+        let (min, max) = Difficulty::Arcade.settings().speed_range(2);
+        assert!(state
+            .asteroids
+            .iter()
+            .all(|a| (min..=max).contains(&a.velocity.magnitude())));
+    }
+
+    // Scenario: Adaptive difficulty eases after deaths and hardens after clean waves
+    #[test]
+    fn test_adaptive_difficulty() {
+        let idle = InputState::default();
+        let clear_wave = |state: &mut PlayingState| {
+            state.asteroids.clear();
+            let wave = state.wave;
+            while state.wave == wave {
+                state.update(&idle, TIMESTEP, 800.0, 600.0);
+            }
+        };
+
+        let mut state = PlayingState::new_seeded(800.0, 600.0, 38);
+        state.set_difficulty(Difficulty::Normal, true, 800.0, 600.0);
+        clear_wave(&mut state);
+        assert_eq!(state.adaptive_level, 1);
+        assert_eq!(state.asteroids.len(), 6);
+
+        // Two deaths make the next wave easier
+        let mut rock = state.asteroids[0].clone();
+        for _ in 0..2 {
+            rock.position = state.ship.position;
+            state.asteroids = vec![rock.clone()];
+            state.ship.invulnerable = false;
+            state.update(&idle, TIMESTEP, 800.0, 600.0);
+        }
+        assert_eq!(state.wave_deaths, 2);
+        clear_wave(&mut state);
+        assert_eq!((state.adaptive_level, state.wave_deaths), (0, 0));
+        assert_eq!(state.asteroids.len(), 6);
+
+        // Without the option the level never moves
+        let mut fixed = PlayingState::new_seeded(800.0, 600.0, 38);
+        clear_wave(&mut fixed);
+        assert_eq!(fixed.adaptive_level, 0);
+    }
+
+    // === Requirement: Level Packs ===
+
+    const LEVEL: &str = r#"
+name = "Trial"
+
+[[wave]]
+[[wave.rocks]]
+size = "small"
+position = [100, 100]
+velocity = [0, 0]
+
+[[wave]]
+time_limit = 2
+win = "survive"
+[[wave.rocks]]
+size = "large"
+count = 2
+position = [700, 500]
+velocity = [0, 0]
+
+[[wave]]
+time_limit = 1
+win = { score = 500 }
+[[wave.rocks]]
+size = "medium"
+position = [700, 100]
+velocity = [0, 0]
+"#;
+
+    /// Idle until the wave changes or the game ends.
+    fn play_wave(state: &mut PlayingState) -> Option<GameState> {
+        let idle = InputState::default();
+        let wave = state.wave;
+        while state.wave == wave {
+            if let Some(next) = state.update(&idle, TIMESTEP, 800.0, 600.0).state {
+                return Some(next);
+            }
+        }
+        None
+    }
+
+    // Scenario: Level waves replace generated ones and follow their win conditions
+    #[test]
+    fn test_level_waves() {
+        let level = LevelPack::from_toml(LEVEL).unwrap();
+        let mut state = PlayingState::new_seeded(800.0, 600.0, 39);
+        state.set_level(level.clone(), 800.0, 600.0);
+        assert_eq!(state.asteroids.len(), 1);
+        assert_eq!(state.asteroids[0].position, Vec2::new(100.0, 100.0));
+        assert_eq!(one_player_hud(&state), "Score: 0  ▲ ▲ ▲   Wave 1/3");
+
+        // Clear: shoot the only rock
+        state.asteroids.clear();
+        assert_eq!(play_wave(&mut state), None);
+        assert_eq!(state.wave, 2);
+        assert_eq!(state.asteroids.len(), 2);
+        let bonus = scoring::NO_DEATH_BONUS + 59 * scoring::SPEED_BONUS;
+        let hud = format!("Score: {}  ▲ ▲ ▲   Wave 2/3 0:02", bonus);
+        assert_eq!(one_player_hud(&state), hud);
+
+        // Survive: the rocks go when the time is up
+        assert_eq!(play_wave(&mut state), None);
+        assert_eq!(state.wave, 3);
+        assert!(state.score > bonus, "surviving earns wave bonuses too");
+        state.score = 0;
+
+        // Score: running out of time first loses the level
+        let mut failed = state.clone();
+        assert_eq!(play_wave(&mut failed), Some(GameState::GameOver));
+        assert!(!failed.level_complete);
+        assert_eq!(failed.wave_time_left(), Some(0.0));
+
+        // Reaching the score wins the last wave and the level
+        state.score = 500;
+        assert_eq!(play_wave(&mut state), Some(GameState::GameOver));
+        assert!(state.level_complete);
+        assert!(state.asteroids.is_empty());
+    }
+
+    // Scenario: Level games report progress and are not ranked
+    #[test]
+    fn test_level_game_results() {
+        let board = ModeHighScores::new().shared();
+        let mut game = Game::new(800.0, 600.0);
+        game.high_scores = Some(SharedHighScores::clone(&board));
+        game.level = Some(LevelPack::from_toml(LEVEL).unwrap());
+        game.start_game();
+        let playing = game.playing.as_mut().unwrap();
+        assert_eq!(playing.level.as_ref().unwrap().name, "Trial");
+        assert_eq!(playing.asteroids.len(), 1);
+        playing.score = 300;
+        game.game_over();
+        assert!(board.lock().unwrap().is_empty());
+        let Scene::GameOver { results, .. } = game.scene() else {
+            panic!("expected game over");
+        };
+        assert_eq!(results, vec!["Score: 300", "Trial: wave 1 of 3"]);
+
+        game.start_game();
+        game.playing.as_mut().unwrap().level_complete = true;
+        game.game_over();
+        assert_eq!(game.final_level.as_deref(), Some("Trial: complete!"));
+
+        // The level comes back with a save
+        game.start_game();
+        let save = game.snapshot().unwrap();
+        let mut other = Game::new(800.0, 600.0);
+        other.restore(SaveGame::from_json(&save.to_json()).unwrap());
+        assert_eq!(other.playing.unwrap().level, game.level);
+    }
+
+    // === Requirement: Level Editor ===
+
+    fn editor_game(name: &str) -> (Game, PathBuf) {
+        let path = std::env::temp_dir()
+            .join(format!(
+                "tuisteroids-editor-{}-{}",
+                std::process::id(),
+                name
+            ))
+            .join("level.toml");
+        let mut game = Game::new(800.0, 600.0);
+        game.level_path = Some(path.clone());
+        (game, path)
+    }
+
+    fn click(game: &mut Game, x: f64, y: f64) {
+        game.pointer(Pointer::Press(
+            crossterm::event::MouseButton::Left,
+            Vec2::new(x, y),
+        ));
+        game.pointer(Pointer::Release);
+    }
+
+    // Scenario: E opens the editor, which saves the level and reopens it
+    #[test]
+    fn test_editor_from_menu() {
+        // Without a level file E starts a game like any other key
+        let mut game = Game::new(800.0, 600.0);
+        let Scene::Menu { editor, .. } = game.scene() else {
+            panic!("expected menu");
+        };
+        assert!(!editor);
+        game.handle_key(KeyCode::Char('e'));
+        assert_eq!(game.state, GameState::Playing);
+
+        let (mut game, path) = editor_game("menu");
+        assert!(matches!(game.scene(), Scene::Menu { editor: true, .. }));
+        game.handle_key(KeyCode::Char('e'));
+        assert_eq!(game.state, GameState::Editing);
+        assert!(game.demo.is_none());
+        click(&mut game, 100.0, 100.0);
+        click(&mut game, 300.0, 100.0);
+        let Scene::Editor { status, help, .. } = game.scene() else {
+            panic!("expected editor");
+        };
+        assert!(status.contains("Rocks: 2"), "{}", status);
+        assert_eq!(help, editor::HELP);
+
+        game.handle_key(KeyCode::Char('s'));
+        assert_eq!(
+            game.notice.as_deref(),
+            Some(format!("SAVED {}", path.display()).as_str())
+        );
+        assert_eq!(LevelPack::load(&path).unwrap().waves[0].rocks.len(), 2);
+
+        // The mouse does nothing outside the editor
+        game.handle_key(KeyCode::Char('q'));
+        assert_eq!(game.state, GameState::Menu);
+        assert!(game.editor.is_none() && game.demo.is_some());
+        click(&mut game, 500.0, 500.0);
+
+        game.handle_key(KeyCode::Char('e'));
+        assert_eq!(game.editor.as_ref().unwrap().wave().rocks.len(), 2);
+
+        // A broken file is reported and not opened
+        game.handle_key(KeyCode::Esc);
+        std::fs::write(&path, "[[wave]]\nbogus = 1\n").unwrap();
+        game.handle_key(KeyCode::Char('E'));
+        assert_eq!(game.state, GameState::Menu);
+        assert!(game.notice.as_deref().unwrap().starts_with("CANNOT EDIT:"));
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    // Scenario: Test play starts at once and comes back to the editor
+    #[test]
+    fn test_editor_test_play() {
+        let (mut game, _) = editor_game("test-play");
+        game.open_editor().unwrap();
+        game.handle_key(KeyCode::Enter);
+        assert_eq!(game.state, GameState::Editing);
+        assert_eq!(game.notice.as_deref(), Some("NO ROCKS TO PLAY"));
+
+        click(&mut game, 100.0, 100.0);
+        game.handle_key(KeyCode::Char('n'));
+        click(&mut game, 700.0, 500.0);
+        click(&mut game, 700.0, 100.0);
+        game.handle_key(KeyCode::Enter);
+        assert_eq!(game.state, GameState::Playing);
+        let playing = game.playing.as_ref().unwrap();
+        assert_eq!(playing.level.as_ref().unwrap().waves.len(), 1);
+        assert_eq!(playing.asteroids.len(), 2);
+        assert_eq!(playing.asteroids[0].position, Vec2::new(700.0, 500.0));
+        // Test games are not saved
+        assert!(game.snapshot().is_none());
+
+        // Quitting goes back to the editor
+        game.feed_keyboard(&InputState {
+            quit: true,
+            ..Default::default()
+        });
+        let result = game.tick(TIMESTEP);
+        assert_eq!(result.state, Some(GameState::Editing));
+        assert_eq!(game.state, GameState::Editing);
+        assert!(game.playing.is_none());
+        let Scene::Editor { status, .. } = game.scene() else {
+            panic!("expected editor");
+        };
+        assert!(status.ends_with("TEST ENDED ON WAVE 1"), "{}", status);
+
+        // Clearing the last wave completes the level
+        game.feed_keyboard(&InputState::default());
+        game.handle_key(KeyCode::Char('['));
+        game.handle_key(KeyCode::Enter);
+        game.playing.as_mut().unwrap().wave = 2;
+        game.playing.as_mut().unwrap().asteroids.clear();
+        for _ in 0..60 {
+            if game.tick(TIMESTEP).state.is_some() {
+                break;
+            }
+        }
+        assert_eq!(game.state, GameState::Editing);
+        assert_eq!(game.notice.as_deref(), Some("LEVEL COMPLETE"));
+        assert_eq!(game.editor.as_ref().unwrap().waves.len(), 2);
+    }
+
+    // Scenario: The frame driver sends only initial presses to the editor
+    #[test]
+    fn test_editor_keys_through_driver() {
+        let (mut game, _) = editor_game("driver");
+        game.open_editor().unwrap();
+        let mut driver = FrameDriver::new();
+        assert!(!driver.press(&mut game, KeyCode::Tab, false));
+        assert_eq!(game.editor.as_ref().unwrap().tool, editor::Tool::Rocks);
+        assert!(!driver.press(&mut game, KeyCode::Tab, true));
+        assert_eq!(game.editor.as_ref().unwrap().tool, editor::Tool::Velocity);
+        // Q leaves the editor rather than the game
+        assert!(!driver.press(&mut game, KeyCode::Char('q'), true));
+        assert_eq!(game.state, GameState::Menu);
+    }
+
+    // === Requirement: Power-Up Pickups ===
+
+    /// One-player game with a single small rock far from the ship.
+    fn pickup_state() -> PlayingState {
+        let mut playing = PlayingState::new_seeded(800.0, 600.0, 42);
+        playing.asteroids = vec![small_asteroid_at(Vec2::new(100.0, 100.0))];
+        playing.ship.invulnerable = false;
+        playing
+    }
+
+    // Scenario: Destroyed asteroids sometimes drop pickups
+    #[test]
+    fn test_destroyed_asteroids_drop_pickups() {
+        let mut drops = 0;
+        for seed in 0..100 {
+            let mut playing = PlayingState::new_seeded(800.0, 600.0, seed);
+            let target = Vec2::new(100.0, 100.0);
+            playing.asteroids = vec![small_asteroid_at(target)];
+            playing.bullet_pool.bullets.push(bullet_at(target));
+            playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+            assert!(playing.asteroids.is_empty());
+            drops += playing.pickups.len();
+            for pickup in &playing.pickups {
+                assert!((pickup.position - target).magnitude() < 1.0);
+            }
+        }
+        assert!(drops > 0 && drops < 30, "{drops}");
+    }
+
+    // Scenario: Flying into a pickup starts its power-up
+    #[test]
+    fn test_collect_pickup() {
+        let mut playing = pickup_state();
+        let at_ship = Pickup::new(PowerUp::Spread, playing.ship.position, Vec2::new(0.0, 0.0));
+        playing.pickups.push(at_ship);
+        let result = playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        assert!(playing.pickups.is_empty());
+        assert_eq!(playing.bullet_pool.spread_timer, pickups::POWER_UP_DURATION);
+        assert!(result.audio_events().contains(&AudioEvent::PowerUp));
+        assert_eq!(one_player_hud(&playing), "Score: 0  ▲ ▲ ▲   SPREAD 10");
+
+        let fire = InputState {
+            fire: true,
+            ..Default::default()
+        };
+        playing.update(&fire, TIMESTEP, 800.0, 600.0);
+        assert_eq!(
+            playing.bullet_pool.active_count(),
+            crate::bullets::SPREAD_SHOTS
+        );
+    }
+
+    // Scenario: Uncollected pickups expire
+    #[test]
+    fn test_pickups_expire() {
+        let mut playing = pickup_state();
+        let mut pickup = Pickup::new(
+            PowerUp::Shield,
+            Vec2::new(700.0, 500.0),
+            Vec2::new(0.0, 0.0),
+        );
+        pickup.age = pickups::PICKUP_LIFETIME - TIMESTEP / 2.0;
+        playing.pickups.push(pickup);
+        playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        assert!(playing.pickups.is_empty());
+        assert_eq!(playing.ship.shield_timer, 0.0);
+    }
+
+    // Scenario: Shielded ship survives a rock
+    #[test]
+    fn test_shield_protects_ship() {
+        let mut playing = pickup_state();
+        playing.ship.shield_timer = 5.0;
+        playing.asteroids = vec![small_asteroid_at(playing.ship.position)];
+        let result = playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        assert!(result.state.is_none());
+        assert_eq!(playing.ship.lives, crate::ship::INITIAL_LIVES);
+        assert!(!result.audio_events().contains(&AudioEvent::ShipDestroyed));
+    }
+
+    // Scenario: Destroyed ship loses its power-ups
+    #[test]
+    fn test_death_clears_power_ups() {
+        let mut playing = pickup_state();
+        playing.bullet_pool.rapid_timer = 5.0;
+        playing.asteroids = vec![small_asteroid_at(playing.ship.position)];
+        playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        assert_eq!(playing.ship.lives, crate::ship::INITIAL_LIVES - 1);
+        assert_eq!(playing.bullet_pool.rapid_timer, 0.0);
+    }
+
+    // Scenario: Smart bomb destroys every rock and scores them
+    #[test]
+    fn test_smart_bomb() {
+        let mut playing = pickup_state();
+        playing
+            .asteroids
+            .push(small_asteroid_at(Vec2::new(700.0, 100.0)));
+        let at_ship = Pickup::new(
+            PowerUp::SmartBomb,
+            playing.ship.position,
+            Vec2::new(0.0, 0.0),
+        );
+        playing.pickups.push(at_ship);
+        let result = playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        assert!(playing.asteroids.is_empty());
+        let bonus = playing.tally[0].total();
+        assert_eq!(playing.score - bonus, 2 * AsteroidSize::Small.points());
+        assert!(result.audio_events().contains(&AudioEvent::SmartBomb));
+        assert!(!result.audio_events().contains(&AudioEvent::PowerUp));
+    }
+
+    // Scenario: Piercing bullet carries on through a rock
+    #[test]
+    fn test_piercing_bullet_survives_hit() {
+        let mut playing = pickup_state();
+        let target = Vec2::new(100.0, 100.0);
+        let mut bullet = bullet_at(target);
+        bullet.piercing = true;
+        playing.bullet_pool.bullets.push(bullet);
+        playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        assert!(playing.asteroids.is_empty());
+        let bonus = playing.tally[0].total();
+        assert_eq!(playing.score - bonus, AsteroidSize::Small.points());
+        let bullet = &playing.bullet_pool.bullets[0];
+        assert!(bullet.alive);
+        assert!(!bullet.can_hit());
+    }
+
+    // Scenario: Pickups and shields are drawn
+    #[test]
+    fn test_pickups_and_shield_drawn() {
+        let mut playing = pickup_state();
+        let idle = InputState::default();
+        let plain = playfield(&playing, [&idle, &idle], 800.0, 600.0);
+        playing.ship.shield_timer = 5.0;
+        playing.pickups.push(Pickup::new(
+            PowerUp::Piercing,
+            Vec2::new(600.0, 400.0),
+            Vec2::new(0.0, 0.0),
+        ));
+        let powered = playfield(&playing, [&idle, &idle], 800.0, 600.0);
+        assert_eq!(powered.polygons.len(), plain.polygons.len() + 2);
+    }
+
+    // === Requirement: Shield Action ===
+
+    // Scenario: Shielded ship bounces off a rock, trading momentum
+    #[test]
+    fn test_shield_bounces_ship_off_rock() {
+        let mut playing = pickup_state();
+        let rock = Vec2::new(playing.ship.position.x + 25.0, playing.ship.position.y);
+        playing.asteroids = vec![small_asteroid_at(rock)];
+        playing.ship.velocity = Vec2::new(60.0, 0.0);
+        let shield = InputState {
+            shield: true,
+            ..Default::default()
+        };
+        let result = playing.update(&shield, TIMESTEP, 800.0, 600.0);
+        assert!(result.state.is_none());
+        assert!(result.audio_events().contains(&AudioEvent::ShieldBounce));
+        assert_eq!(playing.ship.lives, crate::ship::INITIAL_LIVES);
+        // Equal masses: the ship stops and the rock carries on
+        assert!(playing.ship.velocity.x.abs() < 1.0);
+        assert!(playing.asteroids[0].velocity.x > 50.0);
+        assert!(playing.ship.shield_energy < crate::ship::SHIELD_MAX_ENERGY);
+    }
+
+    // Scenario: Without the shield the same rock destroys the ship
+    #[test]
+    fn test_unshielded_ship_hit_by_rock() {
+        let mut playing = pickup_state();
+        let rock = Vec2::new(playing.ship.position.x + 20.0, playing.ship.position.y);
+        playing.asteroids = vec![small_asteroid_at(rock)];
+        playing.ship.velocity = Vec2::new(60.0, 0.0);
+        let result = playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        assert!(!result.audio_events().contains(&AudioEvent::ShieldBounce));
+        assert_eq!(playing.ship.lives, crate::ship::INITIAL_LIVES - 1);
+    }
+
+    // Scenario: Holding the shield drains it until it drops
+    #[test]
+    fn test_shield_drains_until_it_drops() {
+        let mut playing = pickup_state();
+        let shield = InputState {
+            shield: true,
+            ..Default::default()
+        };
+        let mut ticks = 0;
+        playing.update(&shield, TIMESTEP, 800.0, 600.0);
+        while playing.ship.shield_up {
+            playing.update(&shield, TIMESTEP, 800.0, 600.0);
+            ticks += 1;
+        }
+        let seconds = ticks as f64 * TIMESTEP;
+        let full = crate::ship::SHIELD_MAX_ENERGY / crate::ship::SHIELD_DRAIN;
+        assert!((seconds - full).abs() < 0.05, "{seconds}");
+    }
+
+    // Scenario: HUD shows the shield's energy bar while it is used
+    #[test]
+    fn test_shield_energy_bar_on_hud() {
+        let mut playing = pickup_state();
+        assert_eq!(one_player_hud(&playing), "Score: 0  ▲ ▲ ▲ ");
+        playing.ship.shield_energy = 0.5;
+        assert_eq!(
+            one_player_hud(&playing),
+            "Score: 0  ▲ ▲ ▲   Shield [████░░░░]"
+        );
+    }
+
+    // Scenario: Raised shield is drawn around the ship
+    #[test]
+    fn test_raised_shield_drawn() {
+        let mut playing = pickup_state();
+        let idle = InputState::default();
+        let plain = playfield(&playing, [&idle, &idle], 800.0, 600.0);
+        playing.ship.shield_up = true;
+        let shielded = playfield(&playing, [&idle, &idle], 800.0, 600.0);
+        assert_eq!(shielded.polygons.len(), plain.polygons.len() + 1);
+    }
+
+    // === Requirement: Combo Scoring ===
+
+    // Scenario: Kills in quick succession score at a rising multiplier
+    #[test]
+    fn test_combo_multiplies_kills() {
+        let mut playing = pickup_state();
+        let rocks = [100.0, 200.0, 300.0].map(|x| Vec2::new(x, 500.0));
+        playing.asteroids = rocks.map(small_asteroid_at).to_vec();
+        playing
+            .asteroids
+            .push(small_asteroid_at(Vec2::new(700.0, 100.0)));
+        playing.bullet_pool.bullets = rocks.map(bullet_at).to_vec();
+        playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        assert_eq!(playing.score, 100 + 100 + 200);
+        let texts: Vec<String> = playing.popups.iter().map(Popup::text).collect();
+        assert_eq!(texts, vec!["100", "100", "200 x2"]);
+        assert_eq!(playing.scoring.multiplier(), 2);
+
+        // Popups float up and fade
+        let idle = InputState::default();
+        let field = playfield(&playing, [&idle, &idle], 800.0, 600.0);
+        assert_eq!(field.labels.len(), 3);
+        for _ in 0..(scoring::POPUP_LIFETIME / TIMESTEP).ceil() as usize {
+            playing.update(&idle, TIMESTEP, 800.0, 600.0);
+        }
+        assert!(playing.popups.is_empty());
+    }
+
+    // Scenario: Dying ends the combo and loses the no-death bonus
+    #[test]
+    fn test_death_ends_combo_in_game() {
+        let mut playing = pickup_state();
+        playing.scoring.kill(100);
+        playing.scoring.kill(100);
+        playing.asteroids = vec![small_asteroid_at(playing.ship.position)];
+        playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        assert_eq!(playing.scoring.multiplier(), 1);
+        assert_eq!(playing.scoring.finish_wave(1).no_death_bonus, 0);
+    }
+
+    // === Requirement: Wave Tally ===
+
+    // Scenario: Clearing a wave awards its bonuses and shows the tally
+    #[test]
+    fn test_wave_tally_on_clear() {
+        let mut game = Game::new(800.0, 600.0);
+        game.start_game();
+        let playing = game.playing.as_mut().unwrap();
+        playing.asteroids = vec![small_asteroid_at(Vec2::new(100.0, 100.0))];
+        playing.bullet_pool.bullets = vec![bullet_at(Vec2::new(100.0, 100.0))];
+        playing.scoring.shots = 2;
+        playing.scoring.wave_time = 10.2;
+        let result = playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        assert_eq!(result.state, None);
+        let tally = playing.tally[0].clone();
+        assert_eq!(tally.accuracy, Some(50));
+        assert_eq!(tally.speed_bonus, 49 * scoring::SPEED_BONUS);
+        assert_eq!(playing.score, 100 + 500 + scoring::NO_DEATH_BONUS + 980);
+        let Scene::Playing { tally: lines, .. } = game.scene() else {
+            panic!("expected playing");
+        };
+        assert_eq!(lines[0], "WAVE 1 CLEAR");
+        assert_eq!(lines.last().unwrap(), "Bonus              2480");
+
+        // The tally goes when the next wave starts, and the count starts over
+        let playing = game.playing.as_mut().unwrap();
+        while playing.wave == 1 {
+            playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        }
+        assert!(playing.tally.is_empty());
+        assert_eq!(playing.scoring.shots + playing.scoring.hits, 0);
+    }
+
+    // Scenario: A ship out of a two-ship game earns no bonus
+    #[test]
+    fn test_out_ship_gets_no_bonus() {
+        let mut playing = pickup_state();
+        playing.add_player_two(
+            Multiplayer::CoOp {
+                friendly_fire: false,
+            },
+            800.0,
+            600.0,
+        );
+        playing.player_two.as_mut().unwrap().ship.lives = 0;
+        playing.asteroids.clear();
+        playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        assert_eq!(playing.tally.len(), 2);
+        assert!(playing.tally[0].total() > 0);
+        assert_eq!(playing.tally[1].total(), 0);
+        assert_eq!(playing.player_two.unwrap().score, 0);
+    }
+
+    // === Requirement: Session Statistics ===
+
+    // Scenario: Flying, firing and thrusting are counted
+    #[test]
+    fn test_stats_track_play() {
+        let mut playing = pickup_state();
+        let input = InputState {
+            thrust: true,
+            fire: true,
+            ..Default::default()
+        };
+        for _ in 0..30 {
+            playing.update(&input, TIMESTEP, 800.0, 600.0);
+        }
+        let stats = &playing.stats;
+        assert!(stats.shots > 0);
+        assert!((stats.thrust_time - 0.5).abs() < 1e-9);
+        assert!((stats.life_time - 0.5).abs() < 1e-9);
+        assert!(stats.distance > 0.0);
+    }
+
+    // Scenario: Kills are counted by size, and only bullet kills as hits
+    #[test]
+    fn test_stats_count_kills() {
+        let mut playing = pickup_state();
+        playing.bullet_pool.bullets = vec![bullet_at(Vec2::new(100.0, 100.0))];
+        playing
+            .asteroids
+            .push(small_asteroid_at(Vec2::new(700.0, 500.0)));
+        playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        assert_eq!((playing.stats.hits, playing.stats.kills), (1, [0, 0, 1]));
+
+        playing.pickups.push(Pickup::new(
+            PowerUp::SmartBomb,
+            playing.ship.position,
+            Vec2::new(0.0, 0.0),
+        ));
+        playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        assert_eq!((playing.stats.hits, playing.stats.kills), (1, [0, 0, 2]));
+        assert_eq!(playing.stats.waves_cleared, 1);
+    }
+
+    // Scenario: A rock passing close by is a near miss; one that hits ends a life
+    #[test]
+    fn test_stats_near_miss_and_lives() {
+        let mut playing = pickup_state();
+        let gap = crate::ship::SHIP_RADIUS + AsteroidSize::Small.radius() + 5.0;
+        let mut rock = small_asteroid_at(playing.ship.position + Vec2::new(gap, 0.0));
+        rock.velocity = Vec2::new(300.0, 0.0);
+        playing.asteroids = vec![rock, small_asteroid_at(Vec2::new(100.0, 100.0))];
+        for _ in 0..10 {
+            playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        }
+        assert_eq!(playing.stats.near_misses, 1);
+
+        playing.asteroids = vec![small_asteroid_at(playing.ship.position)];
+        playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        assert_eq!(playing.stats.lives.len(), 1);
+        assert!((playing.stats.lives[0] - 11.0 * TIMESTEP).abs() < 1e-9);
+        assert_eq!(playing.stats.near_misses, 1);
+    }
+
+    // Scenario: A rock that leaves while the ship is shielded is no near miss
+    #[test]
+    fn test_stats_no_near_miss_while_shielded() {
+        let mut playing = pickup_state();
+        let gap = crate::ship::SHIP_RADIUS + AsteroidSize::Small.radius() + 5.0;
+        let mut rock = small_asteroid_at(playing.ship.position + Vec2::new(gap, 0.0));
+        rock.velocity = Vec2::new(300.0, 0.0);
+        playing.asteroids = vec![rock, small_asteroid_at(Vec2::new(100.0, 100.0))];
+        playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        assert!(playing.stats.close_call);
+
+        playing.ship.shield_timer = 5.0;
+        for _ in 0..10 {
+            playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        }
+        playing.ship.shield_timer = 0.0;
+        playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        assert_eq!(playing.stats.near_misses, 0);
+    }
+
+    // === Requirement: Post-Game Summary ===
+
+    // Scenario: Game over shows every player's summary and adds it to the lifetime file
+    #[test]
+    fn test_game_over_summary_and_lifetime() {
+        let dir =
+            std::env::temp_dir().join(format!("tuisteroids-game-stats-{}", std::process::id()));
+        let path = dir.join(stats::STATS_FILE);
+        let mut game = Game::new(800.0, 600.0);
+        game.stats_path = Some(path.clone());
+        game.start_simultaneous(Multiplayer::Versus);
+        let playing = game.playing.as_mut().unwrap();
+        playing.stats.shots = 4;
+        playing.player_two.as_mut().unwrap().stats.shots = 6;
+        game.game_over();
+        let Scene::GameOver { summary, .. } = game.scene() else {
+            panic!("expected game over");
+        };
+        assert_eq!(summary[0], "GAME SUMMARY       1UP     2UP");
+        assert_eq!(summary[1], "Shots fired          4       6");
+        let lifetime = LifetimeStats::load(&path).unwrap();
+        assert_eq!((lifetime.games, lifetime.shots), (2, 10));
+
+        game.start_game();
+        game.game_over();
+        assert_eq!(LifetimeStats::load(&path).unwrap().games, 3);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    // Scenario: S on the menu shows the lifetime stats until a key is pressed
+    #[test]
+    fn test_menu_shows_lifetime_stats() {
+        let mut game = Game::new(800.0, 600.0);
+        game.handle_key(KeyCode::Char('s'));
+        assert_eq!(
+            game.state,
+            GameState::Playing,
+            "no stats file, so S starts a game"
+        );
+
+        let dir =
+            std::env::temp_dir().join(format!("tuisteroids-menu-stats-{}", std::process::id()));
+        let mut game = Game::new(800.0, 600.0);
+        game.stats_path = Some(dir.join(stats::STATS_FILE));
+        assert!(matches!(game.scene(), Scene::Menu { stats: true, .. }));
+        game.handle_key(KeyCode::Char('S'));
+        assert_eq!(game.state, GameState::Stats);
+        let Scene::Stats { lines } = game.scene() else {
+            panic!("expected stats");
+        };
+        assert_eq!(lines[0], "Games played         0");
+        assert!(!game.handle_key(KeyCode::Char('q')));
+        assert_eq!(game.state, GameState::Menu);
+
+        // An unreadable file is reported on the menu
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join(stats::STATS_FILE), "not json").unwrap();
+        game.handle_key(KeyCode::Char('s'));
+        assert_eq!(game.state, GameState::Menu);
+        assert!(game
+            .notice
+            .as_deref()
+            .unwrap()
+            .starts_with("CANNOT READ STATS"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    // === Requirement: Game Events ===
+
+    // Scenario: Update reports flying, firing, kills, points and waves in order
+    #[test]
+    fn test_update_reports_events() {
+        let mut playing = pickup_state();
+        let input = InputState {
+            thrust: true,
+            fire: true,
+            ..InputState::default()
+        };
+        let events = playing.update(&input, TIMESTEP, 800.0, 600.0).events;
+        assert_eq!(events.len(), 2);
+        assert!(matches!(
+            events[0],
+            GameEvent::ShipFlew {
+                player: 0,
+                thrusting: true,
+                ..
+            }
+        ));
+        assert_eq!(
+            events[1],
+            GameEvent::BulletFired {
+                player: 0,
+                bullets: 1
+            }
+        );
+
+        playing.bullet_pool.bullets = vec![bullet_at(Vec2::new(100.0, 100.0))];
+        let events = playing
+            .update(&InputState::default(), TIMESTEP, 800.0, 600.0)
+            .events;
+        assert_eq!(
+            events[1..4],
+            [
+                GameEvent::AsteroidDestroyed {
+                    size: AsteroidSize::Small,
+                    position: Vec2::new(100.0, 100.0),
+                    by: Destroyer::Bullet { player: 0 },
+                },
+                GameEvent::PointsAwarded {
+                    player: 0,
+                    points: AsteroidSize::Small.points(),
+                },
+                GameEvent::WaveCleared { player: 0 },
+            ]
+        );
+
+        let mut started = Vec::new();
+        while playing.wave == 1 {
+            started.extend(
+                playing
+                    .update(&InputState::default(), TIMESTEP, 800.0, 600.0)
+                    .events,
+            );
+        }
+        assert!(started.contains(&GameEvent::WaveStarted { wave: 2 }));
+    }
+
+    // Scenario: Losing a ship is reported for its player and who shot it
+    #[test]
+    fn test_update_reports_ship_destroyed() {
+        let mut playing = pickup_state();
+        playing.asteroids = vec![small_asteroid_at(playing.ship.position)];
+        let events = playing
+            .update(&InputState::default(), TIMESTEP, 800.0, 600.0)
+            .events;
+        assert!(events.contains(&GameEvent::ShipDestroyed {
+            player: 0,
+            by: None
+        }));
+
+        let mut playing = two_ship_state(Multiplayer::Versus);
+        let target = playing.player_two.as_ref().unwrap().ship.position;
+        playing.bullet_pool.bullets.push(bullet_at(target));
+        let idle = InputState::default();
+        let events = playing
+            .update_players([&idle, &idle], TIMESTEP, 800.0, 600.0)
+            .events;
+        assert!(events.contains(&GameEvent::ShipDestroyed {
+            player: 1,
+            by: Some(0)
+        }));
+    }
+
+    // Scenario: A smart bomb's rocks are credited to the player who collected it
+    #[test]
+    fn test_update_reports_smart_bomb() {
+        let mut playing = pickup_state();
+        let at_ship = Pickup::new(
+            PowerUp::SmartBomb,
+            playing.ship.position,
+            Vec2::new(0.0, 0.0),
+        );
+        playing.pickups.push(at_ship);
+        let result = playing.update(&InputState::default(), TIMESTEP, 800.0, 600.0);
+        assert!(result.events.contains(&GameEvent::PowerUpCollected {
+            player: 0,
+            kind: PowerUp::SmartBomb
+        }));
+        assert!(result.events.contains(&GameEvent::AsteroidDestroyed {
+            size: AsteroidSize::Small,
+            position: Vec2::new(100.0, 100.0),
+            by: Destroyer::SmartBomb { player: 0 },
+        }));
+        assert_eq!(result.audio_events(), vec![AudioEvent::SmartBomb]);
+    }
+
+    // === Requirement: Achievement Unlocks ===
+
+    // Scenario: An unlock shows a toast and is saved, and unlocks only once
+    #[test]
+    fn test_game_unlocks_achievements() {
+        let dir = std::env::temp_dir().join(format!(
+            "tuisteroids-game-achievements-{}",
+            std::process::id()
+        ));
+        let path = dir.join(achievements::ACHIEVEMENTS_FILE);
+        let mut game = Game::new(800.0, 600.0);
+        game.achievements_path = Some(path.clone());
+        game.start_game();
+        game.playing.as_mut().unwrap().asteroids.clear();
+        game.tick(TIMESTEP);
+        let Scene::Playing { toast, .. } = game.scene() else {
+            panic!("expected playing");
+        };
+        assert_eq!(
+            toast.as_deref(),
+            Some("ACHIEVEMENT: Drifter - Clear a wave without thrusting")
+        );
+        let saved = Achievements::load(&path).unwrap();
+        assert!(saved.unlocked.contains("drifter"));
+
+        for _ in 0..(achievements::TOAST_TIME / TIMESTEP) as usize + 1 {
+            game.tick(TIMESTEP);
+        }
+        assert!(matches!(game.scene(), Scene::Playing { toast: None, .. }));
+
+        // A new game knows it is already unlocked
+        let mut game = Game::new(800.0, 600.0);
+        game.achievements_path = Some(path.clone());
+        game.start_game();
+        game.playing.as_mut().unwrap().asteroids.clear();
+        game.tick(TIMESTEP);
+        assert!(game.toasts.is_empty());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    // Scenario: Rewound games and test plays unlock nothing
+    #[test]
+    fn test_assisted_game_unlocks_nothing() {
+        let dir = std::env::temp_dir().join(format!(
+            "tuisteroids-assisted-achievements-{}",
+            std::process::id()
+        ));
+        let mut game = Game::new(800.0, 600.0);
+        game.achievements_path = Some(dir.join(achievements::ACHIEVEMENTS_FILE));
+        game.start_game();
+        game.assisted = true;
+        game.playing.as_mut().unwrap().asteroids.clear();
+        game.tick(TIMESTEP);
+        assert!(game.toasts.is_empty());
+        assert!(game.achievements.unlocked.is_empty());
+        assert!(!dir.exists());
+    }
+
+    // Scenario: The lifetime stats screen lists achievements
+    #[test]
+    fn test_stats_screen_lists_achievements() {
+        let dir = std::env::temp_dir().join(format!(
+            "tuisteroids-stats-achievements-{}",
+            std::process::id()
+        ));
+        let mut game = Game::new(800.0, 600.0);
+        game.stats_path = Some(dir.join(stats::STATS_FILE));
+        game.achievements_path = Some(dir.join(achievements::ACHIEVEMENTS_FILE));
+        let mut unlocked = Achievements::default();
+        unlocked.unlocked.insert("veteran".to_string());
+        unlocked
+            .write(&dir.join(achievements::ACHIEVEMENTS_FILE))
+            .unwrap();
+        game.handle_key(KeyCode::Char('s'));
+        let Scene::Stats { lines } = game.scene() else {
+            panic!("expected stats");
+        };
+        assert!(lines.contains(&"Achievements       1/4".to_string()));
+        assert!(lines.contains(&"[x] Veteran   Reach wave 10".to_string()));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    // === Requirement: Frame Driver ===
+
+    // Scenario: Menu keys act only on initial presses
+    #[test]
+    fn test_frame_driver_menu_keys() {
+        let mut game = Game::new(800.0, 600.0);
+        let mut driver = FrameDriver::new();
+        assert!(!driver.press(&mut game, KeyCode::Enter, false));
+        assert_eq!(game.state, GameState::Menu);
+        assert!(!driver.press(&mut game, KeyCode::Enter, true));
+        assert_eq!(game.state, GameState::Playing);
+
+        game.state = GameState::Menu;
+        assert!(driver.press(&mut game, KeyCode::Char('q'), true));
+    }
+
+    // Scenario: Held keys steer the ship through fixed timesteps
+    #[test]
+    fn test_frame_driver_steers() {
+        let mut game = Game::new(800.0, 600.0);
+        let mut driver = FrameDriver::new();
+        driver.press(&mut game, KeyCode::Enter, true);
+        let heading = game.playing.as_ref().unwrap().ship.rotation;
+        driver.press(&mut game, KeyCode::Right, true);
+        driver.frame(&mut game, TIMESTEP * 3.0, false);
+        let playing = game.playing.as_ref().unwrap();
+        assert!(playing.frame_count >= 2);
+        assert_ne!(playing.ship.rotation, heading);
+        assert!(game.last_input.rotate_right);
+
+        // With keyboard enhancement a release stops the turn at once
+        driver.release(&game, KeyCode::Right);
+        driver.frame(&mut game, TIMESTEP, true);
+        assert!(!game.last_input.rotate_right);
+    }
+
+    // === Requirement: Mouse Controls ===
+
+    fn mouse_event(kind: crossterm::event::MouseEventKind, column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+        }
+    }
+
+    // Scenario: The menu switches between keyboard and mouse controls
+    #[test]
+    fn test_menu_toggles_controls() {
+        let mut game = Game::new(800.0, 600.0);
+        assert_eq!(game.controls, ControlScheme::Keyboard);
+        game.handle_key(KeyCode::Char('k'));
+        assert_eq!(game.state, GameState::Menu);
+        let Scene::Menu { controls, .. } = game.scene() else {
+            panic!("expected menu");
+        };
+        assert_eq!(controls, ControlScheme::Mouse);
+        assert!(
+            !game.wants_mouse(),
+            "the menu leaves the mouse to the terminal"
+        );
+        game.handle_key(KeyCode::Enter);
+        assert!(game.wants_mouse());
+
+        game.state = GameState::Menu;
+        game.handle_key(KeyCode::Char('K'));
+        assert_eq!(game.controls, ControlScheme::Keyboard);
+        game.handle_key(KeyCode::Enter);
+        assert!(!game.wants_mouse());
+    }
+
+    // Scenario: The mouse aims, fires and thrusts
+    #[test]
+    fn test_frame_driver_mouse_controls() {
+        use crossterm::event::{MouseButton, MouseEventKind};
+        let mut game = Game::new(800.0, 600.0);
+        game.set_controls(ControlScheme::Mouse);
+        let mut driver = FrameDriver::new();
+        driver.press(&mut game, KeyCode::Enter, true);
+        // Right of the ship, which starts in the middle pointing up
+        driver.mouse(
+            &mut game,
+            &mouse_event(MouseEventKind::Moved, 70, 15),
+            80,
+            31,
+        );
+        driver.frame(&mut game, TIMESTEP, false);
+        assert!(game.last_input.rotate_right);
+        assert!(!game.last_input.fire && !game.last_input.thrust);
+
+        let left = MouseEventKind::Down(MouseButton::Left);
+        let right = MouseEventKind::Down(MouseButton::Right);
+        driver.mouse(&mut game, &mouse_event(left, 70, 15), 80, 31);
+        driver.mouse(&mut game, &mouse_event(right, 70, 15), 80, 31);
+        driver.frame(&mut game, TIMESTEP, false);
+        assert!(game.last_input.fire && game.last_input.thrust);
+        assert_eq!(game.playing.as_ref().unwrap().bullet_pool.bullets.len(), 1);
+
+        let released = MouseEventKind::Up(MouseButton::Right);
+        driver.mouse(&mut game, &mouse_event(released, 70, 15), 80, 31);
+        driver.frame(&mut game, TIMESTEP, false);
+        assert!(!game.last_input.fire && !game.last_input.thrust);
+    }
+
+    // Scenario: Keyboard controls ignore the mouse
+    #[test]
+    fn test_keyboard_controls_ignore_mouse() {
+        use crossterm::event::{MouseButton, MouseEventKind};
+        let mut game = Game::new(800.0, 600.0);
+        let mut driver = FrameDriver::new();
+        driver.press(&mut game, KeyCode::Enter, true);
+        let click = MouseEventKind::Down(MouseButton::Left);
+        driver.mouse(&mut game, &mouse_event(click, 70, 15), 80, 31);
+        driver.frame(&mut game, TIMESTEP, false);
+        assert_eq!(game.last_input, InputState::default());
+        assert!(game
+            .playing
+            .as_ref()
+            .unwrap()
+            .bullet_pool
+            .bullets
+            .is_empty());
+    }
+
+    // === Requirement: Quicksave and Continue ===
+
+    fn save_game(name: &str) -> (Game, PathBuf) {
+        let path = std::env::temp_dir()
+            .join(format!("tuisteroids-game-{}-{}", std::process::id(), name))
+            .join("quicksave.json");
+        let mut game = Game::new(800.0, 600.0);
+        game.save_path = Some(path.clone());
+        (game, path)
+    }
+
+    fn remove_save(path: &std::path::Path) {
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    // Scenario: Quicksave writes the game and continue resumes it exactly
+    #[test]
+    fn test_quicksave_and_continue() {
+        let (mut game, path) = save_game("continue");
+        assert!(!game.has_saved_game());
+        game.start_two_player();
+        game.playing = Some(PlayingState::new_seeded(800.0, 600.0, 9));
+        for _ in 0..300 {
+            game.tick(TIMESTEP);
+        }
+        assert!(game.quicksave());
+        assert_eq!(game.notice.as_deref(), Some("GAME SAVED"));
+        let Scene::Playing { banner, .. } = game.scene() else {
+            panic!("expected playing");
+        };
+        assert_eq!(banner.as_deref(), Some("GAME SAVED"));
+
+        // Keep playing the original, then continue the save in a fresh game
+        let saved_frame = game.playing.as_ref().unwrap().frame_count;
+        for _ in 0..600 {
+            game.tick(TIMESTEP);
+        }
+        let mut resumed = Game::new(1000.0, 600.0);
+        resumed.save_path = Some(path.clone());
+        assert!(resumed.has_saved_game());
+        let Scene::Menu { saved_game, .. } = resumed.scene() else {
+            panic!("expected menu");
+        };
+        assert!(saved_game);
+        assert!(!resumed.handle_key(KeyCode::Char('r')));
+        assert_eq!(resumed.state, GameState::Playing);
+        assert_eq!(resumed.world_width, 800.0);
+        assert!(resumed.turns.is_some());
+        assert_eq!(resumed.playing.as_ref().unwrap().frame_count, saved_frame);
+        for _ in 0..600 {
+            resumed.tick(TIMESTEP);
+        }
+        assert_eq!(
+            crate::netplay::state_hash(resumed.playing.as_ref().unwrap()),
+            crate::netplay::state_hash(game.playing.as_ref().unwrap())
+        );
+        remove_save(&path);
+    }
+
+    // Scenario: Notices fade after a moment
+    #[test]
+    fn test_notice_expires() {
+        let (mut game, path) = save_game("notice");
+        game.start_game();
+        assert!(game.quicksave());
+        for _ in 0..(NOTICE_TIME / TIMESTEP) as usize + 1 {
+            game.tick(TIMESTEP);
+        }
+        assert!(game.notice.is_none());
+        remove_save(&path);
+    }
+
+    // Scenario: Only local games in progress can be saved
+    #[test]
+    fn test_quicksave_unavailable() {
+        let mut game = Game::new(800.0, 600.0);
+        game.start_game();
+        assert!(!game.quicksave());
+        assert!(!game.continue_saved());
+
+        let (mut game, path) = save_game("unavailable");
+        assert!(game.snapshot().is_none());
+        assert!(!game.quicksave());
+        let (host, joiner) = net_pair();
+        let _joiner = joiner.join().unwrap();
+        game.start_netplay(host);
+        assert!(game.snapshot().is_none());
+        assert!(!game.quicksave());
+        assert!(!path.exists());
+
+        // Without a save R starts a new game like any other key
+        let (mut game, _) = save_game("unavailable");
+        game.handle_key(KeyCode::Char('R'));
+        assert_eq!(game.state, GameState::Playing);
+        assert_eq!(game.playing.as_ref().unwrap().frame_count, 0);
+    }
+
+    // Scenario: Save failures and unreadable saves are reported
+    #[test]
+    fn test_save_errors() {
+        let (mut game, path) = save_game("errors");
+        std::fs::create_dir_all(&path).unwrap();
+        game.start_game();
+        assert!(!game.quicksave());
+        assert!(game.notice.as_deref().unwrap().starts_with("SAVE FAILED"));
+        remove_save(&path);
+
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "{\"version\":1}").unwrap();
+        let (mut game, _) = save_game("errors");
+        game.handle_key(KeyCode::Char('r'));
+        assert_eq!(game.state, GameState::Menu);
+        let Scene::Menu { notice, .. } = game.scene() else {
+            panic!("expected menu");
+        };
+        assert!(notice.unwrap().starts_with("CANNOT CONTINUE"));
+        remove_save(&path);
+    }
+
+    // Scenario: F5 quicksaves during play
+    #[test]
+    fn test_frame_driver_quicksave() {
+        let (mut game, path) = save_game("driver");
+        let mut driver = FrameDriver::new();
+        driver.press(&mut game, KeyCode::Enter, true);
+        driver.press(&mut game, QUICKSAVE_KEY, false);
+        assert!(!path.exists());
+        driver.press(&mut game, QUICKSAVE_KEY, true);
+        assert!(path.exists());
+        remove_save(&path);
+    }
+
+    // === Requirement: Rewind ===
+
+    // Scenario: Rewind is offered only outside competitive modes
+    #[test]
+    fn test_rewind_modes() {
+        let mut game = Game::new(800.0, 600.0);
+        assert!(game.rewind.is_none());
+        game.start_game();
+        assert!(game.rewind.is_some());
+        game.start_simultaneous(Multiplayer::CoOp {
+            friendly_fire: false,
+        });
+        assert!(game.rewind.is_some());
+        game.start_simultaneous(Multiplayer::Versus);
+        assert!(game.rewind.is_none());
+        game.start_two_player();
+        assert!(game.rewind.is_none());
+        let (host, joiner) = net_pair();
+        let _joiner = joiner.join().unwrap();
+        game.start_netplay(host);
+        assert!(game.rewind.is_none());
+    }
+
+    // Scenario: Holding rewind scrubs back and play resumes from there
+    #[test]
+    fn test_rewind_scrubs_and_resumes() {
+        let mut game = Game::new(800.0, 600.0);
+        game.start_game();
+        game.playing = Some(PlayingState::new_seeded(800.0, 600.0, 6));
+        game.feed_keyboard(&InputState {
+            thrust: true,
+            rotate_left: true,
+            ..InputState::default()
+        });
+        let mut hashes = Vec::new();
+        for _ in 0..120 {
+            hashes.push(crate::netplay::state_hash(game.playing.as_ref().unwrap()));
+            game.tick(TIMESTEP);
+        }
+
+        game.rewinding = true;
+        for _ in 0..30 {
+            let result = game.tick(TIMESTEP);
+            assert!(result.state.is_none() && result.audio_events().is_empty());
+        }
+        let playing = game.playing.as_ref().unwrap();
+        assert_eq!(playing.frame_count, 60);
+        assert_eq!(crate::netplay::state_hash(playing), hashes[60]);
+        assert!(game.assisted);
+        assert!(!game.last_input.thrust);
+        let Scene::Playing { banner, hud, .. } = game.scene() else {
+            panic!("expected playing");
+        };
+        assert_eq!(banner.as_deref(), Some("<< REWIND"));
+        assert!(hud.ends_with("ASSISTED"));
+
+        game.rewinding = false;
+        game.tick(TIMESTEP);
+        assert_eq!(game.playing.as_ref().unwrap().frame_count, 61);
+        assert_eq!(game.rewind.as_ref().unwrap().newest(), 61);
+
+        // A new game is unassisted again
+        game.start_game();
+        assert!(!game.assisted);
+    }
+
+    // Scenario: Rewound games are not ranked
+    #[test]
+    fn test_assisted_game_not_ranked() {
+        let board = ModeHighScores::new().shared();
+        let mut game = Game::new(800.0, 600.0);
+        game.high_scores = Some(SharedHighScores::clone(&board));
+        game.start_game();
+        game.playing.as_mut().unwrap().score = 500;
+        game.assisted = true;
+        game.game_over();
+        assert!(board.lock().unwrap().is_empty());
+        let Scene::GameOver { note, .. } = game.scene() else {
+            panic!("expected game over");
+        };
+        assert!(note.unwrap().starts_with("Assisted"));
+
+        // The mark survives a save
+        game.start_game();
+        game.assisted = true;
+        let save = game.snapshot().unwrap();
+        let mut other = Game::new(800.0, 600.0);
+        other.restore(save);
+        assert!(other.assisted);
+        assert!(other.rewind.is_some());
+    }
+
+    // Scenario: The rewind key rewinds while held
+    #[test]
+    fn test_frame_driver_rewind_key() {
+        let mut game = Game::new(800.0, 600.0);
+        let mut driver = FrameDriver::new();
+        driver.press(&mut game, KeyCode::Enter, true);
+        driver.frame(&mut game, TIMESTEP * 2.0, false);
+        assert!(!game.rewinding);
+        driver.press(&mut game, REWIND_KEY, true);
+        driver.frame(&mut game, TIMESTEP, false);
+        assert!(game.rewinding);
+
+        // Without enhancement the hold wears off once key events stop
+        for _ in 0..=crate::input::HOLD_FRAMES {
+            driver.frame(&mut game, 0.0, false);
+        }
+        assert!(!game.rewinding);
+
+        // With enhancement it lasts until release
+        driver.press(&mut game, REWIND_KEY, false);
+        for _ in 0..30 {
+            driver.frame(&mut game, 0.0, true);
+        }
+        assert!(game.rewinding);
+        driver.release(&game, REWIND_KEY);
+        driver.frame(&mut game, 0.0, true);
+        assert!(!game.rewinding);
+    }
 }
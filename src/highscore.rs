@@ -0,0 +1,198 @@
+// High score capability: ranked boards of the best scores per mode, shareable between games
+
+use crate::mode::GameMode;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+pub const HIGH_SCORE_SLOTS: usize = 10;
+
+/// One entry on the board.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HighScore {
+    pub name: String,
+    pub score: u32,
+}
+
+/// The best scores, highest first. Ties keep the earlier entry ahead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HighScores {
+    entries: Vec<HighScore>,
+    slots: usize,
+}
+
+/// One board per game mode, each created when its first score arrives.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModeHighScores {
+    boards: BTreeMap<GameMode, HighScores>,
+}
+
+/// Boards shared by several games, e.g. every session on a server.
+pub type SharedHighScores = Arc<Mutex<ModeHighScores>>;
+
+impl Default for HighScores {
+    fn default() -> Self {
+        Self::new(HIGH_SCORE_SLOTS)
+    }
+}
+
+impl HighScores {
+    pub fn new(slots: usize) -> Self {
+        Self {
+            entries: Vec::new(),
+            slots,
+        }
+    }
+
+    pub fn entries(&self) -> &[HighScore] {
+        &self.entries
+    }
+
+    /// Record a score. Returns its rank (0 is the top) if it made the board.
+    /// Scores of zero are not recorded.
+    pub fn submit(&mut self, name: &str, score: u32) -> Option<usize> {
+        if score == 0 {
+            return None;
+        }
+        let rank = self.entries.partition_point(|entry| entry.score >= score);
+        if rank >= self.slots {
+            return None;
+        }
+        self.entries.insert(
+            rank,
+            HighScore {
+                name: name.to_string(),
+                score,
+            },
+        );
+        self.entries.truncate(self.slots);
+        Some(rank)
+    }
+
+    /// Board lines for display, e.g. " 1. ALICE      12500".
+    pub fn lines(&self) -> Vec<String> {
+        self.entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| format!("{:>2}. {:<10} {:>6}", i + 1, entry.name, entry.score))
+            .collect()
+    }
+}
+
+impl ModeHighScores {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wrap the boards for sharing between games.
+    pub fn shared(self) -> SharedHighScores {
+        Arc::new(Mutex::new(self))
+    }
+
+    pub fn board(&self, mode: GameMode) -> Option<&HighScores> {
+        self.boards.get(&mode)
+    }
+
+    /// True if no mode has a score yet.
+    pub fn is_empty(&self) -> bool {
+        self.boards.values().all(|board| board.entries().is_empty())
+    }
+
+    /// Record a score on its mode's board. Returns its rank if it made the board.
+    pub fn submit(&mut self, mode: GameMode, name: &str, score: u32) -> Option<usize> {
+        self.boards.entry(mode).or_default().submit(name, score)
+    }
+
+    /// Board lines for one mode; empty if it has no scores.
+    pub fn lines(&self, mode: GameMode) -> Vec<String> {
+        self.board(mode).map(HighScores::lines).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // === Requirement: High Score Board ===
+
+    // Scenario: Scores are ranked highest first
+    #[test]
+    fn test_ranked() {
+        let mut board = HighScores::default();
+        assert_eq!(board.submit("A", 100), Some(0));
+        assert_eq!(board.submit("B", 300), Some(0));
+        assert_eq!(board.submit("C", 200), Some(1));
+        let scores: Vec<u32> = board.entries().iter().map(|e| e.score).collect();
+        assert_eq!(scores, vec![300, 200, 100]);
+    }
+
+    // Scenario: Ties keep the earlier score ahead
+    #[test]
+    fn test_ties() {
+        let mut board = HighScores::default();
+        board.submit("FIRST", 500);
+        assert_eq!(board.submit("SECOND", 500), Some(1));
+        assert_eq!(board.entries()[0].name, "FIRST");
+    }
+
+    // Scenario: Board keeps only its slots
+    #[test]
+    fn test_full_board() {
+        let mut board = HighScores::new(2);
+        board.submit("A", 100);
+        board.submit("B", 200);
+        assert_eq!(board.submit("C", 50), None);
+        assert_eq!(board.submit("D", 150), Some(1));
+        assert_eq!(board.entries().len(), 2);
+        assert_eq!(board.entries()[1].name, "D");
+    }
+
+    // Scenario: Zero scores are not recorded
+    #[test]
+    fn test_zero_score() {
+        let mut board = HighScores::default();
+        assert_eq!(board.submit("A", 0), None);
+        assert!(board.entries().is_empty());
+    }
+
+    // Scenario: Board lines show rank, name and score
+    #[test]
+    fn test_lines() {
+        let mut board = HighScores::default();
+        board.submit("ALICE", 12500);
+        board.submit("BOB", 300);
+        assert_eq!(
+            board.lines(),
+            vec![" 1. ALICE       12500", " 2. BOB           300"]
+        );
+    }
+
+    // Scenario: Each mode keeps its own board
+    #[test]
+    fn test_boards_per_mode() {
+        let boards = ModeHighScores::new().shared();
+        assert!(boards.lock().unwrap().is_empty());
+        boards
+            .lock()
+            .unwrap()
+            .submit(GameMode::Classic, "ALICE", 500);
+        assert_eq!(
+            boards
+                .lock()
+                .unwrap()
+                .submit(GameMode::Hardcore, "BOB", 100),
+            Some(0)
+        );
+        let boards = boards.lock().unwrap();
+        assert!(!boards.is_empty());
+        assert_eq!(
+            boards.lines(GameMode::Classic),
+            vec![" 1. ALICE         500"]
+        );
+        assert_eq!(
+            boards.board(GameMode::Hardcore).unwrap().entries()[0].name,
+            "BOB"
+        );
+        assert!(boards.lines(GameMode::Zen).is_empty());
+        assert!(boards.board(GameMode::Zen).is_none());
+    }
+}
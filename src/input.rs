@@ -1,6 +1,11 @@
-// Input capability: keyboard input abstraction and key mapping
+// Input capability: keyboard and mouse input abstraction and key mapping
 
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use crate::physics::Vec2;
+use crate::renderer;
+use crossterm::event::{
+    self, Event, KeyCode, KeyEvent, KeyEventKind, MouseButton, MouseEvent, MouseEventKind,
+};
+use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
 /// Game actions abstracted from raw keyboard input.
@@ -10,16 +15,20 @@ pub enum Action {
     RotateRight,
     Thrust,
     Fire,
+    Shield,
     Quit,
 }
 
 /// Current state of all input actions for a single frame.
-#[derive(Debug, Clone, Default)]
+/// Missing fields deserialize as inactive, so bots may send only the actions they use.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct InputState {
     pub rotate_left: bool,
     pub rotate_right: bool,
     pub thrust: bool,
     pub fire: bool,
+    pub shield: bool,
     pub quit: bool,
 }
 
@@ -30,6 +39,7 @@ impl InputState {
             Action::RotateRight => self.rotate_right,
             Action::Thrust => self.thrust,
             Action::Fire => self.fire,
+            Action::Shield => self.shield,
             Action::Quit => self.quit,
         }
     }
@@ -42,11 +52,208 @@ pub fn map_key(code: KeyCode) -> Option<Action> {
         KeyCode::Right => Some(Action::RotateRight),
         KeyCode::Up => Some(Action::Thrust),
         KeyCode::Char(' ') => Some(Action::Fire),
+        KeyCode::Down => Some(Action::Shield),
         KeyCode::Char('q') | KeyCode::Char('Q') => Some(Action::Quit),
         _ => None,
     }
 }
 
+/// Map a crossterm KeyCode to player 2's action in simultaneous two-ship modes:
+/// A/D rotate, W thrusts, S fires, X raises the shield. Quit stays on Q for both players.
+pub fn map_key_player_two(code: KeyCode) -> Option<Action> {
+    match code {
+        KeyCode::Char('a') | KeyCode::Char('A') => Some(Action::RotateLeft),
+        KeyCode::Char('d') | KeyCode::Char('D') => Some(Action::RotateRight),
+        KeyCode::Char('w') | KeyCode::Char('W') => Some(Action::Thrust),
+        KeyCode::Char('s') | KeyCode::Char('S') => Some(Action::Fire),
+        KeyCode::Char('x') | KeyCode::Char('X') => Some(Action::Shield),
+        _ => None,
+    }
+}
+
+/// How player 1 steers: the keyboard alone, or the mouse alongside it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ControlScheme {
+    #[default]
+    Keyboard,
+    /// The ship turns toward the cursor; left-click fires and the right button thrusts.
+    Mouse,
+}
+
+impl ControlScheme {
+    /// Name shown on the menu.
+    pub fn name(self) -> &'static str {
+        match self {
+            ControlScheme::Keyboard => "KEYBOARD",
+            ControlScheme::Mouse => "MOUSE",
+        }
+    }
+
+    /// The other scheme, for the menu toggle.
+    pub fn next(self) -> ControlScheme {
+        match self {
+            ControlScheme::Keyboard => ControlScheme::Mouse,
+            ControlScheme::Mouse => ControlScheme::Keyboard,
+        }
+    }
+
+    /// Parse a command-line value: "keyboard" or "mouse".
+    pub fn parse(value: &str) -> Option<ControlScheme> {
+        match value {
+            "keyboard" => Some(ControlScheme::Keyboard),
+            "mouse" => Some(ControlScheme::Mouse),
+            _ => None,
+        }
+    }
+}
+
+/// The mouse over the playing screen as tracked by the run loop: the world position
+/// under the cursor and whether the thrust button is held. Unlike keys, buttons always
+/// report their release, so no hold counters are needed.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct MouseAim {
+    pub target: Option<Vec2>,
+    pub thrust: bool,
+}
+
+impl MouseAim {
+    /// Follow a mouse event on a `cols` by `rows` terminal showing the playing screen.
+    /// The cursor maps through the playfield's braille grid, stopping at its bottom row
+    /// over the HUD. Returns true for a left-button press, which fires.
+    pub fn handle(
+        &mut self,
+        event: &MouseEvent,
+        cols: u16,
+        rows: u16,
+        world_width: f64,
+        world_height: f64,
+    ) -> bool {
+        let field_rows = renderer::playing_field_rows(rows as usize);
+        if cols == 0 || field_rows == 0 {
+            return false;
+        }
+        let row = event.row.min(field_rows as u16 - 1);
+        self.target = Some(renderer::cell_to_world(
+            event.column,
+            row,
+            cols as usize,
+            field_rows,
+            world_width,
+            world_height,
+        ));
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => return true,
+            MouseEventKind::Down(MouseButton::Right) => self.thrust = true,
+            MouseEventKind::Up(MouseButton::Right) => self.thrust = false,
+            _ => {}
+        }
+        false
+    }
+}
+
+/// Frames a pressed key stays held on terminals without release events (~333ms at 60fps).
+pub const HOLD_FRAMES: u8 = 20;
+
+/// One player's keyboard state as tracked by the run loop.
+///
+/// Without keyboard enhancement, terminals only repeat one key at a time, so a press
+/// holds its action for HOLD_FRAMES. Hold counters only decay while the terminal is
+/// quiet and freeze while any key generates events; this bridges the gap when another
+/// key steals the repeat. With enhancement, release events clear actions directly.
+#[derive(Debug, Default)]
+pub struct PlayerKeys {
+    pub state: InputState,
+    hold_left: u8,
+    hold_right: u8,
+    hold_thrust: u8,
+    hold_shield: u8,
+    raw_fire_pressed: bool,
+    fire_detector: FireEdgeDetector,
+}
+
+impl PlayerKeys {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Handle a press or repeat event. Fire only counts on the initial press.
+    pub fn press(&mut self, action: Action, initial_press: bool) {
+        match action {
+            Action::RotateLeft => {
+                self.state.rotate_left = true;
+                self.hold_left = HOLD_FRAMES;
+            }
+            Action::RotateRight => {
+                self.state.rotate_right = true;
+                self.hold_right = HOLD_FRAMES;
+            }
+            Action::Thrust => {
+                self.state.thrust = true;
+                self.hold_thrust = HOLD_FRAMES;
+            }
+            Action::Shield => {
+                self.state.shield = true;
+                self.hold_shield = HOLD_FRAMES;
+            }
+            Action::Fire => {
+                if initial_press {
+                    self.raw_fire_pressed = true;
+                }
+            }
+            Action::Quit => self.state.quit = true,
+        }
+    }
+
+    /// Handle a release event (only sent with keyboard enhancement).
+    pub fn release(&mut self, action: Action) {
+        match action {
+            Action::RotateLeft => {
+                self.state.rotate_left = false;
+                self.hold_left = 0;
+            }
+            Action::RotateRight => {
+                self.state.rotate_right = false;
+                self.hold_right = 0;
+            }
+            Action::Thrust => {
+                self.state.thrust = false;
+                self.hold_thrust = 0;
+            }
+            Action::Shield => {
+                self.state.shield = false;
+                self.hold_shield = 0;
+            }
+            Action::Quit => self.state.quit = false,
+            Action::Fire => {} // handled by edge detector
+        }
+    }
+
+    /// Resolve this frame's fire from the presses seen since the last call.
+    pub fn latch_fire(&mut self) {
+        self.state.fire = self.fire_detector.update(self.raw_fire_pressed);
+        self.raw_fire_pressed = false;
+    }
+
+    /// Age the hold counters after the frame's updates (no-op with keyboard enhancement).
+    pub fn decay(&mut self, enhanced_keyboard: bool, any_key_event: bool) {
+        if enhanced_keyboard {
+            return;
+        }
+        if !any_key_event {
+            self.hold_left = self.hold_left.saturating_sub(1);
+            self.hold_right = self.hold_right.saturating_sub(1);
+            self.hold_thrust = self.hold_thrust.saturating_sub(1);
+            self.hold_shield = self.hold_shield.saturating_sub(1);
+        }
+        self.state.rotate_left = self.hold_left > 0;
+        self.state.rotate_right = self.hold_right > 0;
+        self.state.thrust = self.hold_thrust > 0;
+        self.state.shield = self.hold_shield > 0;
+        self.state.quit = false;
+    }
+}
+
 /// Tracks edge detection for the fire action to prevent auto-repeat.
 #[derive(Debug, Default)]
 pub struct FireEdgeDetector {
@@ -74,6 +281,7 @@ pub fn poll_input(state: &mut InputState, fire_detector: &mut FireEdgeDetector)
     state.rotate_left = false;
     state.rotate_right = false;
     state.thrust = false;
+    state.shield = false;
     state.quit = false;
 
     let mut raw_fire_pressed = false;
@@ -92,6 +300,7 @@ pub fn poll_input(state: &mut InputState, fire_detector: &mut FireEdgeDetector)
                     Action::RotateRight => state.rotate_right = true,
                     Action::Thrust => state.thrust = true,
                     Action::Fire => raw_fire_pressed = true,
+                    Action::Shield => state.shield = true,
                     Action::Quit => state.quit = true,
                 }
             }
@@ -116,6 +325,7 @@ mod tests {
         assert!(!state.is_active(Action::RotateRight));
         assert!(!state.is_active(Action::Thrust));
         assert!(!state.is_active(Action::Fire));
+        assert!(!state.is_active(Action::Shield));
         assert!(!state.is_active(Action::Quit));
     }
 
@@ -160,6 +370,12 @@ mod tests {
         assert_eq!(map_key(KeyCode::Char(' ')), Some(Action::Fire));
     }
 
+    // Scenario: Down arrow maps to Shield
+    #[test]
+    fn test_down_arrow_maps_to_shield() {
+        assert_eq!(map_key(KeyCode::Down), Some(Action::Shield));
+    }
+
     // Scenario: Q key maps to Quit
     #[test]
     fn test_q_maps_to_quit() {
@@ -172,6 +388,123 @@ mod tests {
         assert_eq!(map_key(KeyCode::Char('x')), None);
     }
 
+    // === Requirement: Player Two Key Set ===
+
+    // Scenario: WASD maps to player 2's actions
+    #[test]
+    fn test_player_two_keys() {
+        assert_eq!(
+            map_key_player_two(KeyCode::Char('a')),
+            Some(Action::RotateLeft)
+        );
+        assert_eq!(
+            map_key_player_two(KeyCode::Char('D')),
+            Some(Action::RotateRight)
+        );
+        assert_eq!(map_key_player_two(KeyCode::Char('w')), Some(Action::Thrust));
+        assert_eq!(map_key_player_two(KeyCode::Char('s')), Some(Action::Fire));
+        assert_eq!(map_key_player_two(KeyCode::Char('x')), Some(Action::Shield));
+    }
+
+    // Scenario: Player 2's keys do not overlap player 1's
+    #[test]
+    fn test_player_key_sets_disjoint() {
+        for code in [
+            KeyCode::Left,
+            KeyCode::Right,
+            KeyCode::Up,
+            KeyCode::Down,
+            KeyCode::Char(' '),
+        ] {
+            assert_eq!(map_key_player_two(code), None);
+        }
+        for c in ['a', 'd', 'w', 's', 'x'] {
+            assert_eq!(map_key(KeyCode::Char(c)), None);
+        }
+        assert_eq!(map_key_player_two(KeyCode::Char('q')), None);
+    }
+
+    // === Requirement: Held Key Tracking ===
+
+    // Scenario: Press holds an action until the terminal has been quiet long enough
+    #[test]
+    fn test_player_keys_hold_and_decay() {
+        let mut keys = PlayerKeys::new();
+        keys.press(Action::Thrust, true);
+        assert!(keys.state.thrust);
+        // Other keys generating events freeze the counter
+        keys.decay(false, true);
+        assert!(keys.state.thrust);
+        for _ in 0..HOLD_FRAMES - 1 {
+            keys.decay(false, false);
+            assert!(keys.state.thrust);
+        }
+        keys.decay(false, false);
+        assert!(!keys.state.thrust);
+    }
+
+    // Scenario: Shield is held like thrust
+    #[test]
+    fn test_player_keys_shield_held() {
+        let mut keys = PlayerKeys::new();
+        keys.press(Action::Shield, true);
+        for _ in 0..HOLD_FRAMES - 1 {
+            keys.decay(false, false);
+            assert!(keys.state.shield);
+        }
+        keys.decay(false, false);
+        assert!(!keys.state.shield);
+        keys.press(Action::Shield, true);
+        keys.release(Action::Shield);
+        assert!(!keys.state.shield);
+    }
+
+    // Scenario: Release clears an action with keyboard enhancement
+    #[test]
+    fn test_player_keys_release() {
+        let mut keys = PlayerKeys::new();
+        keys.press(Action::RotateLeft, true);
+        keys.press(Action::RotateRight, true);
+        keys.press(Action::Quit, true);
+        keys.release(Action::RotateLeft);
+        keys.release(Action::Quit);
+        keys.release(Action::Fire);
+        keys.decay(true, false);
+        assert!(!keys.state.rotate_left);
+        assert!(keys.state.rotate_right);
+        assert!(!keys.state.quit);
+        keys.release(Action::RotateRight);
+        keys.press(Action::Thrust, true);
+        keys.release(Action::Thrust);
+        assert_eq!(keys.state, InputState::default());
+    }
+
+    // Scenario: Fire triggers once per press, not on repeat
+    #[test]
+    fn test_player_keys_fire_edge() {
+        let mut keys = PlayerKeys::new();
+        keys.press(Action::Fire, true);
+        keys.latch_fire();
+        assert!(keys.state.fire);
+        keys.press(Action::Fire, false);
+        keys.latch_fire();
+        assert!(!keys.state.fire);
+        keys.latch_fire();
+        keys.press(Action::Fire, true);
+        keys.latch_fire();
+        assert!(keys.state.fire);
+    }
+
+    // Scenario: Quit is cleared each frame without keyboard enhancement
+    #[test]
+    fn test_player_keys_quit_cleared() {
+        let mut keys = PlayerKeys::new();
+        keys.press(Action::Quit, true);
+        assert!(keys.state.quit);
+        keys.decay(false, true);
+        assert!(!keys.state.quit);
+    }
+
     // === Requirement: Non-Blocking Input Polling ===
     // Note: Real terminal polling tested via integration tests.
     // Unit test verifies the abstraction layer works correctly.
@@ -190,6 +523,7 @@ mod tests {
 
     // Scenario: Input polling captures pending event
     #[test]
+    #[allow(clippy::single_match, clippy::collapsible_match)]
     fn test_input_state_captures_actions() {
         let mut state = InputState::default();
         // Simulate processing a left arrow key
@@ -228,4 +562,72 @@ mod tests {
         detector.update(false); // release
         assert!(detector.update(true)); // press again — fires
     }
+
+    // === Requirement: Mouse Aiming ===
+
+    fn mouse(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+        }
+    }
+
+    // Scenario: The cursor maps to the world through the braille grid
+    #[test]
+    fn test_mouse_aim_follows_cursor() {
+        let mut aim = MouseAim::default();
+        let moved = mouse(MouseEventKind::Moved, 0, 0);
+        assert!(!aim.handle(&moved, 80, 31, 800.0, 600.0));
+        assert_eq!(aim.target, Some(Vec2::new(5.0, 10.0)));
+        let over_hud = mouse(MouseEventKind::Moved, 79, 30);
+        aim.handle(&over_hud, 80, 31, 800.0, 600.0);
+        assert_eq!(aim.target, Some(Vec2::new(795.0, 590.0)));
+        assert!(!aim.handle(&moved, 80, 1, 800.0, 600.0), "no playfield");
+    }
+
+    // Scenario: Left-click fires and the right button thrusts while held
+    #[test]
+    fn test_mouse_buttons() {
+        let mut aim = MouseAim::default();
+        let left = mouse(MouseEventKind::Down(MouseButton::Left), 40, 15);
+        assert!(aim.handle(&left, 80, 31, 800.0, 600.0));
+        assert!(!aim.thrust);
+        aim.handle(
+            &mouse(MouseEventKind::Down(MouseButton::Right), 40, 15),
+            80,
+            31,
+            800.0,
+            600.0,
+        );
+        assert!(aim.thrust);
+        aim.handle(
+            &mouse(MouseEventKind::Drag(MouseButton::Right), 41, 15),
+            80,
+            31,
+            800.0,
+            600.0,
+        );
+        assert!(aim.thrust);
+        aim.handle(
+            &mouse(MouseEventKind::Up(MouseButton::Right), 41, 15),
+            80,
+            31,
+            800.0,
+            600.0,
+        );
+        assert!(!aim.thrust);
+    }
+
+    // Scenario: Control schemes are named, toggled and parsed
+    #[test]
+    fn test_control_scheme() {
+        assert_eq!(ControlScheme::default(), ControlScheme::Keyboard);
+        assert_eq!(ControlScheme::Keyboard.next(), ControlScheme::Mouse);
+        assert_eq!(ControlScheme::Mouse.next(), ControlScheme::Keyboard);
+        assert_eq!(ControlScheme::Mouse.name(), "MOUSE");
+        assert_eq!(ControlScheme::parse("mouse"), Some(ControlScheme::Mouse));
+        assert_eq!(ControlScheme::parse("trackball"), None);
+    }
 }
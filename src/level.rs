@@ -0,0 +1,698 @@
+// Level capability: scripted waves loaded from TOML level packs
+//
+// A level pack lists waves in order. Each wave is one or more groups of rocks with a
+// size, count, placement, motion and optional shape and spin, plus an optional time
+// limit and a win condition. Coordinates are on the standard 800x600 field and are
+// scaled to the world the level is played in.
+
+use crate::asteroids::{self, Asteroid, AsteroidSize};
+use crate::game::{MIN_SPAWN_DISTANCE, WORLD_HEIGHT, WORLD_WIDTH};
+use crate::physics::Vec2;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+use std::fmt;
+use std::io;
+use std::ops::Range;
+use std::path::Path;
+use toml::Spanned;
+
+/// Spawn speed range for rocks that give neither a velocity nor a speed.
+pub const DEFAULT_SPEED: (f64, f64) = (20.0, 80.0);
+
+/// Most rocks one group may spawn.
+pub const MAX_COUNT: u32 = 100;
+
+/// A validated level pack, ready to play.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LevelPack {
+    pub name: String,
+    pub waves: Vec<WaveDef>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WaveDef {
+    pub rocks: Vec<RockGroup>,
+    /// Seconds to meet the win condition; surviving waves last this long.
+    pub time_limit: Option<f64>,
+    pub win: WinCondition,
+}
+
+/// What ends a wave successfully.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WinCondition {
+    /// Destroy every rock.
+    #[default]
+    Clear,
+    /// Stay alive until the time limit.
+    Survive,
+    /// Reach this total score.
+    Score(u32),
+}
+
+/// Identical rocks spawned together.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RockGroup {
+    pub size: AsteroidSize,
+    pub count: u32,
+    pub placement: Placement,
+    pub motion: Motion,
+    /// Outline vertices relative to the rock's centre; random if None.
+    pub shape: Option<Vec<Vec2>>,
+    /// Radians per second; random if None.
+    pub spin: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Placement {
+    /// Anywhere far enough from the ship, like a normal wave.
+    Anywhere,
+    At(Vec2),
+    /// A random point in the rectangle from `min` to `max`.
+    Zone {
+        min: Vec2,
+        max: Vec2,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Motion {
+    Velocity(Vec2),
+    /// A random direction at a speed in this range.
+    Speed {
+        min: f64,
+        max: f64,
+    },
+}
+
+/// A problem in a level file and the line it is on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LevelError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for LevelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for LevelError {}
+
+// The file as written, with spans kept for error messages.
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawPack {
+    name: Option<String>,
+    #[serde(default)]
+    wave: Vec<Spanned<RawWave>>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawWave {
+    #[serde(default)]
+    rocks: Vec<RawRocks>,
+    time_limit: Option<Spanned<f64>>,
+    win: Option<Spanned<WinCondition>>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawRocks {
+    size: AsteroidSize,
+    count: Option<Spanned<u32>>,
+    position: Option<Spanned<[f64; 2]>>,
+    /// x, y, width, height
+    zone: Option<Spanned<[f64; 4]>>,
+    velocity: Option<Spanned<[f64; 2]>>,
+    /// min, max
+    speed: Option<Spanned<[f64; 2]>>,
+    shape: Option<Spanned<Vec<[f64; 2]>>>,
+    spin: Option<Spanned<f64>>,
+}
+
+// The file as `to_toml` writes it.
+
+#[derive(Serialize)]
+struct FilePack<'a> {
+    name: &'a str,
+    wave: Vec<FileWave>,
+}
+
+#[derive(Serialize)]
+struct FileWave {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time_limit: Option<f64>,
+    win: WinCondition,
+    rocks: Vec<FileRocks>,
+}
+
+#[derive(Serialize)]
+struct FileRocks {
+    size: AsteroidSize,
+    count: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    position: Option<[f64; 2]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zone: Option<[f64; 4]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    velocity: Option<[f64; 2]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    speed: Option<[f64; 2]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shape: Option<Vec<[f64; 2]>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    spin: Option<f64>,
+}
+
+impl From<&RockGroup> for FileRocks {
+    fn from(group: &RockGroup) -> Self {
+        let (position, zone) = match group.placement {
+            Placement::Anywhere => (None, None),
+            Placement::At(at) => (Some([at.x, at.y]), None),
+            Placement::Zone { min, max } => {
+                (None, Some([min.x, min.y, max.x - min.x, max.y - min.y]))
+            }
+        };
+        let (velocity, speed) = match group.motion {
+            Motion::Velocity(v) => (Some([v.x, v.y]), None),
+            Motion::Speed { min, max } => (None, Some([min, max])),
+        };
+        Self {
+            size: group.size,
+            count: group.count,
+            position,
+            zone,
+            velocity,
+            speed,
+            shape: group
+                .shape
+                .as_ref()
+                .map(|shape| shape.iter().map(|v| [v.x, v.y]).collect()),
+            spin: group.spin,
+        }
+    }
+}
+
+/// Builds errors that point at a line of the source.
+struct Source<'a>(&'a str);
+
+impl Source<'_> {
+    fn error(&self, span: Range<usize>, message: impl Into<String>) -> LevelError {
+        let start = span.start.min(self.0.len());
+        LevelError {
+            line: self.0[..start].matches('\n').count() + 1,
+            message: message.into(),
+        }
+    }
+}
+
+fn in_field(x: f64, y: f64) -> bool {
+    (0.0..=WORLD_WIDTH).contains(&x) && (0.0..=WORLD_HEIGHT).contains(&y)
+}
+
+impl LevelPack {
+    /// Parse and validate a level pack.
+    pub fn from_toml(text: &str) -> Result<Self, LevelError> {
+        let source = Source(text);
+        let raw: RawPack = toml::from_str(text)
+            .map_err(|e| source.error(e.span().unwrap_or(0..0), e.message().trim()))?;
+        if raw.wave.is_empty() {
+            return Err(source.error(0..0, "level has no [[wave]] tables"));
+        }
+        let waves = raw
+            .wave
+            .into_iter()
+            .enumerate()
+            .map(|(i, wave)| Self::wave(&source, i + 1, wave))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            name: raw.name.unwrap_or_else(|| "Custom level".to_string()),
+            waves,
+        })
+    }
+
+    /// Read a level pack from a file. Errors name the file and line.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        Self::from_toml(&text).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), e),
+            )
+        })
+    }
+
+    /// Write the pack in the level file format; `from_toml` reads it back unchanged.
+    pub fn to_toml(&self) -> String {
+        let file = FilePack {
+            name: &self.name,
+            wave: self
+                .waves
+                .iter()
+                .map(|wave| FileWave {
+                    time_limit: wave.time_limit,
+                    win: wave.win,
+                    rocks: wave.rocks.iter().map(FileRocks::from).collect(),
+                })
+                .collect(),
+        };
+        toml::to_string(&file).expect("level packs serialize")
+    }
+
+    /// Write the pack to a file, creating its directory if needed.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, self.to_toml())
+    }
+
+    fn wave(source: &Source, number: usize, wave: Spanned<RawWave>) -> Result<WaveDef, LevelError> {
+        let span = wave.span();
+        let wave = wave.into_inner();
+        if wave.rocks.is_empty() {
+            return Err(source.error(span, format!("wave {} has no rocks", number)));
+        }
+        if let Some(ref limit) = wave.time_limit {
+            if limit.get_ref().is_nan() || *limit.get_ref() <= 0.0 {
+                return Err(source.error(limit.span(), "time_limit must be positive"));
+            }
+        }
+        let win = match wave.win {
+            Some(win) => {
+                let span = win.span();
+                match win.into_inner() {
+                    WinCondition::Survive if wave.time_limit.is_none() => {
+                        return Err(source.error(span, "win = \"survive\" needs a time_limit"))
+                    }
+                    WinCondition::Score(0) => {
+                        return Err(source.error(span, "win score must be positive"))
+                    }
+                    win => win,
+                }
+            }
+            None => WinCondition::Clear,
+        };
+        let rocks = wave
+            .rocks
+            .into_iter()
+            .map(|rocks| Self::rocks(source, rocks))
+            .collect::<Result<_, _>>()?;
+        Ok(WaveDef {
+            rocks,
+            time_limit: wave.time_limit.map(Spanned::into_inner),
+            win,
+        })
+    }
+
+    fn rocks(source: &Source, rocks: RawRocks) -> Result<RockGroup, LevelError> {
+        let count = match rocks.count {
+            Some(count) if *count.get_ref() == 0 => {
+                return Err(source.error(count.span(), "count must be at least 1"))
+            }
+            Some(count) if *count.get_ref() > MAX_COUNT => {
+                return Err(
+                    source.error(count.span(), format!("count must be at most {}", MAX_COUNT))
+                )
+            }
+            Some(count) => count.into_inner(),
+            None => 1,
+        };
+
+        let placement = match (rocks.position, rocks.zone) {
+            (Some(_), Some(zone)) => {
+                return Err(source.error(zone.span(), "give either position or zone, not both"))
+            }
+            (Some(position), None) => {
+                let [x, y] = *position.get_ref();
+                if !in_field(x, y) {
+                    return Err(source.error(
+                        position.span(),
+                        format!("position must be within {}x{}", WORLD_WIDTH, WORLD_HEIGHT),
+                    ));
+                }
+                Placement::At(Vec2::new(x, y))
+            }
+            (None, Some(zone)) => {
+                let [x, y, w, h] = *zone.get_ref();
+                if !(w > 0.0 && h > 0.0 && in_field(x, y) && in_field(x + w, y + h)) {
+                    return Err(source.error(
+                        zone.span(),
+                        format!(
+                            "zone must be [x, y, width, height] with a positive size within {}x{}",
+                            WORLD_WIDTH, WORLD_HEIGHT
+                        ),
+                    ));
+                }
+                Placement::Zone {
+                    min: Vec2::new(x, y),
+                    max: Vec2::new(x + w, y + h),
+                }
+            }
+            (None, None) => Placement::Anywhere,
+        };
+
+        let motion = match (rocks.velocity, rocks.speed) {
+            (Some(_), Some(speed)) => {
+                return Err(source.error(speed.span(), "give either velocity or speed, not both"))
+            }
+            (Some(velocity), None) => {
+                let [x, y] = *velocity.get_ref();
+                if !(x.is_finite() && y.is_finite()) {
+                    return Err(source.error(velocity.span(), "velocity must be finite"));
+                }
+                Motion::Velocity(Vec2::new(x, y))
+            }
+            (None, Some(speed)) => {
+                let [min, max] = *speed.get_ref();
+                if !(0.0 <= min && min <= max && max.is_finite()) {
+                    return Err(source.error(
+                        speed.span(),
+                        "speed must be [min, max] with 0 <= min <= max",
+                    ));
+                }
+                Motion::Speed { min, max }
+            }
+            (None, None) => Motion::Speed {
+                min: DEFAULT_SPEED.0,
+                max: DEFAULT_SPEED.1,
+            },
+        };
+
+        let shape = match rocks.shape {
+            Some(shape) if shape.get_ref().len() < 3 => {
+                return Err(source.error(shape.span(), "shape needs at least 3 vertices"))
+            }
+            Some(shape) if !shape.get_ref().iter().flatten().all(|v| v.is_finite()) => {
+                return Err(source.error(shape.span(), "shape vertices must be finite"))
+            }
+            Some(shape) => Some(
+                shape
+                    .into_inner()
+                    .into_iter()
+                    .map(|[x, y]| Vec2::new(x, y))
+                    .collect(),
+            ),
+            None => None,
+        };
+        let spin = match rocks.spin {
+            Some(spin) if !spin.get_ref().is_finite() => {
+                return Err(source.error(spin.span(), "spin must be finite"))
+            }
+            spin => spin.map(Spanned::into_inner),
+        };
+        Ok(RockGroup {
+            size: rocks.size,
+            count,
+            placement,
+            motion,
+            shape,
+            spin,
+        })
+    }
+
+    /// The definition of `wave` (1-based), if the pack has it.
+    pub fn wave_def(&self, wave: u32) -> Option<&WaveDef> {
+        self.waves.get((wave as usize).checked_sub(1)?)
+    }
+
+    /// Spawn the rocks of `wave` in a world of the given size. Positions and zones are
+    /// scaled from the standard field; velocities are not.
+    pub fn spawn<R: Rng>(
+        &self,
+        wave: u32,
+        ship_position: Vec2,
+        world_width: f64,
+        world_height: f64,
+        rng: &mut R,
+    ) -> Vec<Asteroid> {
+        let Some(def) = self.wave_def(wave) else {
+            return Vec::new();
+        };
+        let scale = |p: Vec2| {
+            Vec2::new(
+                p.x * world_width / WORLD_WIDTH,
+                p.y * world_height / WORLD_HEIGHT,
+            )
+        };
+        let mut rocks = Vec::new();
+        for group in &def.rocks {
+            for _ in 0..group.count {
+                let position = match group.placement {
+                    Placement::Anywhere => asteroids::spawn_position(
+                        ship_position,
+                        MIN_SPAWN_DISTANCE,
+                        world_width,
+                        world_height,
+                        rng,
+                    ),
+                    Placement::At(at) => scale(at),
+                    Placement::Zone { min, max } => scale(Vec2::new(
+                        rng.gen_range(min.x..=max.x),
+                        rng.gen_range(min.y..=max.y),
+                    )),
+                };
+                let velocity = match group.motion {
+                    Motion::Velocity(velocity) => velocity,
+                    Motion::Speed { min, max } => {
+                        let speed = rng.gen_range(min..=max);
+                        Vec2::from_angle(rng.gen_range(0.0..(2.0 * PI))).scale(speed)
+                    }
+                };
+                let rock = match group.shape {
+                    Some(ref shape) => Asteroid::new_with_shape(
+                        position,
+                        velocity,
+                        group.size,
+                        group.spin.unwrap_or(0.0),
+                        shape.clone(),
+                    ),
+                    None => {
+                        let mut rock = Asteroid::new(position, velocity, group.size, rng);
+                        if let Some(spin) = group.spin {
+                            rock.angular_velocity = spin;
+                        }
+                        rock
+                    }
+                };
+                rocks.push(rock);
+            }
+        }
+        rocks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+
+    const PACK: &str = r#"
+name = "Gauntlet"
+
+[[wave]]
+[[wave.rocks]]
+size = "large"
+count = 2
+zone = [0, 0, 200, 600]
+speed = [30, 30]
+
+[[wave.rocks]]
+size = "small"
+position = [400, 100]
+velocity = [0, 50]
+shape = [[10, 0], [0, 10], [-10, 0], [0, -10]]
+spin = 0.5
+
+[[wave]]
+time_limit = 30
+win = "survive"
+[[wave.rocks]]
+size = "medium"
+count = 3
+
+[[wave]]
+time_limit = 45
+win = { score = 5000 }
+[[wave.rocks]]
+size = "large"
+"#;
+
+    fn error(text: &str) -> LevelError {
+        LevelPack::from_toml(text).unwrap_err()
+    }
+
+    // === Requirement: Level Files ===
+
+    // Scenario: A level pack parses into waves
+    #[test]
+    fn test_parse_pack() {
+        let pack = LevelPack::from_toml(PACK).unwrap();
+        assert_eq!(pack.name, "Gauntlet");
+        assert_eq!(pack.waves.len(), 3);
+
+        let first = &pack.waves[0];
+        assert_eq!((first.win, first.time_limit), (WinCondition::Clear, None));
+        assert_eq!(first.rocks[0].count, 2);
+        assert_eq!(
+            first.rocks[0].placement,
+            Placement::Zone {
+                min: Vec2::new(0.0, 0.0),
+                max: Vec2::new(200.0, 600.0)
+            }
+        );
+        assert_eq!(
+            first.rocks[1].placement,
+            Placement::At(Vec2::new(400.0, 100.0))
+        );
+        assert_eq!(
+            first.rocks[1].motion,
+            Motion::Velocity(Vec2::new(0.0, 50.0))
+        );
+        assert_eq!(first.rocks[1].shape.as_ref().unwrap().len(), 4);
+
+        let second = &pack.waves[1];
+        assert_eq!(
+            (second.win, second.time_limit),
+            (WinCondition::Survive, Some(30.0))
+        );
+        assert_eq!(second.rocks[0].placement, Placement::Anywhere);
+        assert_eq!(
+            second.rocks[0].motion,
+            Motion::Speed {
+                min: 20.0,
+                max: 80.0
+            }
+        );
+        assert_eq!(pack.waves[2].win, WinCondition::Score(5000));
+        assert_eq!(pack.wave_def(3).unwrap().rocks[0].count, 1);
+        assert!(pack.wave_def(0).is_none() && pack.wave_def(4).is_none());
+
+        let unnamed = LevelPack::from_toml("[[wave]]\nrocks = [{ size = \"small\" }]").unwrap();
+        assert_eq!(unnamed.name, "Custom level");
+    }
+
+    // Scenario: Waves spawn as written
+    #[test]
+    fn test_spawn() {
+        let pack = LevelPack::from_toml(PACK).unwrap();
+        let mut rng = rand_chacha::ChaCha12Rng::seed_from_u64(39);
+        let ship = Vec2::new(400.0, 300.0);
+        let rocks = pack.spawn(1, ship, 1600.0, 600.0, &mut rng);
+        assert_eq!(rocks.len(), 3);
+        for rock in &rocks[..2] {
+            assert_eq!(rock.size, AsteroidSize::Large);
+            assert!(rock.position.x <= 400.0, "zone is scaled to the world");
+            assert!((rock.velocity.magnitude() - 30.0).abs() < 1e-9);
+        }
+        let small = &rocks[2];
+        assert_eq!(small.position, Vec2::new(800.0, 100.0));
+        assert_eq!(small.velocity, Vec2::new(0.0, 50.0));
+        assert_eq!((small.angular_velocity, small.vertices.len()), (0.5, 4));
+
+        // Unplaced rocks keep clear of the ship
+        for rock in pack.spawn(2, ship, 800.0, 600.0, &mut rng) {
+            assert!(
+                crate::collision::toroidal_distance(rock.position, ship, 800.0, 600.0)
+                    >= MIN_SPAWN_DISTANCE
+            );
+        }
+        assert!(pack.spawn(9, ship, 800.0, 600.0, &mut rng).is_empty());
+    }
+
+    // Scenario: Errors point to the offending line
+    #[test]
+    fn test_errors_name_the_line() {
+        let cases = [
+            ("[[wave]]\n[[wave.rocks]]\nsize = \"huge\"\n", 3, "huge"),
+            ("[[wave]]\n[[wave.rocks]]\nsize = \"large\"\ncolour = 1\n", 4, "colour"),
+            ("[[wave]]\n[[wave.rocks]]\nsize = \"large\"\ncount = 0\n", 4, "count"),
+            ("[[wave]]\n[[wave.rocks]]\nsize = \"large\"\ncount = 4000000\n", 4, "at most"),
+            ("[[wave]]\n[[wave.rocks]]\nsize = \"large\"\nposition = [900, 10]\n", 4, "position"),
+            ("[[wave]]\n[[wave.rocks]]\nsize = \"large\"\nposition = [1, 1]\nzone = [0, 0, 10, 10]\n", 5, "either"),
+            ("[[wave]]\n[[wave.rocks]]\nsize = \"large\"\nzone = [700, 0, 200, 10]\n", 4, "zone"),
+            ("[[wave]]\n[[wave.rocks]]\nsize = \"large\"\nvelocity = [1, 1]\nspeed = [1, 2]\n", 5, "either"),
+            ("[[wave]]\n[[wave.rocks]]\nsize = \"large\"\nspeed = [5, 2]\n", 4, "speed"),
+            ("[[wave]]\n[[wave.rocks]]\nsize = \"large\"\nspeed = [1, inf]\n", 4, "speed"),
+            ("[[wave]]\n[[wave.rocks]]\nsize = \"large\"\nvelocity = [nan, 1]\n", 4, "velocity must be finite"),
+            ("[[wave]]\n[[wave.rocks]]\nsize = \"large\"\nspin = -inf\n", 4, "spin must be finite"),
+            ("[[wave]]\n[[wave.rocks]]\nsize = \"large\"\nshape = [[0, 0], [1, 1]]\n", 4, "3 vertices"),
+            ("[[wave]]\n[[wave.rocks]]\nsize = \"large\"\nshape = [[0, 0], [1, nan], [2, 0]]\n", 4, "vertices must be finite"),
+            ("\n\n[[wave]]\ntime_limit = -5\n[[wave.rocks]]\nsize = \"large\"\n", 4, "time_limit"),
+            ("[[wave]]\nwin = \"survive\"\n[[wave.rocks]]\nsize = \"large\"\n", 2, "time_limit"),
+            ("[[wave]]\nwin = { score = 0 }\n[[wave.rocks]]\nsize = \"large\"\n", 2, "score"),
+            (
+                "name = \"x\"\n[[wave]]\nrocks = [{ size = \"small\" }]\n[[wave]]\ntime_limit = 5\n",
+                4,
+                "wave 2 has no rocks",
+            ),
+            ("name = \"unterminated\n", 1, ""),
+            ("name = \"empty\"\n", 1, "no [[wave]]"),
+        ];
+        for (text, line, needle) in cases {
+            let err = error(text);
+            assert_eq!(err.line, line, "{:?} -> {}", text, err);
+            assert!(err.message.contains(needle), "{:?} -> {}", text, err);
+        }
+        assert_eq!(
+            LevelError {
+                line: 7,
+                message: "oops".to_string()
+            }
+            .to_string(),
+            "line 7: oops"
+        );
+    }
+
+    // Scenario: Loading a file names it in errors
+    #[test]
+    fn test_load() {
+        let dir = std::env::temp_dir().join(format!("tuisteroids-level-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let good = dir.join("good.toml");
+        std::fs::write(&good, PACK).unwrap();
+        assert_eq!(LevelPack::load(&good).unwrap().waves.len(), 3);
+
+        let bad = dir.join("bad.toml");
+        std::fs::write(&bad, "[[wave]]\n[[wave.rocks]]\nsize = 3\n").unwrap();
+        let err = LevelPack::load(&bad).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("bad.toml: line 3:"), "{}", err);
+
+        let pack = LevelPack::from_toml(PACK).unwrap();
+        let saved = dir.join("levels").join("saved.toml");
+        pack.save(&saved).unwrap();
+        assert_eq!(LevelPack::load(&saved).unwrap(), pack);
+        assert_eq!(
+            LevelPack::load(&dir.join("missing.toml"))
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::NotFound
+        );
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    // Scenario: Packs written out read back the same
+    #[test]
+    fn test_to_toml_round_trip() {
+        let pack = LevelPack::from_toml(PACK).unwrap();
+        let text = pack.to_toml();
+        assert_eq!(LevelPack::from_toml(&text).unwrap(), pack, "{}", text);
+        assert!(text.contains("name = \"Gauntlet\""));
+        assert!(text.contains("[[wave.rocks]]"));
+    }
+
+    // Scenario: The example pack is valid
+    #[test]
+    fn test_example_pack() {
+        let pack = LevelPack::from_toml(include_str!("../examples/trial.toml")).unwrap();
+        assert_eq!(pack.name, "Trial");
+        assert_eq!(pack.waves.len(), 3);
+        assert_eq!(pack.waves[2].win, WinCondition::Score(1000));
+    }
+}
@@ -1,10 +1,31 @@
+pub mod achievements;
 pub mod asteroids;
 pub mod audio;
+pub mod bot;
 pub mod bullets;
+pub mod cli;
 pub mod collision;
+pub mod controller;
 pub mod demo_ai;
+pub mod difficulty;
+pub mod editor;
+pub mod entity;
+pub mod env;
+pub mod events;
 pub mod game;
+pub mod highscore;
 pub mod input;
+pub mod level;
+pub mod mode;
+pub mod netplay;
 pub mod physics;
+pub mod pickups;
 pub mod renderer;
+pub mod rewind;
+pub mod save;
+pub mod scoring;
 pub mod ship;
+pub mod spectate;
+pub mod stats;
+pub mod telnet;
+pub mod turns;
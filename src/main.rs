@@ -1,6 +1,161 @@
+use tuisteroids::cli::{self, BotTransport, Command, NetRole};
+use tuisteroids::game::{self, Game, Multiplayer, WORLD_HEIGHT, WORLD_WIDTH};
+use tuisteroids::input::ControlScheme;
+use tuisteroids::level::LevelPack;
+use tuisteroids::netplay::{self, Lockstep, NetConfig};
+use tuisteroids::ship::ExtraLives;
+use tuisteroids::spectate::{self, Broadcaster, Endpoint};
+use tuisteroids::telnet::TelnetServer;
+
+use std::path::PathBuf;
+
 fn main() {
-    if let Err(e) = tuisteroids::game::run() {
+    let command = match cli::parse_args(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("Error: {}\n\n{}", e, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    let result = match command {
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            Ok(())
+        }
+        Command::Play {
+            broadcast,
+            level,
+            extra_lives,
+            controls,
+        } => play(broadcast, level, extra_lives, controls),
+        Command::Watch(endpoint) => spectate::watch(&endpoint),
+        Command::Edit(path) => edit(path),
+        Command::Serve(addr) => TelnetServer::bind(addr.as_str()).and_then(|server| {
+            eprintln!("Serving telnet on {}", server.local_addr()?);
+            server.serve()
+        }),
+        Command::Bot {
+            transport,
+            seed,
+            max_ticks,
+        } => {
+            let seed = seed.unwrap_or_else(rand::random);
+            match transport {
+                BotTransport::Stdio => tuisteroids::bot::run_stdio(seed, max_ticks),
+                #[cfg(unix)]
+                BotTransport::UnixSocket(path) => {
+                    tuisteroids::bot::run_unix_socket(&path, seed, max_ticks)
+                }
+                #[cfg(not(unix))]
+                BotTransport::UnixSocket(_) => Err(std::io::Error::new(
+                    std::io::ErrorKind::Unsupported,
+                    "Unix sockets are not supported on this platform",
+                )),
+            }
+        }
+        Command::Net {
+            role,
+            seed,
+            input_delay,
+            hash_interval,
+            versus,
+            headless,
+            max_ticks,
+        } => {
+            let config = NetConfig {
+                seed: seed.unwrap_or_else(rand::random),
+                input_delay,
+                hash_interval,
+                mode: if versus {
+                    Multiplayer::Versus
+                } else {
+                    Multiplayer::CoOp {
+                        friendly_fire: false,
+                    }
+                },
+            };
+            connect(&role, config).and_then(|lockstep| {
+                if headless {
+                    play_headless(lockstep, max_ticks)
+                } else {
+                    let mut game = Game::new(WORLD_WIDTH, WORLD_HEIGHT);
+                    game.start_netplay(lockstep);
+                    game::run_game(game, None)
+                }
+            })
+        }
+    };
+
+    if let Err(e) = result {
         eprintln!("Error: {}", e);
         std::process::exit(1);
     }
 }
+
+/// Play locally, optionally with a level pack, spectators, extra life settings or mouse
+/// controls. The level is checked before the terminal switches to raw mode so its errors
+/// print normally, and is the file the editor opens.
+fn play(
+    broadcast: Option<Endpoint>,
+    level: Option<PathBuf>,
+    extra_lives: ExtraLives,
+    controls: ControlScheme,
+) -> std::io::Result<()> {
+    let mut game = Game::with_default_paths(WORLD_WIDTH, WORLD_HEIGHT);
+    game.extra_lives = extra_lives;
+    game.set_controls(controls);
+    if let Some(path) = level {
+        let level = LevelPack::load(&path)?;
+        eprintln!("Playing level {}", level.name);
+        game.level = Some(level);
+        game.level_path = Some(path);
+    }
+    let broadcaster = match broadcast {
+        Some(endpoint) => {
+            let broadcaster = Broadcaster::bind(&endpoint)?;
+            eprintln!("Broadcasting on {}", broadcaster.endpoint());
+            Some(broadcaster)
+        }
+        None => None,
+    };
+    game::run_game(game, broadcaster)
+}
+
+/// Open the level editor on a level file. A file that exists but is not a valid
+/// level is reported before the terminal switches to raw mode.
+fn edit(path: PathBuf) -> std::io::Result<()> {
+    let mut game = Game::with_default_paths(WORLD_WIDTH, WORLD_HEIGHT);
+    game.level_path = Some(path);
+    game.open_editor()?;
+    game::run_game(game, None)
+}
+
+/// Set up the network session before the terminal switches to raw mode.
+fn connect(role: &NetRole, config: NetConfig) -> std::io::Result<Lockstep> {
+    let session = match role {
+        NetRole::Host(addr) => {
+            let listener = std::net::TcpListener::bind(addr)?;
+            eprintln!("Waiting for a player on {}...", listener.local_addr()?);
+            netplay::host_tcp(&listener, config)
+        }
+        NetRole::Join(addr) => {
+            eprintln!("Joining {}...", addr);
+            netplay::join_tcp(addr.as_str())
+        }
+    };
+    session.map_err(std::io::Error::other)
+}
+
+/// Fly this peer's ship with the demo AI and print a summary both peers can compare.
+fn play_headless(mut lockstep: Lockstep, max_ticks: Option<u64>) -> std::io::Result<()> {
+    let mut state = lockstep.initial_state();
+    let pilot = netplay::demo_pilot(lockstep.local_player());
+    let summary = netplay::run_headless(&mut lockstep, &mut state, max_ticks, pilot)
+        .map_err(std::io::Error::other)?;
+    println!(
+        "ticks {} hash {:016x} scores {} {}",
+        summary.ticks, summary.hash, summary.scores[0], summary.scores[1]
+    );
+    Ok(())
+}
@@ -0,0 +1,146 @@
+// Mode capability: rule sets for one-player games
+
+use crate::ship::INITIAL_LIVES;
+use serde::{Deserialize, Serialize};
+
+pub const TIME_ATTACK_SECONDS: f64 = 180.0;
+pub const HARDCORE_ROCK_SPEED: f64 = 1.5;
+
+/// The rules a game is played under.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum GameMode {
+    /// Three lives, with extra lives at the configured interval.
+    #[default]
+    Classic,
+    /// Highest score before the clock runs out.
+    TimeAttack,
+    /// Endless waves and endless lives; deaths are counted instead.
+    Survival,
+    /// The ship cannot be destroyed.
+    Zen,
+    /// One life, no extra lives and faster rocks.
+    Hardcore,
+}
+
+impl GameMode {
+    pub const ALL: [GameMode; 5] = [
+        GameMode::Classic,
+        GameMode::TimeAttack,
+        GameMode::Survival,
+        GameMode::Zen,
+        GameMode::Hardcore,
+    ];
+
+    /// Name shown on the menu and high score board.
+    pub fn name(self) -> &'static str {
+        match self {
+            GameMode::Classic => "CLASSIC",
+            GameMode::TimeAttack => "TIME ATTACK",
+            GameMode::Survival => "SURVIVAL",
+            GameMode::Zen => "ZEN",
+            GameMode::Hardcore => "HARDCORE",
+        }
+    }
+
+    /// The mode after this one in menu order, wrapping around.
+    pub fn next(self) -> GameMode {
+        let index = Self::ALL.iter().position(|&mode| mode == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    pub fn starting_lives(self) -> u32 {
+        match self {
+            GameMode::Hardcore => 1,
+            _ => INITIAL_LIVES,
+        }
+    }
+
+    pub fn awards_extra_lives(self) -> bool {
+        self != GameMode::Hardcore
+    }
+
+    /// How fast rocks move and spin relative to normal.
+    pub fn rock_speed(self) -> f64 {
+        match self {
+            GameMode::Hardcore => HARDCORE_ROCK_SPEED,
+            _ => 1.0,
+        }
+    }
+
+    /// Seconds until the game ends, if it is timed.
+    pub fn time_limit(self) -> Option<f64> {
+        (self == GameMode::TimeAttack).then_some(TIME_ATTACK_SECONDS)
+    }
+
+    /// Whether rocks can destroy the ship.
+    pub fn ship_can_die(self) -> bool {
+        self != GameMode::Zen
+    }
+
+    /// Whether a destroyed ship respawns without losing a life.
+    pub fn infinite_lives(self) -> bool {
+        self == GameMode::Survival
+    }
+
+    /// Modes that cannot be lost end with the results screen when the player quits.
+    pub fn endless(self) -> bool {
+        matches!(self, GameMode::Survival | GameMode::Zen)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // === Requirement: Game Modes ===
+
+    // Scenario: Modes cycle in menu order
+    #[test]
+    fn test_next_cycles() {
+        let mut mode = GameMode::default();
+        assert_eq!(mode, GameMode::Classic);
+        let mut names = Vec::new();
+        for _ in 0..GameMode::ALL.len() {
+            names.push(mode.name());
+            mode = mode.next();
+        }
+        assert_eq!(mode, GameMode::Classic);
+        assert_eq!(
+            names,
+            vec!["CLASSIC", "TIME ATTACK", "SURVIVAL", "ZEN", "HARDCORE"]
+        );
+    }
+
+    // Scenario: Each mode has its own rules
+    #[test]
+    fn test_rules() {
+        assert_eq!(GameMode::Classic.starting_lives(), INITIAL_LIVES);
+        assert_eq!(GameMode::Hardcore.starting_lives(), 1);
+        assert!(!GameMode::Hardcore.awards_extra_lives());
+        assert!(GameMode::TimeAttack.awards_extra_lives());
+        assert_eq!(GameMode::Hardcore.rock_speed(), HARDCORE_ROCK_SPEED);
+        assert_eq!(GameMode::Zen.rock_speed(), 1.0);
+        assert_eq!(GameMode::TimeAttack.time_limit(), Some(180.0));
+        assert_eq!(GameMode::Survival.time_limit(), None);
+        assert!(!GameMode::Zen.ship_can_die());
+        assert!(GameMode::Survival.ship_can_die());
+        assert!(GameMode::Survival.infinite_lives());
+        assert!(!GameMode::Classic.infinite_lives());
+        assert!(GameMode::Zen.endless() && GameMode::Survival.endless());
+        assert!(!GameMode::Hardcore.endless());
+    }
+
+    // Scenario: Modes serialize by name
+    #[test]
+    fn test_serde_names() {
+        assert_eq!(
+            serde_json::to_string(&GameMode::TimeAttack).unwrap(),
+            "\"time_attack\""
+        );
+        let mode: GameMode = serde_json::from_str("\"hardcore\"").unwrap();
+        assert_eq!(mode, GameMode::Hardcore);
+    }
+}
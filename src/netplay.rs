@@ -0,0 +1,814 @@
+// Netplay capability: two-terminal lockstep multiplayer over TCP
+//
+// Both peers run the same seeded PlayingState with two ships; the host flies ship 1 and
+// the joiner ship 2. Messages are JSON lines. Each tick a peer schedules its local
+// input `input_delay` ticks ahead and sends it, then waits for the other peer's input
+// for the current tick before simulating, so both sides apply identical inputs on
+// identical ticks. Every `hash_interval` ticks both peers exchange a hash of the state
+// to detect desyncs.
+
+use crate::demo_ai::{self, AiDifficulty};
+use crate::events::UpdateResult;
+use crate::game::{Multiplayer, PlayingState, TIMESTEP, WORLD_HEIGHT, WORLD_WIDTH};
+use crate::input::InputState;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+
+/// Bumped whenever the wire format or simulation changes incompatibly.
+pub const PROTOCOL_VERSION: u32 = 1;
+pub const DEFAULT_INPUT_DELAY: u32 = 3; // ticks (~50ms at 60 FPS)
+pub const DEFAULT_HASH_INTERVAL: u64 = 60; // ticks (once a second)
+
+/// Session settings chosen by the host and sent to the joiner.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct NetConfig {
+    pub seed: u64,
+    pub input_delay: u32,
+    pub hash_interval: u64,
+    pub mode: Multiplayer,
+}
+
+impl Default for NetConfig {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            input_delay: DEFAULT_INPUT_DELAY,
+            hash_interval: DEFAULT_HASH_INTERVAL,
+            mode: Multiplayer::CoOp {
+                friendly_fire: false,
+            },
+        }
+    }
+}
+
+/// Messages exchanged between peers, one JSON object per line.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum NetMessage {
+    /// Host to joiner: protocol version and session settings.
+    Hello { version: u32, config: NetConfig },
+    /// Joiner to host: accepts the session.
+    Join { version: u32 },
+    /// A peer's input for a future tick.
+    Input { tick: u64, input: InputState },
+    /// A peer's state hash after simulating up to `tick`.
+    Hash { tick: u64, hash: u64 },
+    /// A peer is leaving.
+    Bye,
+}
+
+/// Why a netplay session ended abnormally.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NetError {
+    /// The other peer left or the connection closed.
+    Disconnected,
+    /// The two simulations diverged.
+    Desync { tick: u64, local: u64, remote: u64 },
+    /// The other peer sent something unexpected.
+    Protocol(String),
+    /// Socket error.
+    Io(String),
+}
+
+impl fmt::Display for NetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NetError::Disconnected => write!(f, "other player disconnected"),
+            NetError::Desync {
+                tick,
+                local,
+                remote,
+            } => write!(
+                f,
+                "desync at tick {} (local {:016x}, remote {:016x})",
+                tick, local, remote
+            ),
+            NetError::Protocol(msg) => write!(f, "protocol error: {}", msg),
+            NetError::Io(msg) => write!(f, "network error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for NetError {}
+
+/// FNV-1a hash of everything that should be identical on both peers: the whole
+/// serialized state, RNG included. Floats serialize to the shortest text that reads back
+/// to the same bits, so any divergence at all is caught.
+pub fn state_hash(state: &PlayingState) -> u64 {
+    let json = serde_json::to_vec(state).expect("game state serializes");
+    let mut hash = Fnv::new();
+    hash.bytes(&json);
+    hash.finish()
+}
+
+struct Fnv(u64);
+
+impl Fnv {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn bytes(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// One peer's end of a lockstep session.
+pub struct Lockstep {
+    reader: Box<dyn BufRead + Send>,
+    writer: Box<dyn Write + Send>,
+    config: NetConfig,
+    local_player: usize,
+    tick: u64,
+    local_inputs: BTreeMap<u64, InputState>,
+    remote_inputs: BTreeMap<u64, InputState>,
+    local_hashes: BTreeMap<u64, u64>,
+    remote_hashes: BTreeMap<u64, u64>,
+    last_inputs: [InputState; 2],
+    closed: bool,
+}
+
+impl Lockstep {
+    /// Host a session over an established connection: send the settings and wait for
+    /// the joiner to accept. The host flies ship 1.
+    pub fn host<R, W>(reader: R, writer: W, config: NetConfig) -> Result<Self, NetError>
+    where
+        R: BufRead + Send + 'static,
+        W: Write + Send + 'static,
+    {
+        let mut session = Self::new(reader, writer, config, 0);
+        session.send(&NetMessage::Hello {
+            version: PROTOCOL_VERSION,
+            config,
+        })?;
+        match session.receive()? {
+            NetMessage::Join { version } => check_version(version)?,
+            other => return Err(unexpected(&other)),
+        }
+        Ok(session)
+    }
+
+    /// Join a session over an established connection: receive the host's settings and
+    /// accept them. The joiner flies ship 2.
+    pub fn join<R, W>(reader: R, writer: W) -> Result<Self, NetError>
+    where
+        R: BufRead + Send + 'static,
+        W: Write + Send + 'static,
+    {
+        let mut session = Self::new(reader, writer, NetConfig::default(), 1);
+        match session.receive()? {
+            NetMessage::Hello { version, config } => {
+                check_version(version)?;
+                session.config = config;
+                session.prefill();
+            }
+            other => return Err(unexpected(&other)),
+        }
+        session.send(&NetMessage::Join {
+            version: PROTOCOL_VERSION,
+        })?;
+        Ok(session)
+    }
+
+    fn new<R, W>(reader: R, writer: W, config: NetConfig, local_player: usize) -> Self
+    where
+        R: BufRead + Send + 'static,
+        W: Write + Send + 'static,
+    {
+        let mut session = Self {
+            reader: Box::new(reader),
+            writer: Box::new(writer),
+            config,
+            local_player,
+            tick: 0,
+            local_inputs: BTreeMap::new(),
+            remote_inputs: BTreeMap::new(),
+            local_hashes: BTreeMap::new(),
+            remote_hashes: BTreeMap::new(),
+            last_inputs: [InputState::default(), InputState::default()],
+            closed: false,
+        };
+        session.prefill();
+        session
+    }
+
+    /// Nobody has input for the first `input_delay` ticks; both sides treat them as idle.
+    fn prefill(&mut self) {
+        self.local_inputs.clear();
+        self.remote_inputs.clear();
+        for tick in 0..self.config.input_delay as u64 {
+            self.local_inputs.insert(tick, InputState::default());
+            self.remote_inputs.insert(tick, InputState::default());
+        }
+    }
+
+    pub fn config(&self) -> &NetConfig {
+        &self.config
+    }
+
+    /// Which ship this peer flies (0 for the host, 1 for the joiner).
+    pub fn local_player(&self) -> usize {
+        self.local_player
+    }
+
+    /// Next tick to be simulated.
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    /// Inputs applied to ship 1 and ship 2 on the last simulated tick.
+    pub fn last_inputs(&self) -> &[InputState; 2] {
+        &self.last_inputs
+    }
+
+    /// The starting state both peers simulate.
+    pub fn initial_state(&self) -> PlayingState {
+        let mut state = PlayingState::new_seeded(WORLD_WIDTH, WORLD_HEIGHT, self.config.seed);
+        state.add_player_two(self.config.mode, WORLD_WIDTH, WORLD_HEIGHT);
+        state
+    }
+
+    /// Schedule this peer's input and advance one tick once the other peer's input for
+    /// it has arrived. Blocks until then.
+    pub fn step(
+        &mut self,
+        state: &mut PlayingState,
+        local_input: &InputState,
+    ) -> Result<UpdateResult, NetError> {
+        let scheduled = self.tick + self.config.input_delay as u64;
+        self.local_inputs.insert(scheduled, local_input.clone());
+        self.send_to_peer(&NetMessage::Input {
+            tick: scheduled,
+            input: local_input.clone(),
+        })?;
+
+        while !self.remote_inputs.contains_key(&self.tick) {
+            let message = self.receive()?;
+            self.handle(message)?;
+        }
+        let local = self.local_inputs.remove(&self.tick).unwrap_or_default();
+        let remote = self.remote_inputs.remove(&self.tick).unwrap_or_default();
+        self.last_inputs = if self.local_player == 0 {
+            [local, remote]
+        } else {
+            [remote, local]
+        };
+
+        let [one, two] = &self.last_inputs;
+        let result = state.update_players([one, two], TIMESTEP, WORLD_WIDTH, WORLD_HEIGHT);
+        self.tick += 1;
+
+        if self.config.hash_interval > 0 && self.tick.is_multiple_of(self.config.hash_interval) {
+            let hash = state_hash(state);
+            self.local_hashes.insert(self.tick, hash);
+            self.send_to_peer(&NetMessage::Hash {
+                tick: self.tick,
+                hash,
+            })?;
+            self.check_hashes()?;
+        }
+        Ok(result)
+    }
+
+    /// Tell the other peer we are leaving. Safe to call more than once.
+    pub fn close(&mut self) {
+        if !self.closed {
+            self.closed = true;
+            let _ = self.send(&NetMessage::Bye);
+        }
+    }
+
+    fn handle(&mut self, message: NetMessage) -> Result<(), NetError> {
+        match message {
+            NetMessage::Input { tick, input } => {
+                if tick < self.tick {
+                    return Err(NetError::Protocol(format!(
+                        "input for past tick {} at tick {}",
+                        tick, self.tick
+                    )));
+                }
+                self.remote_inputs.insert(tick, input);
+                Ok(())
+            }
+            NetMessage::Hash { tick, hash } => {
+                self.remote_hashes.insert(tick, hash);
+                self.check_hashes()
+            }
+            NetMessage::Bye => Err(NetError::Disconnected),
+            other => Err(unexpected(&other)),
+        }
+    }
+
+    fn check_hashes(&mut self) -> Result<(), NetError> {
+        let both: Vec<u64> = self
+            .local_hashes
+            .keys()
+            .filter(|tick| self.remote_hashes.contains_key(tick))
+            .copied()
+            .collect();
+        for tick in both {
+            let local = self.local_hashes.remove(&tick).unwrap_or_default();
+            let remote = self.remote_hashes.remove(&tick).unwrap_or_default();
+            if local != remote {
+                return Err(NetError::Desync {
+                    tick,
+                    local,
+                    remote,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Send during play. If the peer has already gone, its earlier messages may still be
+    /// waiting to be read, so the disconnect is reported by the next read instead.
+    fn send_to_peer(&mut self, message: &NetMessage) -> Result<(), NetError> {
+        match self.send(message) {
+            Err(NetError::Disconnected) => Ok(()),
+            result => result,
+        }
+    }
+
+    fn send(&mut self, message: &NetMessage) -> Result<(), NetError> {
+        let line = serde_json::to_string(message).map_err(|e| NetError::Io(e.to_string()))?;
+        writeln!(self.writer, "{}", line)
+            .and_then(|_| self.writer.flush())
+            .map_err(|e| match e.kind() {
+                std::io::ErrorKind::BrokenPipe | std::io::ErrorKind::ConnectionReset => {
+                    NetError::Disconnected
+                }
+                _ => NetError::Io(e.to_string()),
+            })
+    }
+
+    fn receive(&mut self) -> Result<NetMessage, NetError> {
+        let mut line = String::new();
+        match self.reader.read_line(&mut line) {
+            Ok(0) => Err(NetError::Disconnected),
+            Ok(_) => serde_json::from_str(line.trim())
+                .map_err(|e| NetError::Protocol(format!("bad message: {}", e))),
+            Err(e) if e.kind() == std::io::ErrorKind::ConnectionReset => {
+                Err(NetError::Disconnected)
+            }
+            Err(e) => Err(NetError::Io(e.to_string())),
+        }
+    }
+}
+
+fn check_version(version: u32) -> Result<(), NetError> {
+    if version == PROTOCOL_VERSION {
+        Ok(())
+    } else {
+        Err(NetError::Protocol(format!(
+            "version mismatch: local {}, remote {}",
+            PROTOCOL_VERSION, version
+        )))
+    }
+}
+
+fn unexpected(message: &NetMessage) -> NetError {
+    NetError::Protocol(format!("unexpected message {:?}", message))
+}
+
+fn io_error(e: std::io::Error) -> NetError {
+    NetError::Io(e.to_string())
+}
+
+fn tcp_halves(stream: TcpStream) -> Result<(BufReader<TcpStream>, TcpStream), NetError> {
+    stream.set_nodelay(true).map_err(io_error)?;
+    let reader = BufReader::new(stream.try_clone().map_err(io_error)?);
+    Ok((reader, stream))
+}
+
+/// Accept one joiner on `listener` and host a session.
+pub fn host_tcp(listener: &TcpListener, config: NetConfig) -> Result<Lockstep, NetError> {
+    let (stream, _) = listener.accept().map_err(io_error)?;
+    let (reader, writer) = tcp_halves(stream)?;
+    Lockstep::host(reader, writer, config)
+}
+
+/// Connect to a host and join its session.
+pub fn join_tcp(addr: impl ToSocketAddrs) -> Result<Lockstep, NetError> {
+    let stream = TcpStream::connect(addr).map_err(io_error)?;
+    let (reader, writer) = tcp_halves(stream)?;
+    Lockstep::join(reader, writer)
+}
+
+/// Outcome of a headless netplay session.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetSummary {
+    pub ticks: u64,
+    pub hash: u64,
+    pub scores: [u32; 2],
+}
+
+/// Run a session without a terminal: `next_input` produces this peer's input each tick.
+/// Stops at game over, quit, or `max_ticks`, then says goodbye.
+pub fn run_headless(
+    session: &mut Lockstep,
+    state: &mut PlayingState,
+    max_ticks: Option<u64>,
+    mut next_input: impl FnMut(&PlayingState) -> InputState,
+) -> Result<NetSummary, NetError> {
+    while max_ticks.is_none_or(|max| session.tick() < max) {
+        let input = next_input(state);
+        if session.step(state, &input)?.state.is_some() {
+            break;
+        }
+    }
+    session.close();
+    Ok(NetSummary {
+        ticks: session.tick(),
+        hash: state_hash(state),
+        scores: [
+            state.score,
+            state.player_two.as_ref().map_or(0, |two| two.score),
+        ],
+    })
+}
+
+/// Input source for headless sessions: flies `player`'s ship with the demo AI.
+pub fn demo_pilot(player: usize) -> impl FnMut(&PlayingState) -> InputState {
+    move |state: &PlayingState| {
+        let (ship, bullet_pool) = match (player, &state.player_two) {
+            (1, Some(two)) => (&two.ship, &two.bullet_pool),
+            _ => (&state.ship, &state.bullet_pool),
+        };
+        demo_ai::generate_ai_input(
+            ship,
+            &state.asteroids,
+            bullet_pool,
+            AiDifficulty::default(),
+            WORLD_WIDTH,
+            WORLD_HEIGHT,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asteroids::AsteroidSize;
+    use crate::physics::Vec2;
+    use crate::pickups::{Pickup, PowerUp};
+    use rand::RngCore;
+    use std::io::Cursor;
+    #[cfg(unix)]
+    use std::os::unix::net::UnixStream;
+    use std::thread;
+
+    #[cfg(unix)]
+    fn unix_pair() -> (
+        (BufReader<UnixStream>, UnixStream),
+        (BufReader<UnixStream>, UnixStream),
+    ) {
+        let (a, b) = UnixStream::pair().unwrap();
+        (
+            (BufReader::new(a.try_clone().unwrap()), a),
+            (BufReader::new(b.try_clone().unwrap()), b),
+        )
+    }
+
+    fn config() -> NetConfig {
+        NetConfig {
+            seed: 9,
+            input_delay: 2,
+            hash_interval: 10,
+            mode: Multiplayer::Versus,
+        }
+    }
+
+    // === Requirement: Session Handshake ===
+
+    // Scenario: Joiner receives the host's settings
+    #[cfg(unix)]
+    #[test]
+    fn test_handshake() {
+        let (host_end, join_end) = unix_pair();
+        let joiner = thread::spawn(move || Lockstep::join(join_end.0, join_end.1).unwrap());
+        let host = Lockstep::host(host_end.0, host_end.1, config()).unwrap();
+        let joiner = joiner.join().unwrap();
+        assert_eq!(host.local_player(), 0);
+        assert_eq!(joiner.local_player(), 1);
+        assert_eq!(joiner.config(), &config());
+        assert_eq!(
+            state_hash(&host.initial_state()),
+            state_hash(&joiner.initial_state())
+        );
+        assert!(host.initial_state().player_two.is_some());
+    }
+
+    // Scenario: Version mismatch is rejected
+    #[test]
+    fn test_handshake_version_mismatch() {
+        let hello = serde_json::to_string(&NetMessage::Hello {
+            version: PROTOCOL_VERSION + 1,
+            config: config(),
+        })
+        .unwrap();
+        let err = Lockstep::join(Cursor::new(format!("{}\n", hello)), Vec::new())
+            .err()
+            .unwrap();
+        assert!(matches!(err, NetError::Protocol(ref m) if m.contains("version")));
+    }
+
+    // Scenario: Unexpected handshake message is rejected
+    #[test]
+    fn test_handshake_unexpected() {
+        let err = Lockstep::join(Cursor::new("{\"type\":\"bye\"}\n"), Vec::new())
+            .err()
+            .unwrap();
+        assert!(matches!(err, NetError::Protocol(_)));
+        let err = Lockstep::host(Cursor::new("{\"type\":\"bye\"}\n"), Vec::new(), config())
+            .err()
+            .unwrap();
+        assert!(matches!(err, NetError::Protocol(_)));
+    }
+
+    // === Requirement: Lockstep Simulation ===
+
+    // Scenario: Both peers simulate identical games
+    #[cfg(unix)]
+    #[test]
+    fn test_lockstep_peers_agree() {
+        let (host_end, join_end) = unix_pair();
+        let joiner = thread::spawn(move || {
+            let mut session = Lockstep::join(join_end.0, join_end.1).unwrap();
+            let mut state = session.initial_state();
+            run_headless(&mut session, &mut state, Some(300), demo_pilot(1)).unwrap()
+        });
+        let mut session = Lockstep::host(host_end.0, host_end.1, config()).unwrap();
+        let mut state = session.initial_state();
+        let host = run_headless(&mut session, &mut state, Some(300), demo_pilot(0)).unwrap();
+        let joiner = joiner.join().unwrap();
+        assert_eq!(host, joiner);
+        assert_eq!(host.ticks, 300);
+    }
+
+    // Scenario: Inputs take effect after the input delay
+    #[cfg(unix)]
+    #[test]
+    fn test_input_delay() {
+        let (host_end, join_end) = unix_pair();
+        let thrust = InputState {
+            thrust: true,
+            ..Default::default()
+        };
+        let joiner = thread::spawn(move || {
+            let mut session = Lockstep::join(join_end.0, join_end.1).unwrap();
+            let mut state = session.initial_state();
+            let mut applied = Vec::new();
+            for _ in 0..4 {
+                session.step(&mut state, &InputState::default()).unwrap();
+                applied.push(session.last_inputs()[0].thrust);
+            }
+            applied
+        });
+        let mut session = Lockstep::host(host_end.0, host_end.1, config()).unwrap();
+        let mut state = session.initial_state();
+        let mut applied = Vec::new();
+        for _ in 0..4 {
+            session.step(&mut state, &thrust).unwrap();
+            applied.push(session.last_inputs()[0].thrust);
+        }
+        // Delay of 2: the first two ticks are idle on both peers
+        assert_eq!(applied, vec![false, false, true, true]);
+        assert_eq!(joiner.join().unwrap(), applied);
+    }
+
+    // Scenario: Quitting ends both simulations on the same tick
+    #[cfg(unix)]
+    #[test]
+    fn test_quit_ends_both() {
+        let (host_end, join_end) = unix_pair();
+        let joiner = thread::spawn(move || {
+            let mut session = Lockstep::join(join_end.0, join_end.1).unwrap();
+            let mut state = session.initial_state();
+            run_headless(&mut session, &mut state, None, |_| InputState::default()).unwrap()
+        });
+        let mut session = Lockstep::host(host_end.0, host_end.1, config()).unwrap();
+        let mut state = session.initial_state();
+        let host = run_headless(&mut session, &mut state, None, |s| InputState {
+            quit: s.frame_count >= 5,
+            ..Default::default()
+        })
+        .unwrap();
+        let joiner = joiner.join().unwrap();
+        // Quit pressed when 5 ticks had run, applied 2 ticks later
+        assert_eq!(host.ticks, 8);
+        assert_eq!(joiner, host);
+    }
+
+    // === Requirement: Desync Detection ===
+
+    // Scenario: Diverged state is reported as a desync
+    #[cfg(unix)]
+    #[test]
+    fn test_desync_detected() {
+        let (host_end, join_end) = unix_pair();
+        let joiner = thread::spawn(move || {
+            let mut session = Lockstep::join(join_end.0, join_end.1).unwrap();
+            let mut state = session.initial_state();
+            state.score = 1; // diverge
+            run_headless(&mut session, &mut state, Some(50), |_| {
+                InputState::default()
+            })
+        });
+        let mut session = Lockstep::host(host_end.0, host_end.1, config()).unwrap();
+        let mut state = session.initial_state();
+        let host = run_headless(&mut session, &mut state, Some(50), |_| {
+            InputState::default()
+        });
+        let joiner = joiner.join().unwrap();
+        for result in [host, joiner] {
+            match result {
+                Err(NetError::Desync { tick, .. }) => assert_eq!(tick, 10),
+                // The peer that notices second may instead see the goodbye
+                Err(NetError::Disconnected) => {}
+                other => panic!("expected desync, got {:?}", other),
+            }
+        }
+    }
+
+    // Scenario: State hash covers the whole game state, RNG included
+    #[test]
+    fn test_state_hash_sensitivity() {
+        let session_state = || {
+            let mut state = PlayingState::new_seeded(800.0, 600.0, 3);
+            state.add_player_two(Multiplayer::Versus, 800.0, 600.0);
+            state
+        };
+        let base = state_hash(&session_state());
+        assert_eq!(base, state_hash(&session_state()));
+        let changes: [fn(&mut PlayingState); 15] = [
+            |s| s.ship.position.x += 1e-9,
+            |s| s.player_two.as_mut().unwrap().frags[1] = 1,
+            |s| s.player_two.as_mut().unwrap().ship.rotation = 0.0,
+            |s| s.asteroids[0].velocity.y += 1.0,
+            |s| s.asteroids[0].size = AsteroidSize::Small,
+            |s| {
+                s.bullet_pool.fire(s.ship.position, 0.0);
+            },
+            |s| {
+                s.rng.next_u64();
+            },
+            |s| s.asteroids[0].rotation += 1e-9,
+            |s| s.asteroids[0].vertices[0].x += 1.0,
+            |s| {
+                s.pickups.push(Pickup::new(
+                    PowerUp::Shield,
+                    Vec2::new(1.0, 1.0),
+                    Vec2::new(0.0, 0.0),
+                ))
+            },
+            |s| s.ship.shield_energy -= 0.5,
+            |s| s.ship.shield_up = true,
+            |s| s.scoring.combo = 2,
+            |s| s.clock += 1.0,
+            |s| s.stats.shots = 1,
+        ];
+        for change in changes {
+            let mut state = session_state();
+            change(&mut state);
+            assert_ne!(state_hash(&state), base);
+        }
+    }
+
+    // === Requirement: Graceful Disconnect ===
+
+    // Scenario: Peer leaving mid-game ends the session with a disconnect
+    #[cfg(unix)]
+    #[test]
+    fn test_peer_leaves() {
+        let (host_end, join_end) = unix_pair();
+        let joiner = thread::spawn(move || {
+            let mut session = Lockstep::join(join_end.0, join_end.1).unwrap();
+            let mut state = session.initial_state();
+            for _ in 0..3 {
+                session.step(&mut state, &InputState::default()).unwrap();
+            }
+            session.close();
+            session.close(); // idempotent
+        });
+        let mut session = Lockstep::host(host_end.0, host_end.1, config()).unwrap();
+        let mut state = session.initial_state();
+        let result = run_headless(&mut session, &mut state, None, |_| InputState::default());
+        joiner.join().unwrap();
+        assert_eq!(result, Err(NetError::Disconnected));
+    }
+
+    // Scenario: Closed connection ends the session with a disconnect
+    #[cfg(unix)]
+    #[test]
+    fn test_connection_dropped() {
+        let (host_end, join_end) = unix_pair();
+        let joiner = thread::spawn(move || {
+            let session = Lockstep::join(join_end.0, join_end.1).unwrap();
+            drop(session);
+        });
+        let mut session = Lockstep::host(host_end.0, host_end.1, config()).unwrap();
+        joiner.join().unwrap();
+        let mut state = session.initial_state();
+        let mut result = Ok(());
+        for _ in 0..10 {
+            if let Err(e) = session.step(&mut state, &InputState::default()) {
+                result = Err(e);
+                break;
+            }
+        }
+        assert_eq!(result, Err(NetError::Disconnected));
+    }
+
+    // Scenario: Malformed or out-of-order messages are protocol errors
+    #[test]
+    fn test_bad_messages() {
+        let hello = serde_json::to_string(&NetMessage::Hello {
+            version: PROTOCOL_VERSION,
+            config: config(),
+        })
+        .unwrap();
+        let mut session =
+            Lockstep::join(Cursor::new(format!("{}\nnot json\n", hello)), Vec::new()).unwrap();
+        let mut state = session.initial_state();
+        let input = InputState::default();
+        session.step(&mut state, &input).unwrap();
+        session.step(&mut state, &input).unwrap();
+        let err = session.step(&mut state, &input).err().unwrap();
+        assert!(matches!(err, NetError::Protocol(ref m) if m.contains("bad message")));
+
+        let past = r#"{"type":"input","tick":0,"input":{}}"#;
+        let mut session =
+            Lockstep::join(Cursor::new(format!("{}\n{}\n", hello, past)), Vec::new()).unwrap();
+        let mut state = session.initial_state();
+        for _ in 0..2 {
+            session.step(&mut state, &input).unwrap();
+        }
+        let err = session.step(&mut state, &input).err().unwrap();
+        assert!(matches!(err, NetError::Protocol(ref m) if m.contains("past tick")));
+
+        let again = format!("{}\n{}\n", hello, hello);
+        let mut session = Lockstep::join(Cursor::new(again), Vec::new()).unwrap();
+        let mut state = session.initial_state();
+        for _ in 0..2 {
+            session.step(&mut state, &input).unwrap();
+        }
+        assert!(matches!(
+            session.step(&mut state, &input),
+            Err(NetError::Protocol(_))
+        ));
+    }
+
+    // Scenario: Errors describe what went wrong
+    #[test]
+    fn test_error_messages() {
+        assert_eq!(
+            NetError::Disconnected.to_string(),
+            "other player disconnected"
+        );
+        let desync = NetError::Desync {
+            tick: 60,
+            local: 1,
+            remote: 2,
+        };
+        assert!(desync.to_string().starts_with("desync at tick 60"));
+        assert!(NetError::Protocol("x".into()).to_string().contains("x"));
+        assert!(NetError::Io("y".into()).to_string().contains("y"));
+    }
+
+    // === Requirement: TCP Transport ===
+
+    // Scenario: Two peers play over TCP loopback
+    #[test]
+    fn test_tcp_loopback() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let joiner = thread::spawn(move || {
+            let mut session = join_tcp(addr).unwrap();
+            let mut state = session.initial_state();
+            run_headless(&mut session, &mut state, Some(120), demo_pilot(1)).unwrap()
+        });
+        let mut session = host_tcp(&listener, config()).unwrap();
+        let mut state = session.initial_state();
+        let host = run_headless(&mut session, &mut state, Some(120), demo_pilot(0)).unwrap();
+        assert_eq!(joiner.join().unwrap(), host);
+    }
+
+    // Scenario: Joining nowhere fails with a network error
+    #[test]
+    fn test_join_refused() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+        assert!(matches!(join_tcp(addr), Err(NetError::Io(_))));
+    }
+}
@@ -1,10 +1,11 @@
 // Physics capability: Vec2, motion integration, toroidal wrapping, angular rotation
 
+use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
 use std::ops::{Add, Sub};
 
 /// A 2D vector with f64 components.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Vec2 {
     pub x: f64,
     pub y: f64,
@@ -0,0 +1,299 @@
+// Pickups capability: power-ups dropped by destroyed asteroids, their drift and expiry
+
+use crate::bullets::BulletPool;
+use crate::collision::{Collider, Shape};
+use crate::entity::{Render, Update};
+use crate::physics::{self, Vec2};
+use crate::renderer::Playfield;
+use crate::ship::Ship;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+pub const DROP_CHANCE: f64 = 0.1; // chance a destroyed asteroid leaves a pickup
+pub const PICKUP_SPEED: f64 = 30.0; // units per second
+pub const PICKUP_RADIUS: f64 = 10.0;
+pub const PICKUP_LIFETIME: f64 = 10.0; // seconds before an uncollected pickup vanishes
+pub const PICKUP_BLINK_TIME: f64 = 2.0; // pickups blink for their last seconds
+pub const POWER_UP_DURATION: f64 = 10.0; // seconds a timed power-up lasts
+
+/// What a pickup gives the ship that collects it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PowerUp {
+    /// A bubble that keeps the ship safe from rocks and bullets.
+    Shield,
+    /// Each shot fires three bullets in a fan.
+    Spread,
+    /// Faster bullets, so shots come back into the pool sooner.
+    RapidFire,
+    /// More bullets in flight at once.
+    ExtraBullets,
+    /// Bullets carry on through the rocks they hit.
+    Piercing,
+    /// Destroys every rock at once.
+    SmartBomb,
+}
+
+impl PowerUp {
+    pub const ALL: [PowerUp; 6] = [
+        PowerUp::Shield,
+        PowerUp::Spread,
+        PowerUp::RapidFire,
+        PowerUp::ExtraBullets,
+        PowerUp::Piercing,
+        PowerUp::SmartBomb,
+    ];
+
+    /// Short name for the HUD.
+    pub fn name(self) -> &'static str {
+        match self {
+            PowerUp::Shield => "SHIELD",
+            PowerUp::Spread => "SPREAD",
+            PowerUp::RapidFire => "RAPID",
+            PowerUp::ExtraBullets => "EXTRA",
+            PowerUp::Piercing => "PIERCE",
+            PowerUp::SmartBomb => "BOMB",
+        }
+    }
+
+    /// Give a timed power-up to a ship and its bullets, restarting it if already
+    /// running. Smart bombs are not timed; the game sets them off when collected.
+    pub fn apply(self, ship: &mut Ship, pool: &mut BulletPool) {
+        match self {
+            PowerUp::Shield => ship.shield_timer = POWER_UP_DURATION,
+            PowerUp::Spread => pool.spread_timer = POWER_UP_DURATION,
+            PowerUp::RapidFire => pool.rapid_timer = POWER_UP_DURATION,
+            PowerUp::ExtraBullets => pool.extra_timer = POWER_UP_DURATION,
+            PowerUp::Piercing => pool.piercing_timer = POWER_UP_DURATION,
+            PowerUp::SmartBomb => {}
+        }
+    }
+
+    /// Outline relative to the pickup's centre. Each kind has its own shape.
+    fn shape(self) -> Vec<Vec2> {
+        let r = PICKUP_RADIUS;
+        match self {
+            PowerUp::Shield => ring(r, 8, 0.0),
+            PowerUp::Spread => ring(r, 3, -PI / 2.0),
+            PowerUp::RapidFire => ring(r, 4, 0.0),
+            PowerUp::ExtraBullets => ring(r, 4, PI / 4.0),
+            PowerUp::Piercing => vec![
+                Vec2::new(0.0, -r),
+                Vec2::new(r * 0.4, 0.0),
+                Vec2::new(0.0, r),
+                Vec2::new(-r * 0.4, 0.0),
+            ],
+            PowerUp::SmartBomb => (0..10)
+                .map(|i| {
+                    let radius = if i % 2 == 0 { r } else { r * 0.45 };
+                    Vec2::from_angle(-PI / 2.0 + i as f64 * PI / 5.0).scale(radius)
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Regular polygon of `sides` vertices around the origin, the first at `start`.
+fn ring(radius: f64, sides: usize, start: f64) -> Vec<Vec2> {
+    (0..sides)
+        .map(|i| Vec2::from_angle(start + i as f64 * 2.0 * PI / sides as f64).scale(radius))
+        .collect()
+}
+
+/// A power-up drifting across the field, waiting to be collected.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Pickup {
+    pub kind: PowerUp,
+    pub position: Vec2,
+    pub velocity: Vec2,
+    /// Seconds since it was dropped.
+    pub age: f64,
+}
+
+impl Pickup {
+    pub fn new(kind: PowerUp, position: Vec2, velocity: Vec2) -> Self {
+        Self {
+            kind,
+            position,
+            velocity,
+            age: 0.0,
+        }
+    }
+
+    /// About to expire, so drawn blinking.
+    pub fn expiring(&self) -> bool {
+        self.age >= PICKUP_LIFETIME - PICKUP_BLINK_TIME
+    }
+
+    /// Outline in world space.
+    pub fn world_vertices(&self) -> Vec<Vec2> {
+        self.kind
+            .shape()
+            .into_iter()
+            .map(|v| self.position + v)
+            .collect()
+    }
+}
+
+impl Update for Pickup {
+    /// Drift, wrap and age.
+    fn update(&mut self, dt: f64, world_width: f64, world_height: f64) {
+        self.position = physics::integrate_motion(self.position, self.velocity, dt);
+        self.position = physics::wrap_position(self.position, world_width, world_height);
+        self.age += dt;
+    }
+
+    /// Uncollected for too long.
+    fn expired(&self) -> bool {
+        self.age >= PICKUP_LIFETIME
+    }
+}
+
+impl Render for Pickup {
+    /// Blinks while about to expire.
+    fn render(&self, playfield: &mut Playfield, blink: bool) {
+        if blink || !self.expiring() {
+            playfield.add_polygon(&self.world_vertices());
+        }
+    }
+}
+
+impl Collider for Pickup {
+    fn position(&self) -> Vec2 {
+        self.position
+    }
+
+    fn shape(&self) -> Shape {
+        Shape::Circle {
+            radius: PICKUP_RADIUS,
+        }
+    }
+}
+
+/// Roll for a pickup where an asteroid was destroyed: a random kind drifting in a
+/// random direction, `DROP_CHANCE` of the time.
+pub fn maybe_drop<R: Rng>(position: Vec2, rng: &mut R) -> Option<Pickup> {
+    if !rng.gen_bool(DROP_CHANCE) {
+        return None;
+    }
+    let kind = PowerUp::ALL[rng.gen_range(0..PowerUp::ALL.len())];
+    let velocity = Vec2::from_angle(rng.gen_range(0.0..2.0 * PI)).scale(PICKUP_SPEED);
+    Some(Pickup::new(kind, position, velocity))
+}
+
+/// Timed power-ups running on a ship and its bullets, with whole seconds left, for
+/// the HUD. Empty when none are running.
+pub fn active_label(ship: &Ship, pool: &BulletPool) -> String {
+    [
+        (PowerUp::Shield, ship.shield_timer),
+        (PowerUp::Spread, pool.spread_timer),
+        (PowerUp::RapidFire, pool.rapid_timer),
+        (PowerUp::ExtraBullets, pool.extra_timer),
+        (PowerUp::Piercing, pool.piercing_timer),
+    ]
+    .iter()
+    .filter(|(_, left)| *left > 0.0)
+    .map(|(kind, left)| format!("{} {}", kind.name(), left.ceil() as u32))
+    .collect::<Vec<_>>()
+    .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha12Rng;
+
+    // === Requirement: Pickup Drops ===
+
+    // Scenario: Some destroyed asteroids drop pickups
+    #[test]
+    fn test_drop_chance() {
+        let mut rng = ChaCha12Rng::seed_from_u64(7);
+        let drops = (0..1000)
+            .filter_map(|_| maybe_drop(Vec2::new(100.0, 200.0), &mut rng))
+            .collect::<Vec<_>>();
+        assert!(drops.len() > 50 && drops.len() < 150, "{}", drops.len());
+        for drop in &drops {
+            assert_eq!(drop.position, Vec2::new(100.0, 200.0));
+            assert!((drop.velocity.magnitude() - PICKUP_SPEED).abs() < 1e-9);
+            assert_eq!(drop.age, 0.0);
+        }
+        for kind in PowerUp::ALL {
+            assert!(drops.iter().any(|drop| drop.kind == kind), "{kind:?}");
+        }
+    }
+
+    // === Requirement: Pickup Drift and Expiry ===
+
+    // Scenario: Pickup drifts and wraps
+    #[test]
+    fn test_pickup_drifts_and_wraps() {
+        let mut pickup = Pickup::new(
+            PowerUp::Shield,
+            Vec2::new(795.0, 300.0),
+            Vec2::new(30.0, 0.0),
+        );
+        pickup.update(0.5, 800.0, 600.0);
+        assert!((pickup.position.x - 10.0).abs() < 1e-9);
+        assert!((pickup.age - 0.5).abs() < 1e-9);
+    }
+
+    // Scenario: Pickup blinks, then expires
+    #[test]
+    fn test_pickup_expires() {
+        let mut pickup = Pickup::new(PowerUp::Spread, Vec2::new(0.0, 0.0), Vec2::new(0.0, 0.0));
+        pickup.update(PICKUP_LIFETIME - PICKUP_BLINK_TIME - 0.1, 800.0, 600.0);
+        assert!(!pickup.expiring() && !pickup.expired());
+        pickup.update(0.2, 800.0, 600.0);
+        assert!(pickup.expiring() && !pickup.expired());
+        pickup.update(PICKUP_BLINK_TIME, 800.0, 600.0);
+        assert!(pickup.expired());
+    }
+
+    // Scenario: Each kind has its own outline
+    #[test]
+    fn test_pickup_outlines() {
+        let shapes = PowerUp::ALL.map(|kind| {
+            Pickup::new(kind, Vec2::new(50.0, 50.0), Vec2::new(0.0, 0.0)).world_vertices()
+        });
+        for (i, shape) in shapes.iter().enumerate() {
+            assert!(shape.len() >= 3);
+            for v in shape {
+                assert!((*v - Vec2::new(50.0, 50.0)).magnitude() <= PICKUP_RADIUS + 1e-9);
+            }
+            for other in &shapes[i + 1..] {
+                assert_ne!(shape, other);
+            }
+        }
+    }
+
+    // === Requirement: Timed Power-Ups ===
+
+    // Scenario: Collecting a power-up starts its timer
+    #[test]
+    fn test_apply_starts_timers() {
+        let mut ship = Ship::new(400.0, 300.0);
+        let mut pool = BulletPool::new();
+        for kind in PowerUp::ALL {
+            kind.apply(&mut ship, &mut pool);
+        }
+        assert_eq!(ship.shield_timer, POWER_UP_DURATION);
+        assert_eq!(pool.spread_timer, POWER_UP_DURATION);
+        assert_eq!(pool.rapid_timer, POWER_UP_DURATION);
+        assert_eq!(pool.extra_timer, POWER_UP_DURATION);
+        assert_eq!(pool.piercing_timer, POWER_UP_DURATION);
+    }
+
+    // Scenario: HUD label lists running power-ups
+    #[test]
+    fn test_active_label() {
+        let mut ship = Ship::new(400.0, 300.0);
+        let mut pool = BulletPool::new();
+        assert_eq!(active_label(&ship, &pool), "");
+        PowerUp::Shield.apply(&mut ship, &mut pool);
+        PowerUp::Piercing.apply(&mut ship, &mut pool);
+        pool.piercing_timer = 2.5;
+        assert_eq!(active_label(&ship, &pool), "SHIELD 10 PIERCE 3");
+    }
+}
@@ -1,6 +1,17 @@
 // Renderer capability: braille rasterization, polygon rendering, HUD
 
+use crate::difficulty::Difficulty;
+use crate::input::ControlScheme;
+use crate::mode::GameMode;
 use crate::physics::Vec2;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use serde::{Deserialize, Serialize};
+
+/// Lines under the level editor's field: help, then status.
+pub const EDITOR_FOOTER_ROWS: usize = 2;
+/// Column where the game over summary starts, beside the results.
+pub const SUMMARY_COLUMN: usize = 40;
 
 // Braille Unicode block: U+2800 to U+28FF
 // Each cell is 2 dots wide x 4 dots tall
@@ -179,12 +190,103 @@ pub fn game_over_overlay(score: u32) -> ScreenOverlay {
     }
 }
 
+/// HUD text for two players: both scores and lives, the active player marked when
+/// taking turns, and the frag count in versus.
+pub fn two_player_hud(
+    active: Option<usize>,
+    players: [HudInfo; 2],
+    frags: Option<[u32; 2]>,
+) -> String {
+    let mut hud = players
+        .iter()
+        .enumerate()
+        .map(|(i, hud)| {
+            let marker = if active == Some(i) { ">" } else { " " };
+            let lives = "▲ ".repeat(hud.lives as usize);
+            format!("{}{}UP {}  {}", marker, i + 1, hud.score, lives)
+        })
+        .collect::<Vec<_>>()
+        .join("   ")
+        .trim_end()
+        .to_string();
+    if let Some([one, two]) = frags {
+        hud.push_str(&format!("    FRAGS {}-{}", one, two));
+    }
+    hud
+}
+
+/// Cells in the HUD's shield energy bar.
+pub const ENERGY_BAR_CELLS: usize = 8;
+
+/// HUD bar for a fraction from 0 to 1, filled cells rounded to nearest.
+pub fn energy_bar(fraction: f64) -> String {
+    let filled = (fraction.clamp(0.0, 1.0) * ENERGY_BAR_CELLS as f64).round() as usize;
+    format!(
+        "[{}{}]",
+        "█".repeat(filled),
+        "░".repeat(ENERGY_BAR_CELLS - filled)
+    )
+}
+
+/// Game over lines for a two-player game: each score and the winner.
+pub fn two_player_results(scores: [u32; 2]) -> Vec<String> {
+    let verdict = match scores[0].cmp(&scores[1]) {
+        std::cmp::Ordering::Greater => "Player 1 wins".to_string(),
+        std::cmp::Ordering::Less => "Player 2 wins".to_string(),
+        std::cmp::Ordering::Equal => "It's a tie".to_string(),
+    };
+    vec![
+        format!("Player 1: {}", scores[0]),
+        format!("Player 2: {}", scores[1]),
+        verdict,
+    ]
+}
+
+/// Game over lines for a versus game: frags and points for each player, and the
+/// winner on frags with points breaking a tie.
+pub fn versus_results(frags: [u32; 2], scores: [u32; 2]) -> Vec<String> {
+    let verdict = match (frags[0], scores[0]).cmp(&(frags[1], scores[1])) {
+        std::cmp::Ordering::Greater => "Player 1 wins".to_string(),
+        std::cmp::Ordering::Less => "Player 2 wins".to_string(),
+        std::cmp::Ordering::Equal => "It's a tie".to_string(),
+    };
+    vec![
+        format!("Player 1: {} frags, {} points", frags[0], scores[0]),
+        format!("Player 2: {} frags, {} points", frags[1], scores[1]),
+        verdict,
+    ]
+}
+
 /// Determine if an invulnerable ship should be visible this frame (blink effect).
 /// Blinks at ~10Hz (every 6 frames at 60 FPS).
 pub fn ship_blink_visible(frame_count: u64) -> bool {
     (frame_count / 6).is_multiple_of(2)
 }
 
+/// Terminal rows the playfield takes on the playing screen, above the HUD line.
+pub fn playing_field_rows(rows: usize) -> usize {
+    rows.saturating_sub(1)
+}
+
+/// World position under the centre of terminal cell (`column`, `row`) of a playfield
+/// rasterized into `cols` by `rows` cells, through the braille grid of 2 by 4 dots a
+/// cell.
+pub fn cell_to_world(
+    column: u16,
+    row: u16,
+    cols: usize,
+    rows: usize,
+    world_width: f64,
+    world_height: f64,
+) -> Vec2 {
+    let dot_x = column as f64 * 2.0 + 1.0;
+    let dot_y = row as f64 * 4.0 + 2.0;
+    Vec2::new(
+        dot_x / (cols * 2) as f64 * world_width,
+        dot_y / (rows * 4) as f64 * world_height,
+    )
+}
+
 /// Generate thrust flame vertices behind the ship.
 pub fn thrust_flame_vertices(ship_position: Vec2, ship_rotation: f64) -> [Vec2; 3] {
     let cos_r = ship_rotation.cos();
@@ -207,6 +309,374 @@ pub fn thrust_flame_vertices(ship_position: Vec2, ship_rotation: f64) -> [Vec2;
     [base_left, base_right, tip]
 }
 
+/// Everything drawn on the playfield, in world coordinates: outlines (asteroids, ships,
+/// thrust flames) and bullets. Independent of terminal size, so it can be sent to
+/// spectators and drawn at whatever size their terminal is.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Playfield {
+    pub world_width: f64,
+    pub world_height: f64,
+    pub polygons: Vec<Vec<[f64; 2]>>,
+    pub dots: Vec<[f64; 2]>,
+    /// Text centred on a world position, such as score popups.
+    #[serde(default)]
+    pub labels: Vec<([f64; 2], String)>,
+}
+
+impl Playfield {
+    pub fn new(world_width: f64, world_height: f64) -> Self {
+        Self {
+            world_width,
+            world_height,
+            ..Default::default()
+        }
+    }
+
+    pub fn add_polygon(&mut self, vertices: &[Vec2]) {
+        self.polygons
+            .push(vertices.iter().map(|v| [v.x, v.y]).collect());
+    }
+
+    pub fn add_dot(&mut self, position: Vec2) {
+        self.dots.push([position.x, position.y]);
+    }
+
+    pub fn add_label(&mut self, position: Vec2, text: &str) {
+        self.labels
+            .push(([position.x, position.y], text.to_string()));
+    }
+
+    /// Rasterize into braille text, one string per terminal row.
+    /// Bullets are drawn as 2x2 dot squares, and labels are written over the cells
+    /// around their position, clipped at the edges.
+    pub fn rasterize(&self, cols: usize, rows: usize) -> Vec<String> {
+        let mut buf = BrailleBuffer::new(cols, rows);
+        for polygon in &self.polygons {
+            let verts: Vec<Vec2> = polygon.iter().map(|&[x, y]| Vec2::new(x, y)).collect();
+            buf.draw_polygon(&verts, self.world_width, self.world_height);
+        }
+        for &[x, y] in &self.dots {
+            let dot_x = (x / self.world_width * buf.dot_width() as f64) as i32;
+            let dot_y = (y / self.world_height * buf.dot_height() as f64) as i32;
+            buf.set_dot(dot_x, dot_y);
+            buf.set_dot(dot_x + 1, dot_y);
+            buf.set_dot(dot_x, dot_y + 1);
+            buf.set_dot(dot_x + 1, dot_y + 1);
+        }
+        let mut rows: Vec<Vec<char>> = (0..buf.rows)
+            .map(|row| (0..buf.cols).map(|col| buf.get_char(col, row)).collect())
+            .collect();
+        for ([x, y], text) in &self.labels {
+            let row = (y / self.world_height * buf.rows as f64).floor();
+            if row < 0.0 {
+                continue;
+            }
+            let Some(cells) = rows.get_mut(row as usize) else {
+                continue;
+            };
+            let centre = (x / self.world_width * buf.cols as f64) as i64;
+            let start = centre - text.chars().count() as i64 / 2;
+            for (i, c) in text.chars().enumerate() {
+                let col = start + i as i64;
+                if (0..cells.len() as i64).contains(&col) {
+                    cells[col as usize] = c;
+                }
+            }
+        }
+        rows.into_iter()
+            .map(|cells| cells.into_iter().collect())
+            .collect()
+    }
+}
+
+/// One frame of what the screen shows, captured from the game.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "screen", rename_all = "snake_case")]
+pub enum Scene {
+    /// Title menu over the attract-mode demo, showing the selected one-player mode and
+    /// difficulty and offering to continue a saved game if there is one.
+    Menu {
+        demo: Option<Playfield>,
+        friendly_fire: bool,
+        #[serde(default)]
+        mode: GameMode,
+        #[serde(default)]
+        difficulty: Difficulty,
+        #[serde(default)]
+        adaptive: bool,
+        /// How player 1 steers in games started from the menu.
+        #[serde(default)]
+        controls: ControlScheme,
+        #[serde(default)]
+        saved_game: bool,
+        /// The level editor can be opened.
+        #[serde(default)]
+        editor: bool,
+        /// Lifetime stats can be viewed.
+        #[serde(default)]
+        stats: bool,
+        #[serde(default)]
+        notice: Option<String>,
+    },
+    /// Gameplay with an optional centered banner and the HUD line. Between waves, the
+    /// wave's bonus tally shows centered over the playfield, and a newly unlocked
+    /// achievement shows in the top right corner.
+    Playing {
+        playfield: Playfield,
+        banner: Option<String>,
+        hud: String,
+        #[serde(default)]
+        tally: Vec<String>,
+        #[serde(default)]
+        toast: Option<String>,
+    },
+    /// Results, an optional note (e.g. why a network game ended) and the high score
+    /// board of the mode played, if the game keeps one, with the players' stats in a
+    /// column beside them.
+    GameOver {
+        results: Vec<String>,
+        note: Option<String>,
+        #[serde(default)]
+        high_scores: Vec<String>,
+        #[serde(default)]
+        mode: GameMode,
+        #[serde(default)]
+        summary: Vec<String>,
+    },
+    /// Lifetime stats, viewed from the menu.
+    Stats { lines: Vec<String> },
+    /// The level editor's field over its help and status lines.
+    Editor {
+        playfield: Playfield,
+        help: String,
+        status: String,
+    },
+}
+
+/// Lay out a scene as styled terminal lines for a `cols` x `rows` area.
+pub fn scene_lines(scene: &Scene, cols: usize, rows: usize) -> Vec<Line<'static>> {
+    match scene {
+        Scene::Menu {
+            demo,
+            friendly_fire,
+            mode,
+            difficulty,
+            adaptive,
+            controls,
+            saved_game,
+            editor,
+            stats,
+            notice,
+        } => {
+            // Attract-mode demo as background, no HUD
+            let mut lines: Vec<Line> = match demo {
+                Some(demo) => demo
+                    .rasterize(cols, rows)
+                    .into_iter()
+                    .map(Line::from)
+                    .collect(),
+                None => (0..rows).map(|_| Line::from("")).collect(),
+            };
+
+            // Overlay menu text at vertical center, preserving braille background
+            let center = rows / 2;
+            if center >= 2 && center + 2 < lines.len() {
+                let overlay = |line: &Line, text: &str, style: Style| -> Line<'static> {
+                    let existing: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+                    let text_chars: usize = text.chars().count();
+                    let remaining: String = existing.chars().skip(text_chars).collect();
+                    Line::from(vec![
+                        Span::styled(text.to_string(), style),
+                        Span::raw(remaining),
+                    ])
+                };
+                let white = Style::default().fg(Color::White);
+                lines[center - 2] = overlay(&lines[center - 2], "    TUISTEROIDS", white);
+                // center-1 left unchanged so braille background shows through
+                let start = format!(
+                    "    Press any key to start  M: Mode ({})  K: Controls ({})",
+                    mode.name(),
+                    controls.name()
+                );
+                lines[center] = overlay(&lines[center], &start, Style::default());
+                let modes = format!(
+                    "    2: Turns  C: Co-op  V: Versus  F: Friendly fire ({})",
+                    if *friendly_fire { "on" } else { "off" }
+                );
+                lines[center + 1] = overlay(&lines[center + 1], &modes, Style::default());
+                let quit = format!(
+                    "    D: Difficulty ({})  A: Adaptive ({})  Q: Quit",
+                    difficulty.name(),
+                    if *adaptive { "on" } else { "off" }
+                );
+                lines[center + 2] = overlay(&lines[center + 2], &quit, Style::default());
+                let extras = [
+                    saved_game.then(|| "    R: Continue saved game".to_string()),
+                    editor.then(|| "    E: Edit level".to_string()),
+                    stats.then(|| "    S: Lifetime stats".to_string()),
+                    notice.as_ref().map(|notice| format!("    {}", notice)),
+                ];
+                for (row, text) in (center + 3..lines.len()).zip(extras.into_iter().flatten()) {
+                    lines[row] = overlay(&lines[row], &text, white);
+                }
+            }
+            lines
+        }
+        Scene::Playing {
+            playfield,
+            banner,
+            hud,
+            tally,
+            toast,
+        } => {
+            let mut lines: Vec<Line> = playfield
+                .rasterize(cols, playing_field_rows(rows))
+                .into_iter()
+                .map(Line::from)
+                .collect();
+
+            // Banner, centered over the playfield
+            if let Some(banner) = banner {
+                let row = lines.len() / 2;
+                if row < lines.len() {
+                    let pad = cols.saturating_sub(banner.len()) / 2;
+                    lines[row] = Line::from(Span::styled(
+                        format!("{}{}", " ".repeat(pad), banner),
+                        Style::default().fg(Color::Yellow),
+                    ));
+                }
+            }
+
+            // Wave tally, a left-aligned block centered over the playfield
+            let width = tally.iter().map(|line| line.chars().count()).max();
+            let top = (lines.len() / 2).saturating_sub(tally.len() / 2);
+            for (row, line) in (top..lines.len()).zip(tally) {
+                let pad = cols.saturating_sub(width.unwrap_or(0)) / 2;
+                lines[row] = Line::from(Span::styled(
+                    format!("{}{}", " ".repeat(pad), line),
+                    Style::default().fg(Color::Cyan),
+                ));
+            }
+
+            // Achievement toast, right-aligned on the top row
+            if let (Some(toast), Some(top)) = (toast, lines.first_mut()) {
+                let pad = cols.saturating_sub(toast.chars().count());
+                *top = Line::from(Span::styled(
+                    format!("{}{}", " ".repeat(pad), toast),
+                    Style::default().fg(Color::Green),
+                ));
+            }
+
+            lines.push(Line::from(Span::styled(
+                hud.clone(),
+                Style::default().fg(Color::White),
+            )));
+            lines
+        }
+        Scene::GameOver {
+            results,
+            note,
+            high_scores,
+            mode,
+            summary,
+        } => {
+            let mut text = vec![
+                Line::from(""),
+                Line::from(""),
+                Line::from(Span::styled(
+                    "    GAME OVER",
+                    Style::default().fg(Color::Red),
+                )),
+                Line::from(""),
+            ];
+            text.extend(
+                results
+                    .iter()
+                    .map(|line| Line::from(format!("    {}", line))),
+            );
+            if let Some(note) = note {
+                text.push(Line::from(""));
+                text.push(Line::from(Span::styled(
+                    format!("    {}", note),
+                    Style::default().fg(Color::Yellow),
+                )));
+            }
+            if !high_scores.is_empty() {
+                text.push(Line::from(""));
+                let title = match mode {
+                    GameMode::Classic => "    HIGH SCORES".to_string(),
+                    mode => format!("    HIGH SCORES: {}", mode.name()),
+                };
+                text.push(Line::from(Span::styled(
+                    title,
+                    Style::default().fg(Color::White),
+                )));
+                text.extend(
+                    high_scores
+                        .iter()
+                        .map(|line| Line::from(format!("    {}", line))),
+                );
+            }
+            // Summary in a column beside the results, level with GAME OVER
+            for (row, line) in (2..).zip(summary) {
+                if text.len() <= row {
+                    text.resize(row + 1, Line::from(""));
+                }
+                let pad = SUMMARY_COLUMN.saturating_sub(text[row].width());
+                let style = match row {
+                    2 => Style::default().fg(Color::White),
+                    _ => Style::default().fg(Color::Cyan),
+                };
+                text[row].spans.push(Span::raw(" ".repeat(pad)));
+                text[row].spans.push(Span::styled(line.clone(), style));
+            }
+            text.push(Line::from(""));
+            text.push(Line::from("    Press any key to restart or Q to quit"));
+            text
+        }
+        Scene::Stats { lines } => {
+            let mut text = vec![
+                Line::from(""),
+                Line::from(""),
+                Line::from(Span::styled(
+                    "    LIFETIME STATS",
+                    Style::default().fg(Color::White),
+                )),
+                Line::from(""),
+            ];
+            text.extend(lines.iter().map(|line| {
+                Line::from(Span::styled(
+                    format!("    {}", line),
+                    Style::default().fg(Color::Cyan),
+                ))
+            }));
+            text.push(Line::from(""));
+            text.push(Line::from("    Press any key to return to the menu"));
+            text
+        }
+        Scene::Editor {
+            playfield,
+            help,
+            status,
+        } => {
+            let mut lines: Vec<Line> = playfield
+                .rasterize(cols, rows.saturating_sub(EDITOR_FOOTER_ROWS))
+                .into_iter()
+                .map(Line::from)
+                .collect();
+            lines.push(Line::from(Span::styled(
+                help.clone(),
+                Style::default().fg(Color::DarkGray),
+            )));
+            lines.push(Line::from(Span::styled(
+                status.clone(),
+                Style::default().fg(Color::White),
+            )));
+            lines
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -308,6 +778,7 @@ mod tests {
 
     // Scenario: Polygon vertices are transformed by position and rotation
     #[test]
+    #[allow(clippy::useless_vec)]
     fn test_polygon_transforms() {
         let mut buf1 = BrailleBuffer::new(80, 24);
         let mut buf2 = BrailleBuffer::new(80, 24);
@@ -405,6 +876,15 @@ mod tests {
         assert_eq!(hud.score, 600);
     }
 
+    // Scenario: Shield energy shows as a bar
+    #[test]
+    fn test_energy_bar() {
+        assert_eq!(energy_bar(1.0), "[████████]");
+        assert_eq!(energy_bar(0.5), "[████░░░░]");
+        assert_eq!(energy_bar(0.0), "[░░░░░░░░]");
+        assert_eq!(energy_bar(-1.0), energy_bar(0.0));
+    }
+
     // === Requirement: Game Over Screen ===
 
     // Scenario: Game over text is centered
@@ -455,6 +935,71 @@ mod tests {
         }
     }
 
+    // === Requirement: Two-Player HUD ===
+
+    // Scenario: HUD shows both players with the active one marked
+    #[test]
+    fn test_two_player_hud() {
+        let hud = two_player_hud(
+            Some(1),
+            [
+                HudInfo {
+                    score: 1250,
+                    lives: 2,
+                },
+                HudInfo {
+                    score: 300,
+                    lives: 3,
+                },
+            ],
+            None,
+        );
+        assert_eq!(hud, " 1UP 1250  ▲ ▲    >2UP 300  ▲ ▲ ▲");
+    }
+
+    // Scenario: Versus HUD shows frags and no active marker
+    #[test]
+    fn test_versus_hud() {
+        let hud = two_player_hud(
+            None,
+            [
+                HudInfo {
+                    score: 50,
+                    lives: 1,
+                },
+                HudInfo { score: 0, lives: 0 },
+            ],
+            Some([3, 1]),
+        );
+        assert_eq!(hud, " 1UP 50  ▲     2UP 0    FRAGS 3-1");
+    }
+
+    // Scenario: Versus winner is decided on frags, then points
+    #[test]
+    fn test_versus_results() {
+        assert_eq!(
+            versus_results([2, 1], [100, 900]),
+            vec![
+                "Player 1: 2 frags, 100 points",
+                "Player 2: 1 frags, 900 points",
+                "Player 1 wins"
+            ]
+        );
+        assert_eq!(versus_results([1, 1], [100, 900])[2], "Player 2 wins");
+        assert_eq!(versus_results([1, 1], [100, 100])[2], "It's a tie");
+    }
+
+    // Scenario: Game over reports both players and the winner
+    #[test]
+    fn test_two_player_results() {
+        assert_eq!(
+            two_player_results([500, 900]),
+            vec!["Player 1: 500", "Player 2: 900", "Player 2 wins"]
+        );
+        assert_eq!(two_player_results([900, 500])[2], "Player 1 wins");
+        assert_eq!(two_player_results([0, 0])[2], "It's a tie");
+    }
+
     // === Requirement: Ship Invulnerability Visual Feedback ===
 
     // Scenario: Invulnerable ship blinks
@@ -553,4 +1098,389 @@ mod tests {
         let has_dots = buf.cells.iter().any(|&c| c != 0);
         assert!(has_dots);
     }
+
+    // === Requirement: Scene Rendering ===
+
+    fn text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    // Scenario: Playfield rasterizes outlines and bullets at any size
+    #[test]
+    fn test_playfield_rasterize() {
+        let mut playfield = Playfield::new(800.0, 600.0);
+        playfield.add_polygon(&[Vec2::new(0.0, 0.0), Vec2::new(400.0, 0.0)]);
+        playfield.add_dot(Vec2::new(799.0, 599.0));
+        let small = playfield.rasterize(20, 10);
+        assert_eq!(small.len(), 10);
+        assert!(small.iter().all(|row| row.chars().count() == 20));
+        assert_ne!(small[0].chars().next(), Some('\u{2800}'));
+        assert_ne!(small[9].chars().last(), Some('\u{2800}'));
+        assert_eq!(playfield.rasterize(80, 24).len(), 24);
+    }
+
+    // Scenario: Terminal cells map to the world through the braille grid
+    #[test]
+    fn test_cell_to_world() {
+        assert_eq!(playing_field_rows(31), 30);
+        assert_eq!(playing_field_rows(0), 0);
+        assert_eq!(
+            cell_to_world(0, 0, 80, 30, 800.0, 600.0),
+            Vec2::new(5.0, 10.0)
+        );
+        let middle = cell_to_world(40, 15, 80, 30, 800.0, 600.0);
+        assert!((middle - Vec2::new(405.0, 310.0)).magnitude() < 1e-9);
+    }
+
+    // Scenario: Menu scene overlays the title on the demo
+    #[test]
+    fn test_menu_scene_lines() {
+        let scene = Scene::Menu {
+            demo: Some(Playfield::new(800.0, 600.0)),
+            friendly_fire: true,
+            mode: GameMode::Classic,
+            difficulty: Difficulty::Normal,
+            adaptive: false,
+            controls: ControlScheme::Keyboard,
+            saved_game: false,
+            editor: false,
+            stats: false,
+            notice: None,
+        };
+        let lines = scene_lines(&scene, 80, 24);
+        assert_eq!(lines.len(), 24);
+        assert!(text(&lines[10]).starts_with("    TUISTEROIDS"));
+        assert!(text(&lines[13]).contains("Friendly fire (on)"));
+        assert_eq!(text(&lines[12]).chars().count(), 80);
+        assert!(text(&lines[12]).contains("K: Controls (KEYBOARD)"));
+
+        let no_demo = Scene::Menu {
+            demo: None,
+            friendly_fire: false,
+            mode: GameMode::Classic,
+            difficulty: Difficulty::Normal,
+            adaptive: false,
+            controls: ControlScheme::Keyboard,
+            saved_game: false,
+            editor: false,
+            stats: false,
+            notice: None,
+        };
+        assert!(text(&scene_lines(&no_demo, 80, 24)[13]).contains("(off)"));
+        assert!(text(&scene_lines(&no_demo, 80, 24)[15]).is_empty());
+        // Too small for the overlay: background only
+        assert_eq!(scene_lines(&no_demo, 80, 3).len(), 3);
+    }
+
+    // Scenario: Menu scene offers to continue a saved game
+    #[test]
+    fn test_menu_scene_continue() {
+        let scene = Scene::Menu {
+            demo: None,
+            friendly_fire: false,
+            mode: GameMode::Classic,
+            difficulty: Difficulty::Normal,
+            adaptive: false,
+            controls: ControlScheme::Keyboard,
+            saved_game: true,
+            editor: false,
+            stats: false,
+            notice: Some("CANNOT CONTINUE: bad save".to_string()),
+        };
+        let lines = scene_lines(&scene, 80, 24);
+        assert_eq!(text(&lines[15]), "    R: Continue saved game");
+        assert_eq!(text(&lines[16]), "    CANNOT CONTINUE: bad save");
+
+        // Without a save the notice takes the first free row
+        let scene = Scene::Menu {
+            demo: None,
+            friendly_fire: false,
+            mode: GameMode::Classic,
+            difficulty: Difficulty::Normal,
+            adaptive: false,
+            controls: ControlScheme::Keyboard,
+            saved_game: false,
+            editor: false,
+            stats: false,
+            notice: Some("CANNOT CONTINUE: bad save".to_string()),
+        };
+        assert_eq!(
+            text(&scene_lines(&scene, 80, 24)[15]),
+            "    CANNOT CONTINUE: bad save"
+        );
+        // Rows that do not fit are dropped
+        assert_eq!(scene_lines(&scene, 80, 8).len(), 8);
+    }
+
+    // Scenario: Playing scene ends with the HUD and centers the banner
+    #[test]
+    fn test_playing_scene_lines() {
+        let scene = Scene::Playing {
+            playfield: Playfield::new(800.0, 600.0),
+            banner: Some("PLAYER 2".to_string()),
+            hud: "Score: 10  ▲ ".to_string(),
+            tally: Vec::new(),
+            toast: None,
+        };
+        let lines = scene_lines(&scene, 40, 11);
+        assert_eq!(lines.len(), 11);
+        assert_eq!(text(&lines[10]), "Score: 10  ▲ ");
+        assert_eq!(text(&lines[5]), format!("{}PLAYER 2", " ".repeat(16)));
+    }
+
+    // Scenario: Labels are written over the playfield, clipped at the edges
+    #[test]
+    fn test_playfield_labels() {
+        let mut playfield = Playfield::new(800.0, 600.0);
+        playfield.add_label(Vec2::new(400.0, 300.0), "200 x2");
+        playfield.add_label(Vec2::new(0.0, 0.0), "100");
+        playfield.add_label(Vec2::new(400.0, -5.0), "50");
+        let rows = playfield.rasterize(40, 10);
+        assert_eq!(
+            rows[5].chars().skip(17).take(6).collect::<String>(),
+            "200 x2"
+        );
+        assert!(rows[0].starts_with("00\u{2800}"));
+        assert!(rows.iter().all(|row| row.chars().count() == 40));
+    }
+
+    // Scenario: Wave tally is centered over the playfield
+    #[test]
+    fn test_playing_scene_tally() {
+        let scene = Scene::Playing {
+            playfield: Playfield::new(800.0, 600.0),
+            banner: None,
+            hud: "Score: 10".to_string(),
+            tally: vec!["WAVE 1 CLEAR".to_string(), "Bonus  1000".to_string()],
+            toast: None,
+        };
+        let lines: Vec<String> = scene_lines(&scene, 40, 11).iter().map(text).collect();
+        assert_eq!(lines[4], format!("{}WAVE 1 CLEAR", " ".repeat(14)));
+        assert_eq!(lines[5], format!("{}Bonus  1000", " ".repeat(14)));
+        assert_eq!(lines[10], "Score: 10");
+    }
+
+    // === Requirement: Achievement Toast Display ===
+
+    // Scenario: An unlocked achievement shows in the top right corner
+    #[test]
+    fn test_playing_scene_toast() {
+        let scene = Scene::Playing {
+            playfield: Playfield::new(800.0, 600.0),
+            banner: None,
+            hud: "Score: 10".to_string(),
+            tally: Vec::new(),
+            toast: Some("ACHIEVEMENT: Veteran".to_string()),
+        };
+        let lines = scene_lines(&scene, 40, 11);
+        assert_eq!(
+            text(&lines[0]),
+            format!("{}ACHIEVEMENT: Veteran", " ".repeat(20))
+        );
+        assert_eq!(lines[0].spans[0].style.fg, Some(Color::Green));
+        assert_eq!(text(&lines[10]), "Score: 10");
+    }
+
+    // Scenario: Game over scene lists results and the note
+    #[test]
+    fn test_game_over_scene_lines() {
+        let scene = Scene::GameOver {
+            results: vec!["Score: 1250".to_string()],
+            note: Some("Network game ended: other player disconnected".to_string()),
+            high_scores: Vec::new(),
+            mode: GameMode::Classic,
+            summary: Vec::new(),
+        };
+        let lines: Vec<String> = scene_lines(&scene, 80, 24).iter().map(text).collect();
+        assert_eq!(lines[2], "    GAME OVER");
+        assert_eq!(lines[4], "    Score: 1250");
+        assert_eq!(
+            lines[6],
+            "    Network game ended: other player disconnected"
+        );
+        assert_eq!(
+            lines.last().unwrap(),
+            "    Press any key to restart or Q to quit"
+        );
+    }
+
+    // Scenario: Game over scene shows the high score board
+    #[test]
+    fn test_game_over_scene_high_scores() {
+        let scene = Scene::GameOver {
+            results: vec!["Score: 300".to_string()],
+            note: None,
+            high_scores: vec![" 1. ALICE        500".to_string()],
+            mode: GameMode::Classic,
+            summary: Vec::new(),
+        };
+        let lines: Vec<String> = scene_lines(&scene, 80, 24).iter().map(text).collect();
+        assert_eq!(lines[6], "    HIGH SCORES");
+        assert_eq!(lines[7], "     1. ALICE        500");
+    }
+
+    // Scenario: Game over summary sits in a column beside the results
+    #[test]
+    fn test_game_over_scene_summary() {
+        let summary: Vec<String> = (0..8).map(|i| format!("Row {}", i)).collect();
+        let scene = Scene::GameOver {
+            results: vec!["Score: 300".to_string()],
+            note: None,
+            high_scores: Vec::new(),
+            mode: GameMode::Classic,
+            summary,
+        };
+        let lines: Vec<String> = scene_lines(&scene, 80, 24).iter().map(text).collect();
+        assert_eq!(lines[2], format!("    GAME OVER{}Row 0", " ".repeat(27)));
+        assert_eq!(lines[4], format!("    Score: 300{}Row 2", " ".repeat(26)));
+        assert_eq!(lines[9], format!("{}Row 7", " ".repeat(SUMMARY_COLUMN)));
+        assert_eq!(lines[10], "");
+        assert_eq!(lines[11], "    Press any key to restart or Q to quit");
+    }
+
+    // Scenario: Lifetime stats screen lists the totals
+    #[test]
+    fn test_stats_scene_lines() {
+        let scene = Scene::Stats {
+            lines: vec!["Games played         3".to_string()],
+        };
+        let lines: Vec<String> = scene_lines(&scene, 80, 24).iter().map(text).collect();
+        assert_eq!(lines[2], "    LIFETIME STATS");
+        assert_eq!(lines[4], "    Games played         3");
+        assert_eq!(
+            lines.last().unwrap(),
+            "    Press any key to return to the menu"
+        );
+
+        let menu = Scene::Menu {
+            demo: None,
+            friendly_fire: false,
+            mode: GameMode::Classic,
+            difficulty: Difficulty::Normal,
+            adaptive: false,
+            controls: ControlScheme::Keyboard,
+            saved_game: false,
+            editor: false,
+            stats: true,
+            notice: None,
+        };
+        assert_eq!(
+            text(&scene_lines(&menu, 80, 24)[15]),
+            "    S: Lifetime stats"
+        );
+    }
+
+    // Scenario: Menu shows the difficulty
+    #[test]
+    fn test_menu_scene_difficulty() {
+        let menu = Scene::Menu {
+            demo: None,
+            friendly_fire: false,
+            mode: GameMode::Classic,
+            difficulty: Difficulty::Insane,
+            adaptive: true,
+            controls: ControlScheme::Keyboard,
+            saved_game: false,
+            editor: false,
+            stats: false,
+            notice: None,
+        };
+        assert_eq!(
+            text(&scene_lines(&menu, 80, 24)[14]),
+            "    D: Difficulty (INSANE)  A: Adaptive (on)  Q: Quit"
+        );
+    }
+
+    // Scenario: Menu and high score board name the mode
+    #[test]
+    fn test_scenes_show_mode() {
+        let menu = Scene::Menu {
+            demo: None,
+            friendly_fire: false,
+            mode: GameMode::TimeAttack,
+            difficulty: Difficulty::Normal,
+            adaptive: false,
+            controls: ControlScheme::Keyboard,
+            saved_game: false,
+            editor: false,
+            stats: false,
+            notice: None,
+        };
+        assert_eq!(
+            text(&scene_lines(&menu, 80, 24)[12]),
+            "    Press any key to start  M: Mode (TIME ATTACK)  K: Controls (KEYBOARD)"
+        );
+
+        let scene = Scene::GameOver {
+            results: vec!["Score: 300".to_string()],
+            note: None,
+            high_scores: vec![" 1. ALICE        300".to_string()],
+            mode: GameMode::Hardcore,
+            summary: Vec::new(),
+        };
+        let lines: Vec<String> = scene_lines(&scene, 80, 24).iter().map(text).collect();
+        assert_eq!(lines[6], "    HIGH SCORES: HARDCORE");
+
+        // Scenes from before modes existed read as classic
+        let old: Scene =
+            serde_json::from_str(r#"{"screen":"menu","demo":null,"friendly_fire":false}"#).unwrap();
+        assert!(matches!(
+            old,
+            Scene::Menu {
+                mode: GameMode::Classic,
+                ..
+            }
+        ));
+    }
+
+    // Scenario: Editor scene puts help and status under the field
+    #[test]
+    fn test_editor_scene_lines() {
+        let mut playfield = Playfield::new(800.0, 600.0);
+        playfield.add_polygon(&[Vec2::new(0.0, 0.0), Vec2::new(790.0, 0.0)]);
+        let scene = Scene::Editor {
+            playfield,
+            help: "help".to_string(),
+            status: "status".to_string(),
+        };
+        let lines = scene_lines(&scene, 40, 12);
+        assert_eq!(lines.len(), 12);
+        assert!(text(&lines[0]).chars().all(|c| c != braille_char(0)));
+        assert_eq!(text(&lines[10]), "help");
+        assert_eq!(text(&lines[11]), "status");
+
+        // The editor option shows under the menu when it is available
+        let menu = Scene::Menu {
+            demo: None,
+            friendly_fire: false,
+            mode: GameMode::Classic,
+            difficulty: Difficulty::Normal,
+            adaptive: false,
+            controls: ControlScheme::Keyboard,
+            saved_game: true,
+            editor: true,
+            stats: false,
+            notice: None,
+        };
+        let lines = scene_lines(&menu, 80, 24);
+        assert_eq!(text(&lines[16]), "    E: Edit level");
+    }
+
+    // Scenario: Scenes survive a JSON round trip
+    #[test]
+    fn test_scene_json_round_trip() {
+        let mut playfield = Playfield::new(800.0, 600.0);
+        playfield.add_polygon(&[Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0)]);
+        playfield.add_dot(Vec2::new(5.0, 6.0));
+        let scene = Scene::Playing {
+            playfield,
+            banner: None,
+            hud: "Score: 0".to_string(),
+            tally: Vec::new(),
+            toast: None,
+        };
+        let json = serde_json::to_string(&scene).unwrap();
+        assert!(json.starts_with("{\"screen\":\"playing\""));
+        assert_eq!(serde_json::from_str::<Scene>(&json).unwrap(), scene);
+    }
 }
@@ -0,0 +1,244 @@
+// Rewind capability: ring buffer of game snapshots for scrubbing back through play
+//
+// A snapshot is kept every REWIND_INTERVAL ticks, along with every tick's inputs since
+// the oldest one. Any tick in the buffer is rebuilt by replaying inputs from the
+// nearest snapshot before it, which is exact because the simulation is deterministic.
+
+use crate::game::PlayingState;
+use crate::input::InputState;
+use std::collections::VecDeque;
+
+pub const REWIND_INTERVAL: u64 = 30; // ticks between snapshots (0.5 s)
+pub const REWIND_SECONDS: f64 = 5.0; // history kept
+pub const REWIND_SPEED: u64 = 2; // ticks scrubbed back per tick held
+
+/// History of the last few seconds of a game.
+pub struct Rewind {
+    /// Snapshots with the tick they were taken at, oldest first.
+    checkpoints: VecDeque<(u64, PlayingState)>,
+    /// Both players' inputs and the timestep for every tick since the oldest snapshot.
+    inputs: VecDeque<([InputState; 2], f64)>,
+    /// Tick of the oldest snapshot.
+    base: u64,
+    interval: u64,
+    /// Snapshots kept beyond the newest one.
+    capacity: usize,
+    /// Tick being shown while scrubbing back.
+    cursor: Option<u64>,
+}
+
+impl Default for Rewind {
+    fn default() -> Self {
+        let ticks = (REWIND_SECONDS / crate::game::TIMESTEP).round() as u64;
+        Self::new(REWIND_INTERVAL, ticks)
+    }
+}
+
+impl Rewind {
+    /// Keep at least `history` ticks, with a snapshot every `interval` ticks.
+    pub fn new(interval: u64, history: u64) -> Self {
+        let interval = interval.max(1);
+        Self {
+            checkpoints: VecDeque::new(),
+            inputs: VecDeque::new(),
+            base: 0,
+            interval,
+            capacity: history.div_ceil(interval) as usize,
+            cursor: None,
+        }
+    }
+
+    /// Tick of the oldest state that can be rewound to.
+    pub fn oldest(&self) -> u64 {
+        self.base
+    }
+
+    /// Tick of the latest recorded state.
+    pub fn newest(&self) -> u64 {
+        self.base + self.inputs.len() as u64
+    }
+
+    /// Tick being shown while scrubbing, if rewinding.
+    pub fn cursor(&self) -> Option<u64> {
+        self.cursor
+    }
+
+    /// Number of snapshots held.
+    pub fn snapshots(&self) -> usize {
+        self.checkpoints.len()
+    }
+
+    /// Record the inputs about to be applied to `state`. Recording after a rewind
+    /// resumes from the scrubbed point, dropping the history after it.
+    pub fn record(&mut self, state: &PlayingState, inputs: [&InputState; 2], dt: f64) {
+        self.resume();
+        let now = self.newest();
+        let due = self
+            .checkpoints
+            .back()
+            .is_none_or(|(tick, _)| now >= tick + self.interval);
+        if due {
+            self.checkpoints.push_back((now, state.clone()));
+        }
+        self.inputs
+            .push_back(([inputs[0].clone(), inputs[1].clone()], dt));
+
+        while self.checkpoints.len() > self.capacity + 1 {
+            self.checkpoints.pop_front();
+            let new_base = self.checkpoints.front().map_or(now, |(tick, _)| *tick);
+            self.inputs.drain(..(new_base - self.base) as usize);
+            self.base = new_base;
+        }
+    }
+
+    /// Scrub back `ticks` from the current point, stopping at the oldest state, and
+    /// return the game as it was there. None if nothing has been recorded.
+    pub fn step_back(
+        &mut self,
+        ticks: u64,
+        world_width: f64,
+        world_height: f64,
+    ) -> Option<PlayingState> {
+        if self.checkpoints.is_empty() {
+            return None;
+        }
+        let from = self.cursor.unwrap_or(self.newest());
+        let cursor = from.saturating_sub(ticks).max(self.base);
+        self.cursor = Some(cursor);
+        Some(self.state_at(cursor, world_width, world_height))
+    }
+
+    /// Stop scrubbing: play continues from the cursor and the history after it is dropped.
+    pub fn resume(&mut self) {
+        let Some(cursor) = self.cursor.take() else {
+            return;
+        };
+        self.inputs.truncate((cursor - self.base) as usize);
+        while self
+            .checkpoints
+            .back()
+            .is_some_and(|(tick, _)| *tick > cursor)
+        {
+            self.checkpoints.pop_back();
+        }
+    }
+
+    /// Rebuild the game at `tick` from the nearest earlier snapshot. Replay is silent.
+    fn state_at(&self, tick: u64, world_width: f64, world_height: f64) -> PlayingState {
+        let index = self.checkpoints.partition_point(|(t, _)| *t <= tick) - 1;
+        let (start, ref snapshot) = self.checkpoints[index];
+        let mut state = snapshot.clone();
+        let range = (start - self.base) as usize..(tick - self.base) as usize;
+        for (inputs, dt) in self.inputs.range(range) {
+            state.update_players([&inputs[0], &inputs[1]], *dt, world_width, world_height);
+        }
+        state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{Multiplayer, TIMESTEP};
+    use crate::netplay::state_hash;
+
+    const W: f64 = 800.0;
+    const H: f64 = 600.0;
+
+    fn input(tick: u64) -> InputState {
+        InputState {
+            rotate_left: tick % 50 < 20,
+            thrust: tick % 30 < 10,
+            fire: tick.is_multiple_of(9),
+            ..InputState::default()
+        }
+    }
+
+    /// Play `ticks` ticks, recording each, and return the hash of every state seen.
+    fn play(state: &mut PlayingState, rewind: &mut Rewind, ticks: u64) -> Vec<u64> {
+        let idle = InputState::default();
+        let mut hashes = Vec::new();
+        for _ in 0..ticks {
+            hashes.push(state_hash(state));
+            let input = input(state.frame_count);
+            rewind.record(state, [&input, &idle], TIMESTEP);
+            state.update_players([&input, &idle], TIMESTEP, W, H);
+        }
+        hashes.push(state_hash(state));
+        hashes
+    }
+
+    // === Requirement: Rewind History ===
+
+    // Scenario: Snapshots are taken every interval and old ones are dropped
+    #[test]
+    fn test_history_is_bounded() {
+        let mut state = PlayingState::new_seeded(W, H, 1);
+        let mut rewind = Rewind::new(10, 40);
+        assert!(rewind.step_back(REWIND_SPEED, W, H).is_none());
+        play(&mut state, &mut rewind, 25);
+        assert_eq!(rewind.snapshots(), 3);
+        assert_eq!((rewind.oldest(), rewind.newest()), (0, 25));
+
+        play(&mut state, &mut rewind, 100);
+        assert_eq!(rewind.snapshots(), 5);
+        assert_eq!((rewind.oldest(), rewind.newest()), (80, 125));
+        assert!(rewind.newest() - rewind.oldest() >= 40);
+    }
+
+    // Scenario: Every tick in the history is rebuilt exactly
+    #[test]
+    fn test_step_back_matches_history() {
+        let mut state = PlayingState::new_seeded(W, H, 2);
+        state.add_player_two(
+            Multiplayer::CoOp {
+                friendly_fire: false,
+            },
+            W,
+            H,
+        );
+        let mut rewind = Rewind::default();
+        let hashes = play(&mut state, &mut rewind, 400);
+        let newest = rewind.newest();
+
+        let mut tick = newest;
+        while tick > rewind.oldest() {
+            let past = rewind.step_back(3, W, H).unwrap();
+            tick = tick.saturating_sub(3).max(rewind.oldest());
+            assert_eq!(rewind.cursor(), Some(tick));
+            assert_eq!(state_hash(&past), hashes[tick as usize]);
+        }
+        // Scrubbing stops at the oldest state
+        rewind.step_back(100, W, H).unwrap();
+        assert_eq!(rewind.cursor(), Some(rewind.oldest()));
+        assert!((300..330).contains(&(newest - rewind.oldest())));
+    }
+
+    // Scenario: Play resumes from the scrubbed point
+    #[test]
+    fn test_resume_from_cursor() {
+        let mut state = PlayingState::new_seeded(W, H, 3);
+        let mut rewind = Rewind::default();
+        let hashes = play(&mut state, &mut rewind, 200);
+
+        // Rewind 75 ticks and play on from there
+        let mut past = None;
+        for _ in 0..25 {
+            past = rewind.step_back(REWIND_SPEED + 1, W, H);
+        }
+        let mut state = past.unwrap();
+        assert_eq!(state_hash(&state), hashes[125]);
+        let replayed = play(&mut state, &mut rewind, 75);
+        assert_eq!(rewind.cursor(), None);
+        assert_eq!(rewind.newest(), 200);
+        // Same inputs from the same state: the game plays out as before
+        assert_eq!(replayed.last(), hashes.last());
+
+        // Rewinding again reaches into the new history
+        let past = rewind.step_back(10, W, H).unwrap();
+        assert_eq!(state_hash(&past), hashes[190]);
+        rewind.resume();
+        assert_eq!(rewind.newest(), 190);
+        assert!(rewind.snapshots() > 0);
+    }
+}
@@ -0,0 +1,224 @@
+// Save capability: snapshot a game in progress to a file and restore it
+
+use crate::game::PlayingState;
+use crate::turns::Turns;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const SAVE_VERSION: u32 = 1;
+pub const QUICKSAVE_FILE: &str = "quicksave.json";
+
+/// Everything needed to pick a game back up: the active game, RNG included, and the
+/// waiting player's game in alternating turns.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SaveGame {
+    pub version: u32,
+    pub world_width: f64,
+    pub world_height: f64,
+    pub playing: PlayingState,
+    pub turns: Option<Turns>,
+    /// The game was rewound before it was saved, so its score does not count.
+    #[serde(default)]
+    pub assisted: bool,
+}
+
+/// Just enough of a save file to check its version before reading the rest.
+#[derive(Deserialize)]
+struct Header {
+    version: u32,
+}
+
+impl SaveGame {
+    pub fn new(
+        world_width: f64,
+        world_height: f64,
+        playing: PlayingState,
+        turns: Option<Turns>,
+    ) -> Self {
+        Self {
+            version: SAVE_VERSION,
+            world_width,
+            world_height,
+            playing,
+            turns,
+            assisted: false,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("game state serializes")
+    }
+
+    /// Parse a save, rejecting files written by another save version.
+    pub fn from_json(json: &str) -> io::Result<Self> {
+        let invalid = |e: serde_json::Error| io::Error::new(io::ErrorKind::InvalidData, e);
+        let header: Header = serde_json::from_str(json).map_err(invalid)?;
+        if header.version != SAVE_VERSION {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("unsupported save version {}", header.version),
+            ));
+        }
+        serde_json::from_str(json).map_err(invalid)
+    }
+
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        write_json_atomically(path, self)
+    }
+
+    pub fn read(path: &Path) -> io::Result<Self> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+}
+
+/// Read a JSON file from the data directory. A missing file reads as the default.
+pub fn read_json<T: DeserializeOwned + Default>(path: &Path) -> io::Result<T> {
+    match std::fs::read_to_string(path) {
+        Ok(json) => {
+            serde_json::from_str(&json).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(T::default()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Write `value` as JSON, replacing any earlier file only once the new one is complete.
+pub fn write_json_atomically<T: Serialize>(path: &Path, value: &T) -> io::Result<()> {
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir)?;
+    }
+    let partial = path.with_extension("partial");
+    std::fs::write(&partial, serde_json::to_string(value)?)?;
+    std::fs::rename(&partial, path)
+}
+
+/// Where the quicksave lives, in the data directory.
+pub fn default_path() -> PathBuf {
+    data_dir().join(QUICKSAVE_FILE)
+}
+
+/// The game's data directory: `$XDG_DATA_HOME/tuisteroids`, else
+/// `~/.local/share/tuisteroids`, else the current directory.
+pub fn data_dir() -> PathBuf {
+    let data_home = std::env::var_os("XDG_DATA_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| {
+            std::env::var_os("HOME")
+                .filter(|dir| !dir.is_empty())
+                .map(|home| PathBuf::from(home).join(".local/share"))
+        });
+    match data_home {
+        Some(dir) => dir.join("tuisteroids"),
+        None => PathBuf::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::Multiplayer;
+    use crate::input::InputState;
+    use crate::netplay::state_hash;
+
+    const W: f64 = 800.0;
+    const H: f64 = 600.0;
+    const DT: f64 = 1.0 / 60.0;
+
+    /// Busy, deterministic input: turning, thrusting and firing on different beats.
+    fn input(frame: u64) -> InputState {
+        InputState {
+            rotate_left: frame % 90 < 30,
+            rotate_right: frame % 90 >= 60,
+            thrust: frame % 40 < 15,
+            fire: frame.is_multiple_of(7),
+            ..InputState::default()
+        }
+    }
+
+    fn run(state: &mut PlayingState, frames: u64) {
+        for _ in 0..frames {
+            let frame = state.frame_count;
+            state.update_players([&input(frame), &input(frame + 45)], DT, W, H);
+        }
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("tuisteroids-save-{}", std::process::id()))
+            .join(name)
+    }
+
+    // === Requirement: Save Files ===
+
+    // Scenario: A restored game continues bit-identically
+    #[test]
+    fn test_restore_is_bit_identical() {
+        let mut original = PlayingState::new_seeded(W, H, 35);
+        original.add_player_two(Multiplayer::Versus, W, H);
+        run(&mut original, 900);
+
+        let json = SaveGame::new(W, H, original.clone(), None).to_json();
+        let mut restored = SaveGame::from_json(&json).unwrap().playing;
+        assert_eq!(state_hash(&restored), state_hash(&original));
+
+        run(&mut original, 1800);
+        run(&mut restored, 1800);
+        assert_eq!(state_hash(&restored), state_hash(&original));
+        assert_eq!(
+            SaveGame::new(W, H, restored, None).to_json(),
+            SaveGame::new(W, H, original, None).to_json()
+        );
+    }
+
+    // Scenario: Saves round-trip through a file
+    #[test]
+    fn test_write_and_read() {
+        let path = temp_path("nested/quicksave.json");
+        let mut playing = PlayingState::new_seeded(W, H, 4);
+        run(&mut playing, 120);
+        let turns = Turns::new(PlayingState::new_seeded(W, H, 5));
+        SaveGame::new(1000.0, H, playing.clone(), Some(turns))
+            .write(&path)
+            .unwrap();
+        assert!(!path.with_extension("partial").exists());
+
+        let save = SaveGame::read(&path).unwrap();
+        assert_eq!(save.world_width, 1000.0);
+        assert_eq!(state_hash(&save.playing), state_hash(&playing));
+        assert_eq!(save.turns.unwrap().benched().unwrap().score, 0);
+        std::fs::remove_dir_all(path.parent().unwrap().parent().unwrap()).unwrap();
+    }
+
+    // Scenario: Unreadable saves are rejected
+    #[test]
+    fn test_invalid_saves() {
+        let save = SaveGame::new(W, H, PlayingState::new_seeded(W, H, 1), None);
+        let future = save
+            .to_json()
+            .replacen("\"version\":1", "\"version\":99", 1);
+        let err = SaveGame::from_json(&future).err().unwrap();
+        assert!(err.to_string().contains("version 99"));
+        assert!(SaveGame::from_json("{\"version\":1}").is_err());
+        assert!(SaveGame::from_json("not json").is_err());
+        assert_eq!(
+            SaveGame::read(&temp_path("missing.json"))
+                .err()
+                .unwrap()
+                .kind(),
+            io::ErrorKind::NotFound
+        );
+    }
+
+    // Scenario: Quicksaves go to the user's data directory
+    #[test]
+    fn test_default_path() {
+        let path = default_path();
+        assert!(path.ends_with("quicksave.json"));
+        if std::env::var_os("XDG_DATA_HOME").is_some() || std::env::var_os("HOME").is_some() {
+            assert!(path.parent().unwrap().ends_with("tuisteroids"));
+        }
+    }
+}
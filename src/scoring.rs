@@ -0,0 +1,352 @@
+// Scoring capability: combo multipliers, wave-end bonuses, score popups and tallies
+
+use crate::entity::{Render, Update};
+use crate::physics::Vec2;
+use crate::renderer::Playfield;
+use serde::{Deserialize, Serialize};
+
+pub const COMBO_WINDOW: f64 = 1.5; // seconds after a kill for the next to extend the combo
+pub const COMBO_STEP: u32 = 2; // kills per multiplier step
+pub const MAX_MULTIPLIER: u32 = 4;
+pub const ACCURACY_BONUS: u32 = 10; // points per percent of shots that hit
+pub const NO_DEATH_BONUS: u32 = 1000;
+pub const SPEED_PAR: f64 = 60.0; // seconds to clear a wave before the speed bonus runs out
+pub const SPEED_BONUS: u32 = 20; // points per whole second under par
+pub const POPUP_LIFETIME: f64 = 1.0; // seconds
+pub const POPUP_RISE: f64 = 30.0; // units per second
+
+/// One player's combo and their shots, hits and deaths in the current wave.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Scoring {
+    /// Kills in the current combo.
+    pub combo: u32,
+    /// Seconds left for another kill to extend the combo.
+    pub combo_timer: f64,
+    /// Highest multiplier reached this wave.
+    pub best_multiplier: u32,
+    pub shots: u32,
+    pub hits: u32,
+    pub deaths: u32,
+    /// Seconds since the wave started.
+    pub wave_time: f64,
+}
+
+impl Scoring {
+    /// Multiplier the next kill scores at: one more for every `COMBO_STEP` kills in
+    /// the combo so far, up to `MAX_MULTIPLIER`.
+    pub fn multiplier(&self) -> u32 {
+        (1 + self.combo / COMBO_STEP).min(MAX_MULTIPLIER)
+    }
+
+    /// Score a kill worth `points`: returns the points times the multiplier, and
+    /// extends the combo.
+    pub fn kill(&mut self, points: u32) -> (u32, u32) {
+        let multiplier = self.multiplier();
+        self.best_multiplier = self.best_multiplier.max(multiplier);
+        self.combo += 1;
+        self.combo_timer = COMBO_WINDOW;
+        self.hits += 1;
+        (points * multiplier, multiplier)
+    }
+
+    /// Count bullets fired for accuracy.
+    pub fn shot(&mut self, bullets: usize) {
+        self.shots += bullets as u32;
+    }
+
+    /// The ship was destroyed: the combo ends and the no-death bonus is lost.
+    pub fn death(&mut self) {
+        self.deaths += 1;
+        self.combo = 0;
+        self.combo_timer = 0.0;
+    }
+
+    /// Run down the combo window and the wave clock.
+    pub fn update(&mut self, dt: f64) {
+        self.wave_time += dt;
+        self.combo_timer = (self.combo_timer - dt).max(0.0);
+        if self.combo_timer == 0.0 {
+            self.combo = 0;
+        }
+    }
+
+    /// Bonuses for clearing wave `wave`.
+    pub fn finish_wave(&self, wave: u32) -> WaveTally {
+        let accuracy = (self.shots > 0).then(|| (self.hits * 100 / self.shots).min(100));
+        let under_par = (SPEED_PAR - self.wave_time).max(0.0) as u32;
+        WaveTally {
+            wave,
+            accuracy,
+            accuracy_bonus: accuracy.unwrap_or(0) * ACCURACY_BONUS,
+            no_death_bonus: if self.deaths == 0 { NO_DEATH_BONUS } else { 0 },
+            clear_time: self.wave_time,
+            speed_bonus: under_par * SPEED_BONUS,
+            best_multiplier: self.best_multiplier.max(1),
+        }
+    }
+
+    /// Start counting a new wave. A combo carries over.
+    pub fn start_wave(&mut self) {
+        *self = Self {
+            combo: self.combo,
+            combo_timer: self.combo_timer,
+            ..Self::default()
+        };
+    }
+}
+
+/// One player's bonuses for clearing a wave.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WaveTally {
+    pub wave: u32,
+    /// Percent of shots that hit, if any were fired.
+    pub accuracy: Option<u32>,
+    pub accuracy_bonus: u32,
+    pub no_death_bonus: u32,
+    /// Seconds the wave took.
+    pub clear_time: f64,
+    pub speed_bonus: u32,
+    pub best_multiplier: u32,
+}
+
+impl WaveTally {
+    pub fn total(&self) -> u32 {
+        self.accuracy_bonus + self.no_death_bonus + self.speed_bonus
+    }
+
+    /// Lines for the end-of-wave tally screen: each bonus with what earned it.
+    pub fn lines(&self) -> Vec<String> {
+        let row = |label: &str, value: String, points: String| {
+            format!("{:<11}{:>5}{:>7}", label, value, points)
+                .trim_end()
+                .to_string()
+        };
+        let accuracy = match self.accuracy {
+            Some(percent) => format!("{}%", percent),
+            None => "-".to_string(),
+        };
+        let time = format!("{}s", self.clear_time.ceil() as u32);
+        vec![
+            format!("WAVE {} CLEAR", self.wave),
+            row("Accuracy", accuracy, self.accuracy_bonus.to_string()),
+            row("No deaths", String::new(), self.no_death_bonus.to_string()),
+            row("Time", time, self.speed_bonus.to_string()),
+            row(
+                "Best combo",
+                format!("x{}", self.best_multiplier),
+                String::new(),
+            ),
+            row("Bonus", String::new(), self.total().to_string()),
+        ]
+    }
+}
+
+/// The tally screen for one or two players. Two players each get their bonus total.
+pub fn tally_lines(tallies: &[WaveTally]) -> Vec<String> {
+    match tallies {
+        [] => Vec::new(),
+        [tally] => tally.lines(),
+        [first, ..] => std::iter::once(format!("WAVE {} CLEAR", first.wave))
+            .chain(
+                tallies
+                    .iter()
+                    .enumerate()
+                    .map(|(i, tally)| format!("{}UP bonus {:>6}", i + 1, tally.total())),
+            )
+            .collect(),
+    }
+}
+
+/// Points floating up from where a rock was destroyed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Popup {
+    pub position: Vec2,
+    pub points: u32,
+    pub multiplier: u32,
+    /// Seconds since it appeared.
+    pub age: f64,
+}
+
+impl Popup {
+    pub fn new(position: Vec2, points: u32, multiplier: u32) -> Self {
+        Self {
+            position,
+            points,
+            multiplier,
+            age: 0.0,
+        }
+    }
+
+    /// Points scored, with the multiplier if there was one.
+    pub fn text(&self) -> String {
+        match self.multiplier {
+            1 => self.points.to_string(),
+            m => format!("{} x{}", self.points, m),
+        }
+    }
+}
+
+impl Update for Popup {
+    /// Rise and age; popups float over the wrap edge rather than wrapping.
+    fn update(&mut self, dt: f64, _world_width: f64, _world_height: f64) {
+        self.position = Vec2::new(self.position.x, self.position.y - POPUP_RISE * dt);
+        self.age += dt;
+    }
+
+    /// Faded out.
+    fn expired(&self) -> bool {
+        self.age >= POPUP_LIFETIME
+    }
+}
+
+impl Render for Popup {
+    fn render(&self, playfield: &mut Playfield, _blink: bool) {
+        playfield.add_label(self.position, &self.text());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // === Requirement: Combo Multiplier ===
+
+    // Scenario: Quick consecutive kills raise the multiplier
+    #[test]
+    fn test_combo_raises_multiplier() {
+        let mut scoring = Scoring::default();
+        let scored: Vec<(u32, u32)> = (0..10).map(|_| scoring.kill(100)).collect();
+        assert_eq!(scored[0], (100, 1));
+        assert_eq!(scored[1], (100, 1));
+        assert_eq!(scored[2], (200, 2));
+        assert_eq!(scored[4], (300, 3));
+        assert_eq!(scored[6], (400, 4));
+        assert_eq!(scored[9], (400, MAX_MULTIPLIER));
+    }
+
+    // Scenario: Combo ends when the window passes
+    #[test]
+    fn test_combo_expires() {
+        let mut scoring = Scoring::default();
+        scoring.kill(100);
+        scoring.kill(100);
+        scoring.update(COMBO_WINDOW - 0.1);
+        assert_eq!(scoring.multiplier(), 2);
+        scoring.update(0.2);
+        assert_eq!(scoring.multiplier(), 1);
+        assert_eq!(scoring.combo, 0);
+    }
+
+    // Scenario: Death ends the combo
+    #[test]
+    fn test_death_ends_combo() {
+        let mut scoring = Scoring::default();
+        for _ in 0..4 {
+            scoring.kill(50);
+        }
+        scoring.death();
+        assert_eq!(scoring.multiplier(), 1);
+        assert_eq!(scoring.deaths, 1);
+    }
+
+    // === Requirement: Wave Bonuses ===
+
+    // Scenario: Clean, accurate, quick wave earns every bonus
+    #[test]
+    fn test_wave_bonuses() {
+        let mut scoring = Scoring::default();
+        scoring.shot(4);
+        for _ in 0..3 {
+            scoring.kill(20);
+        }
+        scoring.update(20.5);
+        let tally = scoring.finish_wave(2);
+        assert_eq!(tally.accuracy, Some(75));
+        assert_eq!(tally.accuracy_bonus, 750);
+        assert_eq!(tally.no_death_bonus, NO_DEATH_BONUS);
+        assert_eq!(tally.speed_bonus, 39 * SPEED_BONUS);
+        assert_eq!(tally.best_multiplier, 2);
+        assert_eq!(tally.total(), 750 + 1000 + 780);
+        scoring.kill(20);
+        scoring.start_wave();
+        assert_eq!(scoring.shots + scoring.hits + scoring.deaths, 0);
+        assert_eq!(scoring.wave_time, 0.0);
+        assert_eq!(scoring.combo, 1, "combo carries over");
+    }
+
+    // Scenario: Deaths, misses and slow clears earn nothing
+    #[test]
+    fn test_no_wave_bonuses() {
+        let mut scoring = Scoring::default();
+        scoring.death();
+        scoring.update(SPEED_PAR + 5.0);
+        let tally = scoring.finish_wave(1);
+        assert_eq!(tally.accuracy, None);
+        assert_eq!(tally.total(), 0);
+        assert_eq!(tally.best_multiplier, 1);
+    }
+
+    // Scenario: Accuracy is capped when bullets hit more than once
+    #[test]
+    fn test_accuracy_capped() {
+        let mut scoring = Scoring::default();
+        scoring.shot(1);
+        scoring.kill(20);
+        scoring.kill(50);
+        assert_eq!(scoring.finish_wave(1).accuracy, Some(100));
+    }
+
+    // Scenario: Tally lists each bonus and the total
+    #[test]
+    fn test_tally_lines() {
+        let tally = WaveTally {
+            wave: 3,
+            accuracy: Some(50),
+            accuracy_bonus: 500,
+            no_death_bonus: 0,
+            clear_time: 41.2,
+            speed_bonus: 360,
+            best_multiplier: 3,
+        };
+        assert_eq!(
+            tally.lines(),
+            vec![
+                "WAVE 3 CLEAR",
+                "Accuracy     50%    500",
+                "No deaths             0",
+                "Time         42s    360",
+                "Best combo    x3",
+                "Bonus               860",
+            ]
+        );
+    }
+
+    // Scenario: Two players each see their bonus
+    #[test]
+    fn test_two_player_tally_lines() {
+        let mut one = Scoring::default();
+        one.death();
+        let two = Scoring::default();
+        let tallies = [one.finish_wave(4), two.finish_wave(4)];
+        assert_eq!(
+            tally_lines(&tallies),
+            vec!["WAVE 4 CLEAR", "1UP bonus   1200", "2UP bonus   2200"]
+        );
+        assert!(tally_lines(&[]).is_empty());
+    }
+
+    // === Requirement: Score Popups ===
+
+    // Scenario: Popup rises and fades
+    #[test]
+    fn test_popup_rises_and_fades() {
+        let mut popup = Popup::new(Vec2::new(100.0, 100.0), 200, 2);
+        assert_eq!(popup.text(), "200 x2");
+        popup.update(0.5, 800.0, 600.0);
+        assert!(!popup.expired());
+        assert!((popup.position.y - (100.0 - POPUP_RISE * 0.5)).abs() < 1e-9);
+        popup.update(0.5, 800.0, 600.0);
+        assert!(popup.expired());
+        assert_eq!(Popup::new(Vec2::new(0.0, 0.0), 100, 1).text(), "100");
+    }
+}
@@ -1,6 +1,11 @@
 // Ship capability: player ship with rotation, thrust, lives, respawn
 
+use crate::collision::{Collider, Shape};
+use crate::entity::{Render, Update};
 use crate::physics::{self, Vec2};
+use crate::pickups::PICKUP_BLINK_TIME;
+use crate::renderer::Playfield;
+use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
 
 pub const ROTATION_SPEED: f64 = 5.0; // radians per second
@@ -9,13 +14,21 @@ pub const MAX_SPEED: f64 = 400.0; // units per second
 pub const SHIP_RADIUS: f64 = 12.0;
 pub const INITIAL_LIVES: u32 = 3;
 pub const INVULNERABILITY_DURATION: f64 = 3.0; // seconds
-pub const EXTRA_LIFE_SCORE: u32 = 10_000;
+pub const EXTRA_LIFE_SCORE: u32 = 10_000; // points per extra life by default
+pub const SHIELD_RADIUS: f64 = 20.0; // the shield bubble, drawn and bounced off
+pub const SHIP_MASS: f64 = 1.0; // for bouncing off rocks with the shield up
+pub const SHIELD_MAX_ENERGY: f64 = 1.0;
+pub const SHIELD_DRAIN: f64 = 0.5; // energy per second while the shield is up
+pub const SHIELD_RECHARGE: f64 = 0.1; // energy per second while it is down
+pub const SHIELD_MIN_ENERGY: f64 = 0.25; // needed to raise the shield again
+const SHIELD_SIDES: usize = 16;
 
 // Ship triangle vertices relative to center (pointing right at angle 0)
 const NOSE_OFFSET: f64 = 15.0;
 const WING_OFFSET: f64 = 10.0;
 const WING_SPREAD: f64 = 8.0;
 
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Ship {
     pub position: Vec2,
     pub velocity: Vec2,
@@ -23,7 +36,37 @@ pub struct Ship {
     pub lives: u32,
     pub invulnerable: bool,
     pub invulnerable_timer: f64,
-    pub extra_life_awarded: bool,
+    /// Seconds left on the shield power-up.
+    #[serde(default)]
+    pub shield_timer: f64,
+    /// Energy for the shield action, from 0 to `SHIELD_MAX_ENERGY`.
+    #[serde(default = "full_shield_energy")]
+    pub shield_energy: f64,
+    /// The shield action is up: the ship bounces off rocks.
+    #[serde(default)]
+    pub shield_up: bool,
+}
+
+fn full_shield_energy() -> f64 {
+    SHIELD_MAX_ENERGY
+}
+
+/// When ships earn extra lives: one at every multiple of `every` points, never taking
+/// the ship past `max_lives` if there is a cap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExtraLives {
+    /// Points per extra life. Zero awards none.
+    pub every: u32,
+    pub max_lives: Option<u32>,
+}
+
+impl Default for ExtraLives {
+    fn default() -> Self {
+        Self {
+            every: EXTRA_LIFE_SCORE,
+            max_lives: None,
+        }
+    }
 }
 
 impl Ship {
@@ -35,7 +78,9 @@ impl Ship {
             lives: INITIAL_LIVES,
             invulnerable: false,
             invulnerable_timer: 0.0,
-            extra_life_awarded: false,
+            shield_timer: 0.0,
+            shield_energy: SHIELD_MAX_ENERGY,
+            shield_up: false,
         }
     }
 
@@ -90,20 +135,46 @@ impl Ship {
         }
     }
 
-    /// Update position, apply drag, wrap.
-    pub fn update(&mut self, dt: f64, world_width: f64, world_height: f64) {
-        self.position = physics::integrate_motion(self.position, self.velocity, dt);
-        self.position = physics::wrap_position(self.position, world_width, world_height);
+    /// In play and not blinked out by invulnerability.
+    pub fn visible(&self, blink: bool) -> bool {
+        self.lives > 0 && (!self.invulnerable || blink)
+    }
 
-        if self.invulnerable {
-            self.invulnerable_timer -= dt;
-            if self.invulnerable_timer <= 0.0 {
-                self.invulnerable = false;
-                self.invulnerable_timer = 0.0;
-            }
+    /// Protected by the shield power-up or the shield action: rocks and bullets
+    /// cannot destroy the ship.
+    pub fn shielded(&self) -> bool {
+        self.shield_timer > 0.0 || self.shield_up
+    }
+
+    /// Raise or lower the shield action. Energy drains while it is up and recharges
+    /// while it is down; it drops when empty and needs `SHIELD_MIN_ENERGY` to raise.
+    pub fn update_shield(&mut self, raise: bool, dt: f64) {
+        if raise && (self.shield_up || self.shield_energy >= SHIELD_MIN_ENERGY) {
+            self.shield_energy = (self.shield_energy - SHIELD_DRAIN * dt).max(0.0);
+            self.shield_up = self.shield_energy > 0.0;
+        } else {
+            self.shield_up = false;
+            self.shield_energy = (self.shield_energy + SHIELD_RECHARGE * dt).min(SHIELD_MAX_ENERGY);
+        }
+    }
+
+    /// The shield bubble around the ship, for bouncing rocks off.
+    pub fn shield(&self) -> Shield {
+        Shield {
+            position: self.position,
         }
     }
 
+    /// The shield bubble's outline around the ship.
+    pub fn shield_vertices(&self) -> Vec<Vec2> {
+        (0..SHIELD_SIDES)
+            .map(|i| {
+                let angle = i as f64 * 2.0 * PI / SHIELD_SIDES as f64;
+                self.position + Vec2::from_angle(angle).scale(SHIELD_RADIUS)
+            })
+            .collect()
+    }
+
     /// Get the nose position (for spawning bullets).
     pub fn nose_position(&self) -> Vec2 {
         self.vertices()[0]
@@ -119,8 +190,10 @@ impl Ship {
         }
     }
 
-    /// Respawn at center with invulnerability.
+    /// Respawn at center with invulnerability. Any shield is lost.
     pub fn respawn(&mut self, world_width: f64, world_height: f64) {
+        self.shield_timer = 0.0;
+        self.shield_up = false;
         self.position = Vec2::new(world_width / 2.0, world_height / 2.0);
         self.velocity = Vec2::new(0.0, 0.0);
         self.rotation = -PI / 2.0; // facing up
@@ -128,11 +201,80 @@ impl Ship {
         self.invulnerable_timer = INVULNERABILITY_DURATION;
     }
 
-    /// Check and award extra life at score threshold.
-    pub fn check_extra_life(&mut self, score: u32) {
-        if !self.extra_life_awarded && score >= EXTRA_LIFE_SCORE {
-            self.lives += 1;
-            self.extra_life_awarded = true;
+    /// Award an extra life for each threshold the score crossed going from `from` to
+    /// `to`, up to the cap. Returns how many were awarded.
+    pub fn check_extra_life(&mut self, from: u32, to: u32, rule: ExtraLives) -> u32 {
+        if rule.every == 0 {
+            return 0;
+        }
+        let crossed = (to / rule.every).saturating_sub(from / rule.every);
+        let room = rule
+            .max_lives
+            .map_or(u32::MAX, |max| max.saturating_sub(self.lives));
+        let awarded = crossed.min(room);
+        self.lives += awarded;
+        awarded
+    }
+}
+
+impl Update for Ship {
+    /// Update position, apply drag, wrap.
+    fn update(&mut self, dt: f64, world_width: f64, world_height: f64) {
+        self.position = physics::integrate_motion(self.position, self.velocity, dt);
+        self.position = physics::wrap_position(self.position, world_width, world_height);
+
+        if self.invulnerable {
+            self.invulnerable_timer -= dt;
+            if self.invulnerable_timer <= 0.0 {
+                self.invulnerable = false;
+                self.invulnerable_timer = 0.0;
+            }
+        }
+        self.shield_timer = (self.shield_timer - dt).max(0.0);
+    }
+}
+
+impl Render for Ship {
+    /// The hull, and the shield while it is up; a running-out shield power-up blinks.
+    fn render(&self, playfield: &mut Playfield, blink: bool) {
+        if !self.visible(blink) {
+            return;
+        }
+        playfield.add_polygon(&self.vertices());
+        let power_up_shield =
+            self.shield_timer > PICKUP_BLINK_TIME || (self.shield_timer > 0.0 && blink);
+        if self.shield_up || power_up_shield {
+            playfield.add_polygon(&self.shield_vertices());
+        }
+    }
+}
+
+impl Collider for Ship {
+    fn position(&self) -> Vec2 {
+        self.position
+    }
+
+    fn shape(&self) -> Shape {
+        Shape::Circle {
+            radius: SHIP_RADIUS,
+        }
+    }
+}
+
+/// A ship's shield bubble, which rocks bounce off.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Shield {
+    pub position: Vec2,
+}
+
+impl Collider for Shield {
+    fn position(&self) -> Vec2 {
+        self.position
+    }
+
+    fn shape(&self) -> Shape {
+        Shape::Circle {
+            radius: SHIELD_RADIUS,
         }
     }
 }
@@ -326,6 +468,78 @@ mod tests {
         assert!(!ship.invulnerable);
     }
 
+    // === Requirement: Ship Shield Power-Up ===
+
+    // Scenario: Shield runs down
+    #[test]
+    fn test_shield_runs_down() {
+        let mut ship = Ship::new(400.0, 300.0);
+        assert!(!ship.shielded());
+        ship.shield_timer = 1.0;
+        ship.update(0.6, 800.0, 600.0);
+        assert!(ship.shielded());
+        ship.update(0.6, 800.0, 600.0);
+        assert!(!ship.shielded());
+        assert_eq!(ship.shield_timer, 0.0);
+    }
+
+    // Scenario: Shield is lost on respawn
+    #[test]
+    fn test_shield_lost_on_respawn() {
+        let mut ship = Ship::new(400.0, 300.0);
+        ship.shield_timer = 5.0;
+        ship.respawn(800.0, 600.0);
+        assert!(!ship.shielded());
+    }
+
+    // === Requirement: Ship Shield Action ===
+
+    // Scenario: Shield drains while up and recharges while down
+    #[test]
+    fn test_shield_energy_drains_and_recharges() {
+        let mut ship = Ship::new(400.0, 300.0);
+        ship.update_shield(true, 1.0);
+        assert!(ship.shield_up && ship.shielded());
+        assert!(approx_eq(
+            ship.shield_energy,
+            SHIELD_MAX_ENERGY - SHIELD_DRAIN
+        ));
+        ship.update_shield(false, 1.0);
+        assert!(!ship.shield_up);
+        assert!(approx_eq(
+            ship.shield_energy,
+            SHIELD_MAX_ENERGY - SHIELD_DRAIN + SHIELD_RECHARGE
+        ));
+        ship.update_shield(false, 100.0);
+        assert!(approx_eq(ship.shield_energy, SHIELD_MAX_ENERGY));
+    }
+
+    // Scenario: Empty shield drops and waits for enough energy
+    #[test]
+    fn test_shield_drops_when_empty() {
+        let mut ship = Ship::new(400.0, 300.0);
+        ship.update_shield(true, 10.0);
+        assert!(!ship.shield_up);
+        assert_eq!(ship.shield_energy, 0.0);
+        ship.update_shield(false, 1.0);
+        ship.update_shield(true, 0.0);
+        assert!(!ship.shield_up, "needs the minimum to raise");
+        ship.update_shield(false, 2.0);
+        ship.update_shield(true, 0.1);
+        assert!(ship.shield_up);
+    }
+
+    // Scenario: Shield bubble surrounds the ship
+    #[test]
+    fn test_shield_vertices() {
+        let ship = Ship::new(400.0, 300.0);
+        let bubble = ship.shield_vertices();
+        assert_eq!(bubble.len(), SHIELD_SIDES);
+        for v in bubble {
+            assert!(approx_eq((v - ship.position).magnitude(), SHIELD_RADIUS));
+        }
+    }
+
     // === Requirement: Extra Life ===
 
     // Scenario: Extra life awarded at 10000 points
@@ -333,18 +547,55 @@ mod tests {
     fn test_extra_life_at_10000() {
         let mut ship = Ship::new(400.0, 300.0);
         assert_eq!(ship.lives, 3);
-        ship.check_extra_life(10_050);
+        assert_eq!(
+            ship.check_extra_life(9_950, 10_050, ExtraLives::default()),
+            1
+        );
         assert_eq!(ship.lives, 4);
     }
 
-    // Scenario: Extra life only awarded once
+    // Scenario: Extra life awarded at every multiple, once each
     #[test]
-    fn test_extra_life_only_once() {
+    fn test_extra_life_every_10000() {
+        let rule = ExtraLives::default();
         let mut ship = Ship::new(400.0, 300.0);
-        ship.check_extra_life(10_050);
+        ship.check_extra_life(9_950, 10_050, rule);
+        assert_eq!(ship.check_extra_life(10_050, 19_900, rule), 0);
         assert_eq!(ship.lives, 4);
-        ship.check_extra_life(20_000);
-        assert_eq!(ship.lives, 4); // no second extra life
+        assert_eq!(ship.check_extra_life(19_900, 20_000, rule), 1);
+        assert_eq!(ship.lives, 5);
+    }
+
+    // Scenario: A score jump across several thresholds awards a life for each
+    #[test]
+    fn test_extra_life_several_thresholds() {
+        let mut ship = Ship::new(400.0, 300.0);
+        assert_eq!(
+            ship.check_extra_life(5_000, 32_000, ExtraLives::default()),
+            3
+        );
+        assert_eq!(ship.lives, 6);
+    }
+
+    // Scenario: Interval is configurable and lives stop at the cap
+    #[test]
+    fn test_extra_life_interval_and_cap() {
+        let rule = ExtraLives {
+            every: 5_000,
+            max_lives: Some(5),
+        };
+        let mut ship = Ship::new(400.0, 300.0);
+        assert_eq!(ship.check_extra_life(0, 5_000, rule), 1);
+        assert_eq!(ship.check_extra_life(5_000, 25_000, rule), 1);
+        assert_eq!(ship.lives, 5);
+        assert_eq!(ship.check_extra_life(25_000, 30_000, rule), 0);
+        assert_eq!(ship.lives, 5);
+
+        let none = ExtraLives {
+            every: 0,
+            max_lives: None,
+        };
+        assert_eq!(ship.check_extra_life(0, 100_000, none), 0);
     }
 
     // === Requirement: Ship Screen Wrapping ===
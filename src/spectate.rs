@@ -0,0 +1,441 @@
+// Spectate capability: stream a live game to watchers over TCP or a Unix socket
+//
+// The playing instance publishes one JSON `Scene` per rendered frame. Scenes carry
+// world coordinates rather than rasterized text, so each watcher draws them with the
+// same renderer at its own terminal size. Watchers have no control over the game.
+
+use crate::game::TIMESTEP;
+use crate::renderer::{self, Scene};
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Frames queued per watcher. A watcher that falls further behind skips frames
+/// instead of slowing the game down.
+pub const WATCHER_BACKLOG: usize = 8;
+
+/// Where a broadcast is published: a TCP address, or a Unix socket path (anything
+/// containing a `/`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Endpoint {
+    Tcp(String),
+    Unix(PathBuf),
+}
+
+impl Endpoint {
+    pub fn parse(addr: &str) -> Self {
+        if addr.contains('/') {
+            Endpoint::Unix(PathBuf::from(addr))
+        } else {
+            Endpoint::Tcp(addr.to_string())
+        }
+    }
+}
+
+impl fmt::Display for Endpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Endpoint::Tcp(addr) => write!(f, "{}", addr),
+            Endpoint::Unix(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+type Watchers = Arc<Mutex<Vec<SyncSender<Arc<str>>>>>;
+
+/// Accepts watchers in the background and sends each of them every published scene.
+pub struct Broadcaster {
+    watchers: Watchers,
+    endpoint: Endpoint,
+}
+
+impl Broadcaster {
+    /// Start listening for watchers. A TCP port of 0 picks a free port; see `endpoint`.
+    pub fn bind(endpoint: &Endpoint) -> io::Result<Self> {
+        let watchers: Watchers = Arc::default();
+        let accepting = Arc::clone(&watchers);
+        let endpoint = match endpoint {
+            Endpoint::Tcp(addr) => {
+                let listener = TcpListener::bind(addr)?;
+                let bound = Endpoint::Tcp(listener.local_addr()?.to_string());
+                thread::spawn(move || {
+                    for stream in listener.incoming().flatten() {
+                        let _ = stream.set_nodelay(true);
+                        add_watcher(&accepting, stream);
+                    }
+                });
+                bound
+            }
+            #[cfg(unix)]
+            Endpoint::Unix(path) => {
+                let listener = std::os::unix::net::UnixListener::bind(path)?;
+                thread::spawn(move || {
+                    for stream in listener.incoming().flatten() {
+                        add_watcher(&accepting, stream);
+                    }
+                });
+                Endpoint::Unix(path.clone())
+            }
+            #[cfg(not(unix))]
+            Endpoint::Unix(_) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    "Unix sockets are not supported on this platform",
+                ))
+            }
+        };
+        Ok(Self { watchers, endpoint })
+    }
+
+    /// Where watchers can connect.
+    pub fn endpoint(&self) -> &Endpoint {
+        &self.endpoint
+    }
+
+    /// Number of connected watchers (as of the last publish).
+    pub fn watcher_count(&self) -> usize {
+        self.watchers.lock().unwrap().len()
+    }
+
+    /// Send a scene to every watcher without blocking. Watchers whose connection has
+    /// closed are dropped.
+    pub fn publish(&self, scene: &Scene) {
+        let mut watchers = self.watchers.lock().unwrap();
+        if watchers.is_empty() {
+            return;
+        }
+        let Ok(json) = serde_json::to_string(scene) else {
+            return;
+        };
+        let line: Arc<str> = format!("{}\n", json).into();
+        watchers.retain(|watcher| {
+            !matches!(
+                watcher.try_send(Arc::clone(&line)),
+                Err(TrySendError::Disconnected(_))
+            )
+        });
+    }
+}
+
+impl Drop for Broadcaster {
+    fn drop(&mut self) {
+        if let Endpoint::Unix(ref path) = self.endpoint {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+/// Give a new watcher its own queue and writer thread.
+fn add_watcher<W: Write + Send + 'static>(watchers: &Watchers, mut writer: W) {
+    let (sender, receiver) = mpsc::sync_channel::<Arc<str>>(WATCHER_BACKLOG);
+    thread::spawn(move || {
+        for line in receiver {
+            if writer
+                .write_all(line.as_bytes())
+                .and_then(|_| writer.flush())
+                .is_err()
+            {
+                break;
+            }
+        }
+    });
+    watchers.lock().unwrap().push(sender);
+}
+
+/// What a watcher receives from a broadcast.
+#[derive(Debug, PartialEq)]
+pub enum FeedEvent {
+    Scene(Box<Scene>),
+    /// The broadcast stopped: cleanly (`None`) or with an error message.
+    Ended(Option<String>),
+}
+
+/// Read scenes on a background thread. The last event is always `Ended`.
+pub fn feed<R: BufRead + Send + 'static>(reader: R) -> Receiver<FeedEvent> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for line in reader.lines() {
+            let event = match line {
+                Ok(line) => match serde_json::from_str(&line) {
+                    Ok(scene) => FeedEvent::Scene(Box::new(scene)),
+                    Err(e) => FeedEvent::Ended(Some(format!("bad frame: {}", e))),
+                },
+                Err(e) => FeedEvent::Ended(Some(e.to_string())),
+            };
+            let ended = matches!(event, FeedEvent::Ended(_));
+            if sender.send(event).is_err() || ended {
+                return;
+            }
+        }
+        let _ = sender.send(FeedEvent::Ended(None));
+    });
+    receiver
+}
+
+/// Connect to a broadcast.
+pub fn connect(endpoint: &Endpoint) -> io::Result<Box<dyn BufRead + Send>> {
+    match endpoint {
+        Endpoint::Tcp(addr) => Ok(Box::new(BufReader::new(TcpStream::connect(addr)?))),
+        #[cfg(unix)]
+        Endpoint::Unix(path) => Ok(Box::new(BufReader::new(
+            std::os::unix::net::UnixStream::connect(path)?,
+        ))),
+        #[cfg(not(unix))]
+        Endpoint::Unix(_) => Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Unix sockets are not supported on this platform",
+        )),
+    }
+}
+
+/// Watch a broadcast in this terminal until Q or Esc is pressed, or any key once the
+/// broadcast has ended.
+#[cfg(not(tarpaulin_include))]
+pub fn watch(endpoint: &Endpoint) -> io::Result<()> {
+    use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+    use crossterm::{cursor, execute, terminal};
+    use ratatui::backend::CrosstermBackend;
+    use ratatui::style::{Color, Style};
+    use ratatui::text::{Line, Span};
+    use ratatui::widgets::Paragraph;
+    use ratatui::Terminal;
+    use std::time::Duration;
+
+    let events = feed(connect(endpoint)?);
+
+    terminal::enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(stdout))?;
+
+    let mut scene = None;
+    let mut ended: Option<Option<String>> = None;
+    let result = loop {
+        while let Ok(event) = events.try_recv() {
+            match event {
+                FeedEvent::Scene(latest) => scene = Some(latest),
+                FeedEvent::Ended(error) => ended = Some(error),
+            }
+        }
+
+        let mut quit = false;
+        while event::poll(Duration::ZERO)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    quit |= ended.is_some()
+                        || matches!(key.code, KeyCode::Char('q' | 'Q') | KeyCode::Esc);
+                }
+            }
+        }
+        if quit {
+            break Ok(());
+        }
+
+        let status = match ended {
+            Some(None) => Some("Broadcast ended. Press any key to exit".to_string()),
+            Some(Some(ref error)) => Some(format!("Broadcast lost: {}. Press any key", error)),
+            None if scene.is_none() => Some(format!("Waiting for {}...", endpoint)),
+            None => None,
+        };
+        if let Err(e) = terminal.draw(|frame| {
+            let area = frame.area();
+            let (cols, rows) = (area.width as usize, area.height as usize);
+            let mut lines = match scene {
+                Some(ref scene) => renderer::scene_lines(scene, cols, rows),
+                None => Vec::new(),
+            };
+            if let Some(ref status) = status {
+                lines.truncate(rows.saturating_sub(1));
+                lines.push(Line::from(Span::styled(
+                    status.clone(),
+                    Style::default().fg(Color::Yellow),
+                )));
+            }
+            frame.render_widget(Paragraph::new(lines), area);
+        }) {
+            break Err(e);
+        }
+
+        thread::sleep(Duration::from_secs_f64(TIMESTEP));
+    };
+
+    terminal::disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        terminal::LeaveAlternateScreen,
+        cursor::Show
+    )?;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Playfield;
+    use std::io::Cursor;
+    use std::time::{Duration, Instant};
+
+    fn scene(hud: &str) -> Scene {
+        Scene::Playing {
+            playfield: Playfield::new(800.0, 600.0),
+            banner: None,
+            hud: hud.to_string(),
+            tally: Vec::new(),
+            toast: None,
+        }
+    }
+
+    fn wait_for(mut condition: impl FnMut() -> bool) -> bool {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while Instant::now() < deadline {
+            if condition() {
+                return true;
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+        false
+    }
+
+    fn read_scene(reader: &mut impl BufRead) -> Scene {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        serde_json::from_str(&line).unwrap()
+    }
+
+    // === Requirement: Broadcast Endpoints ===
+
+    // Scenario: Paths select a Unix socket, anything else TCP
+    #[test]
+    fn test_endpoint_parse() {
+        assert_eq!(
+            Endpoint::parse("127.0.0.1:7000"),
+            Endpoint::Tcp("127.0.0.1:7000".to_string())
+        );
+        assert_eq!(
+            Endpoint::parse("/tmp/game.sock"),
+            Endpoint::Unix(PathBuf::from("/tmp/game.sock"))
+        );
+        assert_eq!(
+            Endpoint::parse("./game.sock"),
+            Endpoint::Unix(PathBuf::from("./game.sock"))
+        );
+        assert_eq!(
+            Endpoint::parse("/tmp/game.sock").to_string(),
+            "/tmp/game.sock"
+        );
+        assert_eq!(Endpoint::parse("localhost:1").to_string(), "localhost:1");
+    }
+
+    // === Requirement: Scene Broadcast ===
+
+    // Scenario: Every watcher receives published scenes over TCP
+    #[test]
+    fn test_broadcast_tcp() {
+        let broadcaster = Broadcaster::bind(&Endpoint::parse("127.0.0.1:0")).unwrap();
+        let mut one = connect(broadcaster.endpoint()).unwrap();
+        let mut two = connect(broadcaster.endpoint()).unwrap();
+        assert!(wait_for(|| broadcaster.watcher_count() == 2));
+
+        broadcaster.publish(&scene("Score: 10"));
+        assert_eq!(read_scene(&mut one), scene("Score: 10"));
+        assert_eq!(read_scene(&mut two), scene("Score: 10"));
+    }
+
+    // Scenario: Every watcher receives published scenes over a Unix socket
+    #[cfg(unix)]
+    #[test]
+    fn test_broadcast_unix_socket() {
+        let path = std::env::temp_dir().join(format!("tuisteroids-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let endpoint = Endpoint::Unix(path.clone());
+        let broadcaster = Broadcaster::bind(&endpoint).unwrap();
+        let mut watcher = connect(&endpoint).unwrap();
+        assert!(wait_for(|| broadcaster.watcher_count() == 1));
+        broadcaster.publish(&scene("Score: 20"));
+        assert_eq!(read_scene(&mut watcher), scene("Score: 20"));
+
+        // The socket file is cleaned up with the broadcaster
+        drop(broadcaster);
+        assert!(!path.exists());
+    }
+
+    // Scenario: Departed watchers are dropped
+    #[test]
+    fn test_broadcast_drops_closed_watchers() {
+        let broadcaster = Broadcaster::bind(&Endpoint::parse("127.0.0.1:0")).unwrap();
+        let watcher = connect(broadcaster.endpoint()).unwrap();
+        assert!(wait_for(|| broadcaster.watcher_count() == 1));
+        drop(watcher);
+        assert!(wait_for(|| {
+            broadcaster.publish(&scene("Score: 0"));
+            broadcaster.watcher_count() == 0
+        }));
+    }
+
+    // Scenario: A watcher that stops reading does not stall the game
+    #[test]
+    fn test_broadcast_never_blocks() {
+        let broadcaster = Broadcaster::bind(&Endpoint::parse("127.0.0.1:0")).unwrap();
+        let _stalled = connect(broadcaster.endpoint()).unwrap();
+        assert!(wait_for(|| broadcaster.watcher_count() == 1));
+        let big = scene(&"x".repeat(100_000));
+        let start = Instant::now();
+        for _ in 0..200 {
+            broadcaster.publish(&big);
+        }
+        assert!(start.elapsed() < Duration::from_secs(2));
+        assert_eq!(broadcaster.watcher_count(), 1);
+    }
+
+    // Scenario: Publishing without watchers is a no-op
+    #[test]
+    fn test_broadcast_without_watchers() {
+        let broadcaster = Broadcaster::bind(&Endpoint::parse("127.0.0.1:0")).unwrap();
+        broadcaster.publish(&scene("Score: 0"));
+        assert_eq!(broadcaster.watcher_count(), 0);
+        assert!(Broadcaster::bind(&Endpoint::parse("256.0.0.1:0")).is_err());
+    }
+
+    // === Requirement: Watching ===
+
+    // Scenario: Watcher receives scenes until the broadcast ends
+    #[test]
+    fn test_feed_until_end() {
+        let input = format!(
+            "{}\n{}\n",
+            serde_json::to_string(&scene("a")).unwrap(),
+            serde_json::to_string(&scene("b")).unwrap()
+        );
+        let events: Vec<FeedEvent> = feed(Cursor::new(input)).iter().collect();
+        assert_eq!(
+            events,
+            vec![
+                FeedEvent::Scene(Box::new(scene("a"))),
+                FeedEvent::Scene(Box::new(scene("b"))),
+                FeedEvent::Ended(None),
+            ]
+        );
+    }
+
+    // Scenario: Malformed frames end the feed with an error
+    #[test]
+    fn test_feed_bad_frame() {
+        let events: Vec<FeedEvent> = feed(Cursor::new("not json\n{}\n")).iter().collect();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(&events[0], FeedEvent::Ended(Some(e)) if e.starts_with("bad frame")));
+    }
+
+    // Scenario: Watching nothing fails to connect
+    #[test]
+    fn test_connect_refused() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        drop(listener);
+        assert!(connect(&Endpoint::Tcp(addr)).is_err());
+        assert!(connect(&Endpoint::parse("/nonexistent/tuisteroids.sock")).is_err());
+    }
+}
@@ -0,0 +1,428 @@
+// Stats capability: per-game statistics, the post-game summary and lifetime totals
+
+use crate::asteroids::AsteroidSize;
+use crate::events::{Destroyer, GameEvent};
+use crate::save;
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const STATS_FILE: &str = "stats.json";
+pub const NEAR_MISS_MARGIN: f64 = 15.0; // gap between ship and rock that counts as close
+
+const SIZE_LABELS: [&str; 3] = ["Large rocks", "Medium rocks", "Small rocks"];
+
+/// Index into `kills` and `SIZE_LABELS`.
+fn size_index(size: AsteroidSize) -> usize {
+    match size {
+        AsteroidSize::Large => 0,
+        AsteroidSize::Medium => 1,
+        AsteroidSize::Small => 2,
+    }
+}
+
+/// Seconds as minutes and seconds, e.g. "2:05".
+fn clock(seconds: f64) -> String {
+    let seconds = seconds as u64;
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+/// Percent of shots that hit, capped as a piercing bullet can hit more than once.
+fn accuracy(shots: u32, hits: u32) -> String {
+    match shots {
+        0 => "-".to_string(),
+        shots => format!("{}%", (hits as u64 * 100 / shots as u64).min(100)),
+    }
+}
+
+/// A row of the summary: a label, then one right-aligned column per player.
+fn row(label: &str, values: impl IntoIterator<Item = String>) -> String {
+    values
+        .into_iter()
+        .fold(format!("{:<14}", label), |line, value| {
+            format!("{}{:>8}", line, value)
+        })
+        .trim_end()
+        .to_string()
+}
+
+/// One player's statistics for the game so far.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SessionStats {
+    pub shots: u32,
+    /// Rocks destroyed by bullets.
+    pub hits: u32,
+    /// Rocks destroyed, large, medium and small, by bullets or smart bombs.
+    pub kills: [u32; 3],
+    /// Seconds each lost life lasted.
+    pub lives: Vec<f64>,
+    /// Seconds the current life has lasted.
+    pub life_time: f64,
+    /// World units flown.
+    pub distance: f64,
+    /// Seconds spent thrusting.
+    pub thrust_time: f64,
+    pub waves_cleared: u32,
+    /// Rocks that passed close by without hitting the ship.
+    pub near_misses: u32,
+    /// A rock is close to the ship right now.
+    pub close_call: bool,
+}
+
+impl SessionStats {
+    /// Follow one of the player's game events.
+    pub fn observe(&mut self, event: &GameEvent) {
+        match *event {
+            GameEvent::ShipFlew {
+                dt,
+                speed,
+                thrusting,
+                ..
+            } => self.fly(speed, thrusting, dt),
+            GameEvent::BulletFired { bullets, .. } => self.shot(bullets as usize),
+            GameEvent::AsteroidDestroyed {
+                size,
+                by: Destroyer::Bullet { .. },
+                ..
+            } => self.bullet_kill(size),
+            GameEvent::AsteroidDestroyed { size, .. } => self.kill(size),
+            GameEvent::ShipDestroyed { .. } => self.death(),
+            GameEvent::WaveCleared { .. } => self.waves_cleared += 1,
+            _ => {}
+        }
+    }
+
+    /// Count bullets fired.
+    pub fn shot(&mut self, bullets: usize) {
+        self.shots += bullets as u32;
+    }
+
+    /// A bullet destroyed a rock.
+    pub fn bullet_kill(&mut self, size: AsteroidSize) {
+        self.hits += 1;
+        self.kill(size);
+    }
+
+    pub fn kill(&mut self, size: AsteroidSize) {
+        self.kills[size_index(size)] += 1;
+    }
+
+    /// Time alive, flying at `speed` and maybe thrusting, for `dt` seconds.
+    pub fn fly(&mut self, speed: f64, thrusting: bool, dt: f64) {
+        self.life_time += dt;
+        self.distance += speed * dt;
+        if thrusting {
+            self.thrust_time += dt;
+        }
+    }
+
+    /// Whether a rock is close to the ship this frame. A near miss is counted when the
+    /// last close rock moves away again.
+    pub fn near(&mut self, close: bool) {
+        if self.close_call && !close {
+            self.near_misses += 1;
+        }
+        self.close_call = close;
+    }
+
+    /// The ship cannot be hit, so the rocks now close by will be no misses.
+    pub fn covered(&mut self) {
+        self.close_call = false;
+    }
+
+    /// The ship was destroyed: its life ends, and the rock that hit it was no miss.
+    pub fn death(&mut self) {
+        self.lives.push(self.life_time);
+        self.life_time = 0.0;
+        self.close_call = false;
+    }
+
+    /// Seconds each life lasted, the current one included if it has started.
+    pub fn life_times(&self) -> Vec<f64> {
+        let current = (self.life_time > 0.0).then_some(self.life_time);
+        self.lives.iter().copied().chain(current).collect()
+    }
+
+    fn longest_life(&self) -> f64 {
+        self.life_times().into_iter().fold(0.0, f64::max)
+    }
+}
+
+/// The post-game summary for one or two players, a column each.
+pub fn summary_lines(players: &[SessionStats]) -> Vec<String> {
+    if players.is_empty() {
+        return Vec::new();
+    }
+    let column = |value: &dyn Fn(&SessionStats) -> String| -> Vec<String> {
+        players.iter().map(value).collect()
+    };
+    let mut lines = vec![match players.len() {
+        1 => "GAME SUMMARY".to_string(),
+        _ => row(
+            "GAME SUMMARY",
+            (1..=players.len()).map(|i| format!("{}UP", i)),
+        ),
+    }];
+    lines.push(row("Shots fired", column(&|s| s.shots.to_string())));
+    lines.push(row("Accuracy", column(&|s| accuracy(s.shots, s.hits))));
+    for (i, label) in SIZE_LABELS.iter().enumerate() {
+        lines.push(row(label, column(&|s| s.kills[i].to_string())));
+    }
+    lines.push(row(
+        "Waves cleared",
+        column(&|s| s.waves_cleared.to_string()),
+    ));
+    lines.push(row("Near misses", column(&|s| s.near_misses.to_string())));
+    lines.push(row("Distance", column(&|s| format!("{:.0}", s.distance))));
+    lines.push(row("Thrust time", column(&|s| clock(s.thrust_time))));
+    lines.push(row("Longest life", column(&|s| clock(s.longest_life()))));
+    if let [player] = players {
+        let times: Vec<String> = player.life_times().into_iter().map(clock).collect();
+        if !times.is_empty() {
+            lines.push(format!("{:<14}{}", "Lives", times.join(" ")));
+        }
+    }
+    lines
+}
+
+/// Totals over every game played on this machine, kept in the stats file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LifetimeStats {
+    /// Games played, counting each player of a two-player game.
+    pub games: u32,
+    pub shots: u32,
+    pub hits: u32,
+    pub kills: [u32; 3],
+    /// Seconds alive over all lives.
+    pub time_alive: f64,
+    pub longest_life: f64,
+    pub distance: f64,
+    pub thrust_time: f64,
+    pub waves_cleared: u32,
+    pub near_misses: u32,
+}
+
+impl LifetimeStats {
+    /// Add one player's finished game.
+    pub fn record(&mut self, stats: &SessionStats) {
+        self.games += 1;
+        self.shots += stats.shots;
+        self.hits += stats.hits;
+        for (total, kills) in self.kills.iter_mut().zip(stats.kills) {
+            *total += kills;
+        }
+        self.time_alive += stats.life_times().iter().sum::<f64>();
+        self.longest_life = self.longest_life.max(stats.longest_life());
+        self.distance += stats.distance;
+        self.thrust_time += stats.thrust_time;
+        self.waves_cleared += stats.waves_cleared;
+        self.near_misses += stats.near_misses;
+    }
+
+    /// Lines for the lifetime stats screen.
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines = vec![
+            row("Games played", [self.games.to_string()]),
+            row("Time alive", [clock(self.time_alive)]),
+            row("Longest life", [clock(self.longest_life)]),
+            row("Shots fired", [self.shots.to_string()]),
+            row("Accuracy", [accuracy(self.shots, self.hits)]),
+        ];
+        for (label, kills) in SIZE_LABELS.iter().zip(self.kills) {
+            lines.push(row(label, [kills.to_string()]));
+        }
+        lines.push(row("Waves cleared", [self.waves_cleared.to_string()]));
+        lines.push(row("Near misses", [self.near_misses.to_string()]));
+        lines.push(row("Distance", [format!("{:.0}", self.distance)]));
+        lines.push(row("Thrust time", [clock(self.thrust_time)]));
+        lines
+    }
+
+    /// Read the stats file. A missing file means no games yet.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        save::read_json(path)
+    }
+
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        save::write_json_atomically(path, self)
+    }
+}
+
+/// Where lifetime stats live, in the data directory.
+pub fn default_path() -> PathBuf {
+    save::data_dir().join(STATS_FILE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn played() -> SessionStats {
+        let mut stats = SessionStats::default();
+        stats.shot(8);
+        stats.bullet_kill(AsteroidSize::Large);
+        stats.bullet_kill(AsteroidSize::Small);
+        stats.kill(AsteroidSize::Small);
+        stats.fly(100.0, true, 30.0);
+        stats.death();
+        stats.fly(50.0, false, 65.0);
+        stats.waves_cleared = 2;
+        stats
+    }
+
+    // === Requirement: Session Statistics ===
+
+    // Scenario: Shots, hits and kills by size are counted
+    #[test]
+    fn test_counts_shots_and_kills() {
+        let stats = played();
+        assert_eq!((stats.shots, stats.hits), (8, 2));
+        assert_eq!(stats.kills, [1, 0, 2]);
+    }
+
+    // Scenario: Flying counts time alive per life, distance and thrust time
+    #[test]
+    fn test_flying_and_lives() {
+        let stats = played();
+        assert_eq!(stats.lives, vec![30.0]);
+        assert_eq!(stats.life_times(), vec![30.0, 65.0]);
+        assert!((stats.distance - (3000.0 + 3250.0)).abs() < 1e-9);
+        assert_eq!(stats.thrust_time, 30.0);
+    }
+
+    // Scenario: A rock passing close by counts as a near miss once it has gone
+    #[test]
+    fn test_near_misses() {
+        let mut stats = SessionStats::default();
+        stats.near(true);
+        stats.near(true);
+        assert_eq!(stats.near_misses, 0);
+        stats.near(false);
+        assert_eq!(stats.near_misses, 1);
+
+        // A rock that hits is no miss
+        stats.near(true);
+        stats.death();
+        stats.near(false);
+        assert_eq!(stats.near_misses, 1);
+
+        // Nor is one that leaves while the ship cannot be hit
+        stats.near(true);
+        stats.covered();
+        stats.near(false);
+        assert_eq!(stats.near_misses, 1);
+    }
+
+    // Scenario: Stats follow the player's game events
+    #[test]
+    fn test_observe_events() {
+        let mut stats = SessionStats::default();
+        let position = crate::physics::Vec2::new(0.0, 0.0);
+        for event in [
+            GameEvent::ShipFlew {
+                player: 0,
+                dt: 2.0,
+                speed: 10.0,
+                thrusting: true,
+            },
+            GameEvent::BulletFired {
+                player: 0,
+                bullets: 3,
+            },
+            GameEvent::AsteroidDestroyed {
+                size: AsteroidSize::Large,
+                position,
+                by: Destroyer::Bullet { player: 0 },
+            },
+            GameEvent::AsteroidDestroyed {
+                size: AsteroidSize::Small,
+                position,
+                by: Destroyer::SmartBomb { player: 0 },
+            },
+            GameEvent::WaveCleared { player: 0 },
+            GameEvent::ShipDestroyed {
+                player: 0,
+                by: None,
+            },
+        ] {
+            stats.observe(&event);
+        }
+        assert_eq!((stats.shots, stats.hits, stats.kills), (3, 1, [1, 0, 1]));
+        assert_eq!((stats.distance, stats.thrust_time), (20.0, 2.0));
+        assert_eq!(stats.waves_cleared, 1);
+        assert_eq!(stats.lives, vec![2.0]);
+    }
+
+    // === Requirement: Post-Game Summary ===
+
+    // Scenario: Summary lists each statistic and every life
+    #[test]
+    fn test_summary_lines() {
+        assert_eq!(
+            summary_lines(&[played()]),
+            vec![
+                "GAME SUMMARY",
+                "Shots fired          8",
+                "Accuracy           25%",
+                "Large rocks          1",
+                "Medium rocks         0",
+                "Small rocks          2",
+                "Waves cleared        2",
+                "Near misses          0",
+                "Distance          6250",
+                "Thrust time       0:30",
+                "Longest life      1:05",
+                "Lives         0:30 1:05",
+            ]
+        );
+        assert!(summary_lines(&[]).is_empty());
+    }
+
+    // Scenario: Two players get a column each
+    #[test]
+    fn test_two_player_summary() {
+        let lines = summary_lines(&[played(), SessionStats::default()]);
+        assert_eq!(lines[0], "GAME SUMMARY       1UP     2UP");
+        assert_eq!(lines[2], "Accuracy           25%       -");
+        assert_eq!(lines.len(), 11, "no per-life row");
+    }
+
+    // === Requirement: Lifetime Statistics ===
+
+    // Scenario: Finished games add to the lifetime totals
+    #[test]
+    fn test_lifetime_totals() {
+        let mut lifetime = LifetimeStats::default();
+        lifetime.record(&played());
+        lifetime.record(&played());
+        assert_eq!(lifetime.games, 2);
+        assert_eq!((lifetime.shots, lifetime.hits), (16, 4));
+        assert_eq!(lifetime.kills, [2, 0, 4]);
+        assert_eq!(lifetime.time_alive, 190.0);
+        assert_eq!(lifetime.longest_life, 65.0);
+        let lines = lifetime.lines();
+        assert_eq!(lines[0], "Games played         2");
+        assert_eq!(lines[1], "Time alive        3:10");
+        assert_eq!(lines[4], "Accuracy           25%");
+    }
+
+    // Scenario: Lifetime stats are kept in a file
+    #[test]
+    fn test_lifetime_file() {
+        let dir = std::env::temp_dir().join(format!("tuisteroids-stats-{}", std::process::id()));
+        let path = dir.join(STATS_FILE);
+        assert_eq!(
+            LifetimeStats::load(&path).unwrap(),
+            LifetimeStats::default()
+        );
+        let mut lifetime = LifetimeStats::default();
+        lifetime.record(&played());
+        lifetime.write(&path).unwrap();
+        assert_eq!(LifetimeStats::load(&path).unwrap(), lifetime);
+        std::fs::write(&path, "not json").unwrap();
+        let error = LifetimeStats::load(&path).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,627 @@
+// Server capability: telnet server giving every connection its own game
+//
+// Each client gets a `Game` on its own thread, drawn by ratatui straight onto the
+// socket and driven by keys decoded from the client's bytes. The server asks the
+// client to stop echoing and line buffering, and to report its window size (NAWS),
+// which sets the session's viewport and world size. All sessions share one high
+// score board.
+
+use crate::game::{FrameDriver, Game, TIMESTEP, WORLD_HEIGHT};
+use crate::highscore::{ModeHighScores, SharedHighScores};
+use crate::renderer;
+use crossterm::event::KeyCode;
+use std::io::{self, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+pub const IAC: u8 = 255;
+pub const DONT: u8 = 254;
+pub const DO: u8 = 253;
+pub const WONT: u8 = 252;
+pub const WILL: u8 = 251;
+pub const SB: u8 = 250;
+pub const SE: u8 = 240;
+pub const OPT_ECHO: u8 = 1;
+pub const OPT_SUPPRESS_GO_AHEAD: u8 = 3;
+pub const OPT_NAWS: u8 = 31;
+pub const OPT_LINEMODE: u8 = 34;
+
+/// Sent on connect: the server echoes (so the client does not), no go-aheads, please
+/// report the window size, and no line mode. Together these put clients in
+/// character-at-a-time mode.
+pub const NEGOTIATION: [u8; 12] = [
+    IAC,
+    WILL,
+    OPT_ECHO,
+    IAC,
+    WILL,
+    OPT_SUPPRESS_GO_AHEAD,
+    IAC,
+    DO,
+    OPT_NAWS,
+    IAC,
+    DONT,
+    OPT_LINEMODE,
+];
+
+/// Terminal size assumed for clients that do not report one.
+pub const DEFAULT_SIZE: (u16, u16) = (80, 24);
+/// How long a new session waits for the window size before drawing.
+pub const SIZE_WAIT: Duration = Duration::from_millis(500);
+
+const ESC: u8 = 0x1b;
+const MAX_SEQUENCE: usize = 16;
+
+/// Something the client did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TelnetEvent {
+    Key(KeyCode),
+    /// The client's window size changed (NAWS).
+    Resize {
+        cols: u16,
+        rows: u16,
+    },
+    /// Ctrl-C or Ctrl-D: end the session.
+    Interrupt,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+enum DecodeState {
+    #[default]
+    Data,
+    /// After IAC
+    Command,
+    /// After IAC WILL/WONT/DO/DONT, awaiting the option byte
+    Option,
+    /// Inside IAC SB ... IAC SE
+    Sub,
+    SubIac,
+    /// After a carriage return, which may be followed by LF or NUL
+    Return,
+}
+
+/// Turns the client's byte stream into key presses and window sizes, stripping
+/// telnet commands. Keeps state across reads, so sequences may arrive split.
+#[derive(Debug, Default)]
+pub struct TelnetDecoder {
+    state: DecodeState,
+    sub: Vec<u8>,
+    escape: Vec<u8>,
+}
+
+impl TelnetDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decode one read's worth of bytes. An Escape key alone at the end of a read is
+    /// reported as Esc; a partial arrow-key sequence waits for the next read.
+    pub fn feed(&mut self, bytes: &[u8]) -> Vec<TelnetEvent> {
+        let mut events = Vec::new();
+        for &byte in bytes {
+            self.byte(byte, &mut events);
+        }
+        if self.escape == [ESC] {
+            self.escape.clear();
+            events.push(TelnetEvent::Key(KeyCode::Esc));
+        }
+        events
+    }
+
+    fn byte(&mut self, byte: u8, events: &mut Vec<TelnetEvent>) {
+        match self.state {
+            DecodeState::Data => {
+                if byte == IAC {
+                    self.state = DecodeState::Command;
+                } else {
+                    self.data(byte, events);
+                }
+            }
+            DecodeState::Return => {
+                self.state = DecodeState::Data;
+                if byte != b'\n' && byte != 0 {
+                    self.byte(byte, events);
+                }
+            }
+            DecodeState::Command => {
+                self.state = match byte {
+                    WILL | WONT | DO | DONT => DecodeState::Option,
+                    SB => {
+                        self.sub.clear();
+                        DecodeState::Sub
+                    }
+                    // IAC IAC is a literal 255, which is not a key
+                    _ => DecodeState::Data,
+                };
+            }
+            DecodeState::Option => self.state = DecodeState::Data,
+            DecodeState::Sub => {
+                if byte == IAC {
+                    self.state = DecodeState::SubIac;
+                } else if self.sub.len() < MAX_SEQUENCE {
+                    self.sub.push(byte);
+                }
+            }
+            DecodeState::SubIac => match byte {
+                SE => {
+                    self.state = DecodeState::Data;
+                    if let [OPT_NAWS, w1, w2, h1, h2, ..] = self.sub[..] {
+                        events.push(TelnetEvent::Resize {
+                            cols: u16::from_be_bytes([w1, w2]),
+                            rows: u16::from_be_bytes([h1, h2]),
+                        });
+                    }
+                }
+                IAC => {
+                    self.state = DecodeState::Sub;
+                    if self.sub.len() < MAX_SEQUENCE {
+                        self.sub.push(IAC);
+                    }
+                }
+                _ => self.state = DecodeState::Data,
+            },
+        }
+    }
+
+    fn data(&mut self, byte: u8, events: &mut Vec<TelnetEvent>) {
+        if !self.escape.is_empty() {
+            // Long parameter lists are swallowed without being kept
+            if self.escape.len() < MAX_SEQUENCE {
+                self.escape.push(byte);
+            }
+            match self.escape[..] {
+                [ESC, b'[' | b'O'] => {}
+                [ESC, _] => {
+                    // Not a sequence: a lone Escape followed by an ordinary key
+                    self.escape.clear();
+                    events.push(TelnetEvent::Key(KeyCode::Esc));
+                    self.data(byte, events);
+                }
+                _ if (0x40..=0x7e).contains(&byte) => {
+                    self.escape.clear();
+                    let code = match byte {
+                        b'A' => Some(KeyCode::Up),
+                        b'B' => Some(KeyCode::Down),
+                        b'C' => Some(KeyCode::Right),
+                        b'D' => Some(KeyCode::Left),
+                        _ => None,
+                    };
+                    events.extend(code.map(TelnetEvent::Key));
+                }
+                _ => {}
+            }
+            return;
+        }
+        match byte {
+            ESC => self.escape.push(ESC),
+            b'\r' => {
+                self.state = DecodeState::Return;
+                events.push(TelnetEvent::Key(KeyCode::Enter));
+            }
+            b'\n' => events.push(TelnetEvent::Key(KeyCode::Enter)),
+            0x03 | 0x04 => events.push(TelnetEvent::Interrupt),
+            0x08 | 0x7f => events.push(TelnetEvent::Key(KeyCode::Backspace)),
+            0x20..=0x7e => events.push(TelnetEvent::Key(KeyCode::Char(byte as char))),
+            _ => {}
+        }
+    }
+}
+
+/// World dimensions for a terminal: WORLD_HEIGHT tall, with the width following the
+/// terminal's shape (braille dots are roughly square), clamped to 400..=1600.
+pub fn world_size(cols: u16, rows: u16) -> (f64, f64) {
+    let dots_wide = cols.max(1) as f64 * 2.0;
+    let dots_high = rows.saturating_sub(1).max(1) as f64 * 4.0;
+    let width = (WORLD_HEIGHT * dots_wide / dots_high).clamp(400.0, 1600.0);
+    (width, WORLD_HEIGHT)
+}
+
+/// Accepts telnet clients and runs a game for each on its own thread.
+pub struct TelnetServer {
+    listener: TcpListener,
+    high_scores: SharedHighScores,
+}
+
+impl TelnetServer {
+    pub fn bind(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        Ok(Self {
+            listener: TcpListener::bind(addr)?,
+            high_scores: ModeHighScores::new().shared(),
+        })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// The board shared by every session.
+    pub fn high_scores(&self) -> SharedHighScores {
+        SharedHighScores::clone(&self.high_scores)
+    }
+
+    /// Serve clients until the listener fails. Sessions are named PLAYER1, PLAYER2, ...
+    /// in connection order.
+    #[cfg(not(tarpaulin_include))]
+    pub fn serve(self) -> io::Result<()> {
+        for (n, stream) in self.listener.incoming().enumerate() {
+            let Ok(stream) = stream else {
+                continue;
+            };
+            let name = format!("PLAYER{}", n + 1);
+            let board = self.high_scores();
+            let peer = stream
+                .peer_addr()
+                .map_or_else(|_| "?".to_string(), |addr| addr.to_string());
+            eprintln!("{} connected from {}", name, peer);
+            std::thread::spawn(move || {
+                let result = run_session(stream, board, &name);
+                match result {
+                    Ok(()) => eprintln!("{} left", name),
+                    Err(e) => eprintln!("{} dropped: {}", name, e),
+                }
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Play one client's game until they quit or disconnect.
+#[cfg(not(tarpaulin_include))]
+pub fn run_session(stream: TcpStream, high_scores: SharedHighScores, name: &str) -> io::Result<()> {
+    // The reader thread holds its own handle, so close the socket explicitly
+    let control = stream.try_clone()?;
+    let result = play_session(stream, high_scores, name);
+    let _ = control.shutdown(std::net::Shutdown::Both);
+    result
+}
+
+#[cfg(not(tarpaulin_include))]
+fn play_session(stream: TcpStream, high_scores: SharedHighScores, name: &str) -> io::Result<()> {
+    use crate::game::frame_sleep_duration;
+    use crossterm::terminal::{Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+    use crossterm::{cursor, execute};
+    use ratatui::backend::CrosstermBackend;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::Paragraph;
+    use ratatui::{Terminal, TerminalOptions, Viewport};
+    use std::sync::mpsc::{self, RecvTimeoutError, TryRecvError};
+    use std::time::Instant;
+
+    stream.set_nodelay(true)?;
+    let mut writer = stream.try_clone()?;
+    writer.write_all(&NEGOTIATION)?;
+
+    // Decode the client's bytes on a separate thread so the game never waits on them
+    let (events, received) = mpsc::channel();
+    let mut reader = stream;
+    std::thread::spawn(move || {
+        let mut decoder = TelnetDecoder::new();
+        let mut buf = [0u8; 1024];
+        while let Ok(n @ 1..) = reader.read(&mut buf) {
+            for event in decoder.feed(&buf[..n]) {
+                if events.send(event).is_err() {
+                    return;
+                }
+            }
+        }
+    });
+
+    // Give the client a moment to report its window size
+    let (mut cols, mut rows) = DEFAULT_SIZE;
+    let mut early = Vec::new();
+    let deadline = Instant::now() + SIZE_WAIT;
+    loop {
+        match received.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+            Ok(TelnetEvent::Resize { cols: c, rows: r }) => {
+                (cols, rows) = (c, r);
+                break;
+            }
+            Ok(event) => early.push(event),
+            Err(RecvTimeoutError::Timeout) => break,
+            Err(RecvTimeoutError::Disconnected) => return Ok(()),
+        }
+    }
+
+    let (world_width, world_height) = world_size(cols, rows);
+    let mut game = Game::new(world_width, world_height);
+    game.high_scores = Some(high_scores);
+    game.player_name = name.to_string();
+
+    // Braille is UTF-8 and never contains a 0xFF byte, so output needs no IAC escaping
+    let mut terminal = Terminal::with_options(
+        CrosstermBackend::new(writer),
+        TerminalOptions {
+            viewport: Viewport::Fixed(Rect::new(0, 0, cols, rows)),
+        },
+    )?;
+    execute!(
+        terminal.backend_mut(),
+        EnterAlternateScreen,
+        cursor::Hide,
+        Clear(ClearType::All)
+    )?;
+
+    let mut driver = FrameDriver::new();
+    let target_frame_time = Duration::from_secs_f64(TIMESTEP);
+    let mut last_time = Instant::now();
+    'session: loop {
+        let frame_start = Instant::now();
+        let elapsed = last_time.elapsed().as_secs_f64();
+        last_time = frame_start;
+
+        let mut pending: Vec<TelnetEvent> = std::mem::take(&mut early);
+        loop {
+            match received.try_recv() {
+                Ok(event) => pending.push(event),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => return Ok(()),
+            }
+        }
+        for event in pending {
+            match event {
+                TelnetEvent::Key(code) => {
+                    if driver.press(&mut game, code, true) {
+                        break 'session;
+                    }
+                }
+                TelnetEvent::Resize { cols, rows } => {
+                    terminal.resize(Rect::new(0, 0, cols, rows))?;
+                }
+                TelnetEvent::Interrupt => break 'session,
+            }
+        }
+
+        // No speaker on the far end: audio events are dropped
+        driver.frame(&mut game, elapsed, false);
+
+        let scene = game.scene();
+        terminal.draw(|frame| {
+            let area = frame.area();
+            let lines = renderer::scene_lines(&scene, area.width as usize, area.height as usize);
+            frame.render_widget(Paragraph::new(lines), area);
+        })?;
+
+        if let Some(sleep_time) = frame_sleep_duration(frame_start, target_frame_time) {
+            std::thread::sleep(sleep_time);
+        }
+    }
+
+    execute!(terminal.backend_mut(), LeaveAlternateScreen, cursor::Show)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(events: &[TelnetEvent]) -> Vec<KeyCode> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                TelnetEvent::Key(code) => Some(*code),
+                _ => None,
+            })
+            .collect()
+    }
+
+    // === Requirement: Telnet Input Decoding ===
+
+    // Scenario: Arrow keys, space and letters become key presses
+    #[test]
+    fn test_decode_keys() {
+        let mut decoder = TelnetDecoder::new();
+        let events = decoder.feed(b"\x1b[A\x1b[D\x1bOC\x1b[B q");
+        assert_eq!(
+            keys(&events),
+            vec![
+                KeyCode::Up,
+                KeyCode::Left,
+                KeyCode::Right,
+                KeyCode::Down,
+                KeyCode::Char(' '),
+                KeyCode::Char('q'),
+            ]
+        );
+        // Modifier parameters are ignored
+        assert_eq!(keys(&decoder.feed(b"\x1b[1;5C")), vec![KeyCode::Right]);
+        // Unknown sequences are dropped
+        assert!(decoder.feed(b"\x1b[5~").is_empty());
+    }
+
+    // Scenario: Return is one Enter however the client sends it
+    #[test]
+    fn test_decode_return() {
+        for input in [&b"\r\n"[..], b"\r\0", b"\r", b"\n"] {
+            let mut decoder = TelnetDecoder::new();
+            assert_eq!(keys(&decoder.feed(input)), vec![KeyCode::Enter]);
+        }
+        let mut decoder = TelnetDecoder::new();
+        assert_eq!(
+            keys(&decoder.feed(b"\rx")),
+            vec![KeyCode::Enter, KeyCode::Char('x')]
+        );
+        assert_eq!(keys(&decoder.feed(b"\x7f")), vec![KeyCode::Backspace]);
+    }
+
+    // Scenario: Escape alone is a key
+    #[test]
+    fn test_decode_escape() {
+        let mut decoder = TelnetDecoder::new();
+        assert_eq!(keys(&decoder.feed(b"\x1b")), vec![KeyCode::Esc]);
+        assert_eq!(
+            keys(&decoder.feed(b"\x1bq")),
+            vec![KeyCode::Esc, KeyCode::Char('q')]
+        );
+    }
+
+    // Scenario: Sequences split across reads still decode
+    #[test]
+    fn test_decode_split() {
+        let mut decoder = TelnetDecoder::new();
+        let mut events = Vec::new();
+        for byte in b"\x1b[C\xff\xfa\x1f\x00\x64\x00\x1e\xff\xf0" {
+            events.extend(decoder.feed(&[*byte]));
+        }
+        // The lone ESC in the first read is taken as Escape, so the rest is plain text
+        assert_eq!(events[0], TelnetEvent::Key(KeyCode::Esc));
+
+        let mut decoder = TelnetDecoder::new();
+        let mut events = decoder.feed(b"\x1b[");
+        events.extend(decoder.feed(b"C\xff\xfa\x1f\x00"));
+        events.extend(decoder.feed(b"\x64\x00\x1e\xff"));
+        events.extend(decoder.feed(b"\xf0"));
+        assert_eq!(
+            events,
+            vec![
+                TelnetEvent::Key(KeyCode::Right),
+                TelnetEvent::Resize {
+                    cols: 100,
+                    rows: 30
+                },
+            ]
+        );
+    }
+
+    // Scenario: Telnet negotiation is not mistaken for keys
+    #[test]
+    fn test_decode_negotiation() {
+        let mut decoder = TelnetDecoder::new();
+        let events = decoder.feed(&[
+            IAC, WILL, OPT_NAWS, IAC, DO, OPT_ECHO, IAC, WONT, 24, IAC, DONT, 5, IAC, IAC, IAC,
+            241, b'x',
+        ]);
+        assert_eq!(events, vec![TelnetEvent::Key(KeyCode::Char('x'))]);
+        // Other subnegotiations are skipped
+        assert!(decoder
+            .feed(&[IAC, SB, 24, 0, b'x', b't', IAC, SE])
+            .is_empty());
+        // Aborted subnegotiation
+        assert!(decoder.feed(&[IAC, SB, OPT_NAWS, 0, IAC, 1]).is_empty());
+    }
+
+    // Scenario: Window size reports become resizes
+    #[test]
+    fn test_decode_naws() {
+        let mut decoder = TelnetDecoder::new();
+        assert_eq!(
+            decoder.feed(&[IAC, SB, OPT_NAWS, 0, 80, 0, 24, IAC, SE]),
+            vec![TelnetEvent::Resize { cols: 80, rows: 24 }]
+        );
+        // A 255 in the size is doubled on the wire
+        assert_eq!(
+            decoder.feed(&[IAC, SB, OPT_NAWS, 0, IAC, IAC, 0, 50, IAC, SE]),
+            vec![TelnetEvent::Resize {
+                cols: 255,
+                rows: 50
+            }]
+        );
+        // Truncated reports are ignored
+        assert!(decoder
+            .feed(&[IAC, SB, OPT_NAWS, 0, 80, IAC, SE])
+            .is_empty());
+    }
+
+    // Scenario: Ctrl-C and Ctrl-D end the session
+    #[test]
+    fn test_decode_interrupt() {
+        let mut decoder = TelnetDecoder::new();
+        assert_eq!(
+            decoder.feed(b"\x03\x04\x01"),
+            vec![TelnetEvent::Interrupt, TelnetEvent::Interrupt]
+        );
+    }
+
+    // Scenario: Overlong sequences are swallowed whole
+    #[test]
+    fn test_decode_overlong() {
+        let mut decoder = TelnetDecoder::new();
+        let mut bytes = b"\x1b[".to_vec();
+        bytes.extend([b'1'; 20]);
+        bytes.extend(b"Ay");
+        assert_eq!(
+            keys(&decoder.feed(&bytes)),
+            vec![KeyCode::Up, KeyCode::Char('y')]
+        );
+        let mut bytes = vec![IAC, SB, OPT_NAWS];
+        bytes.extend([1; 20]);
+        bytes.extend([IAC, SE]);
+        assert_eq!(
+            decoder.feed(&bytes),
+            vec![TelnetEvent::Resize {
+                cols: 257,
+                rows: 257
+            }]
+        );
+    }
+
+    // === Requirement: Per-Session World Size ===
+
+    // Scenario: World width follows the terminal's shape
+    #[test]
+    fn test_world_size() {
+        let (w, h) = world_size(80, 24);
+        assert_eq!(h, WORLD_HEIGHT);
+        assert!((w - 600.0 * 160.0 / 92.0).abs() < 1e-9);
+        assert_eq!(world_size(20, 60).0, 400.0);
+        assert_eq!(world_size(400, 10).0, 1600.0);
+        assert_eq!(world_size(0, 0).0, 400.0);
+    }
+
+    // === Requirement: Telnet Sessions ===
+
+    fn read_until(stream: &mut TcpStream, needle: &str) -> Vec<u8> {
+        let mut seen = Vec::new();
+        let mut buf = [0u8; 4096];
+        while !String::from_utf8_lossy(&seen).contains(needle) {
+            let n = stream.read(&mut buf).expect("read before timeout");
+            assert!(n > 0, "connection closed before {:?}", needle);
+            seen.extend(&buf[..n]);
+        }
+        seen
+    }
+
+    // Scenario: Each client gets its own game until it quits
+    #[test]
+    fn test_session_over_loopback() {
+        let server = TelnetServer::bind("127.0.0.1:0").unwrap();
+        let addr = server.local_addr().unwrap();
+        let board = server.high_scores();
+        std::thread::spawn(move || server.serve());
+
+        let mut clients: Vec<TcpStream> = (0..2)
+            .map(|_| {
+                let client = TcpStream::connect(addr).unwrap();
+                client
+                    .set_read_timeout(Some(Duration::from_secs(5)))
+                    .unwrap();
+                client
+            })
+            .collect();
+        for client in clients.iter_mut() {
+            let mut negotiation = [0u8; NEGOTIATION.len()];
+            client.read_exact(&mut negotiation).unwrap();
+            assert_eq!(negotiation, NEGOTIATION);
+            client
+                .write_all(&[
+                    IAC, WILL, OPT_NAWS, IAC, SB, OPT_NAWS, 0, 60, 0, 20, IAC, SE,
+                ])
+                .unwrap();
+            read_until(client, "TUISTEROIDS");
+        }
+
+        // Starting a game on one client leaves the other on the menu
+        clients[0].write_all(b"\r").unwrap();
+        read_until(&mut clients[0], "Score: 0");
+
+        // Quitting the menu closes that client's connection only
+        clients[1].write_all(b"q").unwrap();
+        let mut rest = Vec::new();
+        clients[1].read_to_end(&mut rest).unwrap();
+        assert!(String::from_utf8_lossy(&rest).contains("\x1b[?1049l"));
+
+        // Ctrl-C ends a game in progress
+        clients[0].write_all(b"\x03").unwrap();
+        clients[0].read_to_end(&mut rest).unwrap();
+        assert!(board.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,221 @@
+// Turns capability: two-player alternating turns, one game state per player
+
+use crate::game::PlayingState;
+use crate::stats::SessionStats;
+use serde::{Deserialize, Serialize};
+
+pub const TURN_BANNER_TIME: f64 = 2.0; // seconds the "PLAYER n" banner pauses play
+
+/// Bookkeeping for two players taking turns, arcade style.
+/// The active player's PlayingState lives in `Game::playing`; the waiting player's
+/// game is benched here with its own wave, asteroid field, score and lives.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Turns {
+    current: usize,
+    benched: Option<PlayingState>,
+    final_scores: [Option<u32>; 2],
+    banner_timer: f64,
+    #[serde(default)]
+    final_stats: [Option<SessionStats>; 2],
+}
+
+impl Turns {
+    /// Start with player 1 active and player 2's game benched.
+    pub fn new(player_two: PlayingState) -> Self {
+        Self {
+            current: 0,
+            benched: Some(player_two),
+            final_scores: [None, None],
+            banner_timer: TURN_BANNER_TIME,
+            final_stats: [None, None],
+        }
+    }
+
+    /// Index of the active player (0 for player 1, 1 for player 2).
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// The waiting player's game, if that player is still in.
+    pub fn benched(&self) -> Option<&PlayingState> {
+        self.benched.as_ref()
+    }
+
+    /// Banner naming the active player, shown while play is paused for a turn change.
+    pub fn banner(&self) -> Option<String> {
+        self.is_paused()
+            .then(|| format!("PLAYER {}", self.current + 1))
+    }
+
+    /// True while the turn banner is showing.
+    pub fn is_paused(&self) -> bool {
+        self.banner_timer > 0.0
+    }
+
+    /// Count down the turn banner.
+    pub fn tick_banner(&mut self, dt: f64) {
+        self.banner_timer = (self.banner_timer - dt).max(0.0);
+    }
+
+    /// The active player lost a life: hand over to the other player if they are still in.
+    /// Returns true if the turn changed.
+    pub fn life_lost(&mut self, active: &mut PlayingState) -> bool {
+        let Some(other) = self.benched.as_mut() else {
+            return false;
+        };
+        std::mem::swap(active, other);
+        self.switch();
+        true
+    }
+
+    /// The active player's game is over: record their score and stats and hand over to
+    /// the other player if they are still in. Returns false once both players are out.
+    pub fn player_out(&mut self, active: &mut PlayingState) -> bool {
+        self.final_scores[self.current] = Some(active.score);
+        self.final_stats[self.current] = Some(active.stats.clone());
+        let Some(other) = self.benched.take() else {
+            return false;
+        };
+        *active = other;
+        self.switch();
+        true
+    }
+
+    /// Score and lives for both players, given the active player's game.
+    /// Players who are out report their final score and no lives.
+    pub fn players(&self, active: &PlayingState) -> [(u32, u32); 2] {
+        let mut players = [(0, 0); 2];
+        players[self.current] = (active.score, active.ship.lives);
+        let other = 1 - self.current;
+        players[other] = match (&self.benched, self.final_scores[other]) {
+            (Some(benched), _) => (benched.score, benched.ship.lives),
+            (None, score) => (score.unwrap_or(0), 0),
+        };
+        players
+    }
+
+    /// Both players' scores, given the active player's game.
+    pub fn scores(&self, active: &PlayingState) -> [u32; 2] {
+        let players = self.players(active);
+        [players[0].0, players[1].0]
+    }
+
+    /// Both players' stats, given the active player's game.
+    pub fn stats(&self, active: &PlayingState) -> [SessionStats; 2] {
+        let mut stats = self.final_stats.clone().map(Option::unwrap_or_default);
+        stats[self.current] = active.stats.clone();
+        if let Some(ref benched) = self.benched {
+            stats[1 - self.current] = benched.stats.clone();
+        }
+        stats
+    }
+
+    fn switch(&mut self) {
+        self.current = 1 - self.current;
+        self.banner_timer = TURN_BANNER_TIME;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn states() -> (PlayingState, PlayingState) {
+        let mut one = PlayingState::new_seeded(800.0, 600.0, 1);
+        let mut two = PlayingState::new_seeded(800.0, 600.0, 2);
+        one.score = 100;
+        two.score = 200;
+        (one, two)
+    }
+
+    // === Requirement: Two-Player Alternating Turns ===
+
+    // Scenario: Player 1 starts with a banner
+    #[test]
+    fn test_player_one_starts() {
+        let (_, two) = states();
+        let turns = Turns::new(two);
+        assert_eq!(turns.current(), 0);
+        assert!(turns.is_paused());
+        assert_eq!(turns.banner(), Some("PLAYER 1".to_string()));
+        assert_eq!(turns.benched().unwrap().score, 200);
+    }
+
+    // Scenario: Banner counts down and unpauses
+    #[test]
+    fn test_banner_counts_down() {
+        let (_, two) = states();
+        let mut turns = Turns::new(two);
+        turns.tick_banner(TURN_BANNER_TIME / 2.0);
+        assert!(turns.is_paused());
+        turns.tick_banner(TURN_BANNER_TIME);
+        assert!(!turns.is_paused());
+        assert_eq!(turns.banner(), None);
+    }
+
+    // Scenario: Losing a life switches to the other player's game
+    #[test]
+    fn test_life_lost_switches() {
+        let (mut active, two) = states();
+        let mut turns = Turns::new(two);
+        turns.tick_banner(TURN_BANNER_TIME);
+        assert!(turns.life_lost(&mut active));
+        assert_eq!(turns.current(), 1);
+        assert_eq!(active.score, 200);
+        assert_eq!(turns.benched().unwrap().score, 100);
+        assert_eq!(turns.banner(), Some("PLAYER 2".to_string()));
+
+        assert!(turns.life_lost(&mut active));
+        assert_eq!(turns.current(), 0);
+        assert_eq!(active.score, 100);
+    }
+
+    // Scenario: Remaining player keeps playing after the other is out
+    #[test]
+    fn test_player_out_hands_over() {
+        let (mut active, two) = states();
+        let mut turns = Turns::new(two);
+        assert!(turns.player_out(&mut active));
+        assert_eq!(turns.current(), 1);
+        assert_eq!(active.score, 200);
+        assert!(turns.benched().is_none());
+
+        // With player 1 out, a lost life does not switch
+        assert!(!turns.life_lost(&mut active));
+        assert_eq!(turns.current(), 1);
+        assert_eq!(turns.players(&active), [(100, 0), (200, 3)]);
+    }
+
+    // Scenario: Game ends when both players are out
+    #[test]
+    fn test_both_players_out() {
+        let (mut active, two) = states();
+        let mut turns = Turns::new(two);
+        turns.player_out(&mut active);
+        active.score = 250;
+        assert!(!turns.player_out(&mut active));
+        assert_eq!(turns.scores(&active), [100, 250]);
+    }
+
+    // Scenario: HUD reports both players
+    #[test]
+    fn test_players_reports_both() {
+        let (mut active, two) = states();
+        active.ship.lives = 2;
+        let turns = Turns::new(two);
+        assert_eq!(turns.players(&active), [(100, 2), (200, 3)]);
+    }
+
+    // Scenario: Both players' stats are kept after one is out
+    #[test]
+    fn test_stats_reports_both() {
+        let (mut active, mut two) = states();
+        active.stats.shots = 5;
+        two.stats.shots = 7;
+        let mut turns = Turns::new(two);
+        assert_eq!(turns.stats(&active).map(|stats| stats.shots), [5, 7]);
+        turns.player_out(&mut active);
+        active.stats.shots = 9;
+        assert_eq!(turns.stats(&active).map(|stats| stats.shots), [5, 9]);
+    }
+}
@@ -0,0 +1,50 @@
+// Netplay capability: two headless processes play one game over TCP loopback
+
+use std::io::{BufRead, BufReader};
+use std::process::{Command, Stdio};
+
+const BIN: &str = env!("CARGO_BIN_EXE_tuisteroids");
+
+// === Requirement: TCP Transport ===
+
+// Scenario: Two local processes finish with identical state
+#[test]
+fn test_two_processes_agree() {
+    let mut host = Command::new(BIN)
+        .args(["--host", "127.0.0.1:0", "--headless", "--versus"])
+        .args(["--seed", "11", "--hash-interval", "30"])
+        .args(["--max-ticks", "600"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("start host");
+
+    // The host announces the port it bound before accepting
+    let mut banner = String::new();
+    BufReader::new(host.stderr.take().unwrap())
+        .read_line(&mut banner)
+        .unwrap();
+    let addr = banner
+        .trim()
+        .strip_prefix("Waiting for a player on ")
+        .and_then(|rest| rest.strip_suffix("..."))
+        .unwrap_or_else(|| panic!("unexpected banner: {}", banner))
+        .to_string();
+
+    let joiner = Command::new(BIN)
+        .args(["--join", &addr, "--headless", "--max-ticks", "600"])
+        .output()
+        .expect("run joiner");
+    let host = host.wait_with_output().expect("wait for host");
+
+    assert!(host.status.success(), "host failed");
+    assert!(
+        joiner.status.success(),
+        "joiner failed: {}",
+        String::from_utf8_lossy(&joiner.stderr)
+    );
+    let host_summary = String::from_utf8(host.stdout).unwrap();
+    let joiner_summary = String::from_utf8(joiner.stdout).unwrap();
+    assert!(host_summary.starts_with("ticks "), "{}", host_summary);
+    assert_eq!(host_summary, joiner_summary);
+}